@@ -0,0 +1,150 @@
+//! The runner's command line, declared as types for clap to parse.
+//!
+//! Demo 1 reads its argument by hand, with `argv.nth(1)` and a `match`, which is
+//! fine for one argument but gets long once there are subcommands, options with
+//! values and defaults. Here the command line is a struct and an enum, and
+//! `#[derive(Parser)]` writes the parser: it checks the types, fills in the
+//! defaults, prints `--help` from the doc comments, and suggests what was meant
+//! when something is misspelt. This is System.CommandLine, with attributes in place
+//! of building `Command` and `Option<T>` objects by hand.
+
+use std::fmt::Display;
+use std::io::{ self, Write };
+use std::str::FromStr;
+
+use clap::builder::{ PossibleValue, PossibleValuesParser };
+use clap::{ CommandFactory, Parser, Subcommand };
+use clap_complete::Shell;
+use clap_mangen::Man;
+
+use demo_core::compare::DEFAULT_RUNS;
+use demo_core::head::DEFAULT_LINES;
+use demo_core::hist::{ DEFAULT_BUCKETS, DEFAULT_WIDTH };
+use demo_core::registry;
+
+use crate::config::Format;
+
+/// Runs the demos from "Rust for C# developers", and a few commands built from them
+#[derive(Debug, Parser)]
+#[command(name = "demo", version)]
+pub struct Cli {
+    /// How to print what the command does, over demo.toml and DEMO_FORMAT
+    #[arg(long, value_enum)]
+    pub format: Option<Format>,
+    /// The settings file to read in place of demo.toml
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<String>,
+    /// Prints errors and warnings without colour, as NO_COLOR does
+    #[arg(long)]
+    pub no_color: bool,
+    #[command(subcommand)]
+    pub command: Command
+}
+
+/// What the runner can do. Each variant is a subcommand, and its fields are the
+/// subcommand's arguments.
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Runs a demo, passing it the arguments after its name
+    Run {
+        /// The demo's name, or the number of a numbered one (see `demo list`)
+        #[arg(value_parser = demo_names(), hide_possible_values = true)]
+        demo: String,
+        /// The demo's own arguments, which it reads itself
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>
+    },
+    /// Lists the demos and what each shows
+    List,
+    /// Checks that demos 3 to 5 and the shared library read a file the same way, and times them
+    Compare {
+        file: String,
+        /// How many times each demo reads the file
+        #[arg(default_value_t = DEFAULT_RUNS)]
+        runs: u32
+    },
+    /// Works out statistics for a file of numbers
+    Stats {
+        file: String
+    },
+    /// Draws a histogram of a file of numbers
+    Hist {
+        /// How many ranges to count the numbers in
+        #[arg(long, value_name = "N", default_value_t = DEFAULT_BUCKETS, value_parser = above_zero::<u64>)]
+        buckets: u64,
+        /// How wide the longest bar is
+        #[arg(long, value_name = "N", default_value_t = DEFAULT_WIDTH, value_parser = above_zero::<usize>)]
+        width: usize,
+        file: String
+    },
+    /// Prints the first lines of a file, or of stdin
+    Head {
+        /// How many lines to print
+        #[arg(short = 'n', value_name = "N", default_value_t = DEFAULT_LINES)]
+        lines: usize,
+        file: Option<String>
+    },
+    /// Prints the last lines of a file, or of stdin
+    Tail {
+        /// Carries on printing what's added to the file, until Ctrl-C
+        #[arg(short = 'f')]
+        follow: bool,
+        /// How many lines to print
+        #[arg(short = 'n', value_name = "N", default_value_t = DEFAULT_LINES)]
+        lines: usize,
+        file: Option<String>
+    },
+    /// Prints a script which completes the commands and demo names in a shell
+    Completions {
+        shell: Shell
+    },
+    /// Prints a man page for the runner
+    Manpage
+}
+
+/// Writes a completion script for `shell`, generated from `Cli`.
+///
+/// Everything clap knows about the command line - the subcommands, their options,
+/// and the demos `run` accepts - goes into the script, so it's never out of date
+/// with the parser. System.CommandLine gets the same from `dotnet-suggest`, which
+/// asks the program itself at each Tab; these scripts are generated once and
+/// sourced by the shell.
+///
+/// clap_complete panics if it can't write, so the script is generated into memory
+/// and written out in one go - a stdout closed early is an error, not a crash.
+pub fn completions<W: Write>(shell: Shell, out: &mut W) -> io::Result<()> {
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut Cli::command(), "demo", &mut script);
+    out.write_all(&script)
+}
+
+/// Writes a man page for the runner, in roff, generated from `Cli` as the
+/// `--help` text is
+pub fn manpage<W: Write>(out: &mut W) -> io::Result<()> {
+    Man::new(Cli::command()).render(out)
+}
+
+// The demos a `run` can name, so a misspelt one gets a suggestion like a misspelt
+// subcommand does. The numbered demos can be named by number alone, as
+// `registry::find` allows
+fn demo_names() -> PossibleValuesParser {
+    let names = registry::all().into_iter().map(|demo| {
+        let name = PossibleValue::new(demo.name());
+        match demo.name().strip_prefix("demo") {
+            Some(number) if number.parse::<u32>().is_ok() => name.alias(number),
+            _                                             => name
+        }
+    });
+    PossibleValuesParser::new(names)
+}
+
+// A number which has to be above 0, for the parser to check rather than the command
+fn above_zero<T>(value: &str) -> Result<T, String>
+    where T: FromStr + Default + PartialEq, T::Err: Display
+{
+    match value.parse() {
+        Ok(n) if n == T::default() => Err("needs to be above 0".to_string()),
+        Ok(n)                      => Ok(n),
+        Err(e)                     => Err(e.to_string())
+    }
+}
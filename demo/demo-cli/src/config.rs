@@ -0,0 +1,155 @@
+//! The runner's settings, layered from demo.toml, the environment and the command
+//! line.
+//!
+//! This is how ASP.NET builds its `IConfiguration`: appsettings.json, then
+//! environment variables, then command-line arguments, each overriding the one
+//! before where it says anything. Here each layer is a `Config`, and `over` lays
+//! one on top of another.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use clap::ValueEnum;
+use log::debug;
+use serde::Deserialize;
+
+use demo_core::registry::DemoError;
+
+/// The file the runner reads its settings from, if it exists
+pub const CONFIG_FILE: &str = "demo.toml";
+
+/// The environment variable which overrides `format`
+pub const FORMAT_VAR: &str = "DEMO_FORMAT";
+
+/// The environment variable which overrides `input`
+pub const INPUT_VAR: &str = "DEMO_INPUT";
+
+/// How the runner prints what a command did. `ValueEnum` lets `--format` take one
+/// (see cli.rs), and `FromStr` lets `DEMO_FORMAT`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// As the command prints it
+    Text,
+    /// As a JSON report (see report.rs)
+    Json
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other  => Err(format!("Unknown format: {} (expected text or json)", other))
+        }
+    }
+}
+
+/// One layer of settings. Anything it doesn't set is left to the layers beneath.
+///
+/// demo.toml looks like this:
+///
+/// ```toml
+/// format = "text"
+/// input = "numbers.txt"
+///
+/// [demos.hist]
+/// args = ["--buckets", "5"]
+///
+/// [demos.csv]
+/// input = "orders.csv"
+/// ```
+///
+/// `default` lets the file leave anything out, and `deny_unknown_fields` makes a
+/// misspelt setting an error rather than something silently ignored.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub format: Option<Format>,
+    /// The file a demo reads when it's run without any arguments
+    pub input: Option<String>,
+    /// Settings for each demo, by name
+    pub demos: BTreeMap<String, DemoConfig>
+}
+
+/// The settings for one demo
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DemoConfig {
+    /// Arguments which always go first, such as options the demo should run with
+    pub args: Vec<String>,
+    /// The file this demo reads when it's run without any arguments, in place of
+    /// the top-level `input`
+    pub input: Option<String>
+}
+
+impl Config {
+    /// Reads the settings in the TOML file at `path`
+    pub fn load(path: &Path) -> Result<Config, DemoError> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| DemoError::Config(format!("{}: {}", path.display(), e)))?;
+        debug!("reading settings from {}", path.display());
+        toml::from_str(&text).map_err(|e| {
+            // Said the way ReadError says where a bad line is: demo.toml:3: ...
+            match e.span() {
+                Some(span) => DemoError::Config(format!("{}:{}: {}", path.display(), text[..span.start].matches('\n').count() + 1, e.message())),
+                None       => DemoError::Config(format!("{}: {}", path.display(), e.message()))
+            }
+        })
+    }
+
+    /// Reads demo.toml if there is one, or starts from nothing if there isn't -
+    /// like `AddJsonFile("appsettings.json", optional: true)`
+    pub fn load_default() -> Result<Config, DemoError> {
+        let path = Path::new(CONFIG_FILE);
+        if path.exists() { Config::load(path) } else { Ok(Config::default()) }
+    }
+
+    /// The settings in the environment. `var` looks a variable up, which is
+    /// `env::var` apart from in tests.
+    pub fn from_env<F: Fn(&str) -> Option<String>>(var: F) -> Result<Config, DemoError> {
+        let format = match var(FORMAT_VAR) {
+            Some(format) => Some(format.parse().map_err(|e| DemoError::Config(format!("{}: {}", FORMAT_VAR, e)))?),
+            None         => None
+        };
+        Ok(Config { format, input: var(INPUT_VAR), demos: BTreeMap::new() })
+    }
+
+    /// These settings with `layer`'s on top: wherever `layer` sets something, it
+    /// wins. Each demo's settings are layered the same way.
+    pub fn over(mut self, layer: Config) -> Config {
+        self.format = layer.format.or(self.format);
+        self.input = layer.input.or(self.input);
+        for (name, demo) in layer.demos {
+            let below = self.demos.entry(name).or_default();
+            if !demo.args.is_empty() {
+                below.args = demo.args;
+            }
+            below.input = demo.input.or(below.input.take());
+        }
+        self
+    }
+
+    /// The format to print in, text unless a layer said otherwise
+    pub fn format(&self) -> Format {
+        self.format.unwrap_or(Format::Text)
+    }
+
+    /// The arguments to run demo `name` with, given the ones on the command line:
+    /// its configured `args` first, then either those given or, if there were none,
+    /// its input file.
+    pub fn demo_args(&self, name: &str, given: Vec<String>) -> Vec<String> {
+        let demo = self.demos.get(name);
+        let mut args: Vec<String> = demo.map(|demo| demo.args.clone()).unwrap_or_default();
+        if given.is_empty() {
+            args.extend(demo.and_then(|demo| demo.input.clone()).or_else(|| self.input.clone()));
+        } else {
+            args.extend(given);
+        }
+        args
+    }
+}
@@ -0,0 +1,20 @@
+//! What the `demo` runner is built from, besides demo-core: its command line, its
+//! settings and its JSON reports.
+//!
+//! None of the demos need these, so they live here rather than in demo-core, and
+//! the library has no clap in it. They're a library of their own, next to
+//! main.rs, so the tests in `tests/` can reach them - a binary's modules can't be
+//! used from outside it.
+
+extern crate clap;
+extern crate clap_complete;
+extern crate clap_mangen;
+extern crate demo_core;
+extern crate log;
+extern crate serde;
+extern crate serde_json;
+extern crate toml;
+
+pub mod cli;
+pub mod config;
+pub mod report;
@@ -0,0 +1,161 @@
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process;
+
+extern crate clap;
+extern crate demo_cli;
+extern crate demo_core;
+extern crate log;
+extern crate serde_json;
+
+use clap::{ CommandFactory, FromArgMatches };
+use log::debug;
+
+use demo_cli::cli::{ self, Cli, Command };
+use demo_cli::config::{ Config, Format };
+use demo_cli::report::{ self, ErrorReport };
+use demo_core::args;
+use demo_core::ReadError;
+use demo_core::compare;
+use demo_core::diagnostics;
+use demo_core::exit_codes::Exit;
+use demo_core::head;
+use demo_core::hist;
+use demo_core::registry;
+use demo_core::registry::DemoError;
+use demo_core::stats;
+use demo_core::tail;
+
+// Exit implements std::process::Termination, which turns our result into
+// a different exit code for each kind of failure (see exit_codes.rs)
+fn main() -> Exit {
+    let args: Vec<String> = env::args().collect();
+    let mut command = Cli::command();
+    let matches = match command.try_get_matches_from_mut(&args) {
+        Ok(matches) => matches,
+        Err(e) => return parse_error(e)
+    };
+    let cli = match Cli::from_arg_matches(&matches) {
+        Ok(cli) => cli,
+        Err(e) => return parse_error(e)
+    };
+
+    if cli.no_color {
+        diagnostics::disable_color();
+    }
+    diagnostics::init_logging();
+
+    let result = configure_and_dispatch(cli, &args[1..]);
+    if let Err(ref e) = result {
+        if report::json_errors() {
+            print_error_report(e);
+            return Exit(result);
+        }
+        match *e {
+            // An error a command or demo found in its own arguments, which clap
+            // passed through to it
+            DemoError::Usage(_) => {
+                diagnostics::error(e);
+                print_usage(&mut command, matches.subcommand_name());
+            },
+            _ => diagnostics::report_error(e)
+        }
+    }
+    Exit(result)
+}
+
+// clap's errors, which it prints itself, with a suggestion for anything misspelt.
+// --help and --version are errors too, so that parsing stops there, but they
+// print to stdout and succeed
+fn parse_error(e: clap::Error) -> Exit {
+    if !e.use_stderr() {
+        let _ = e.print();
+        return Exit(Ok(()));
+    }
+    let message = e.to_string();
+    let error = DemoError::Usage(message.lines().next().unwrap_or_default().trim_start_matches("error: ").to_string());
+    if report::json_errors() {
+        print_error_report(&error);
+    } else {
+        // To stderr, in colour as the diagnostics are
+        let _ = e.print();
+    }
+    Exit(Err(error))
+}
+
+// The runner's own options are the top layer of its settings (see config.rs):
+// demo.toml, then the environment, then these
+fn configure_and_dispatch(cli: Cli, args: &[String]) -> Result<(), DemoError> {
+    let file = match cli.config {
+        Some(ref config_file) => Config::load(Path::new(config_file))?,
+        None                  => Config::load_default()?
+    };
+    let layer = Config { format: cli.format, ..Config::default() };
+    let config = file.over(Config::from_env(|name| env::var(name).ok())?).over(layer);
+
+    // The child of a --format json run prints text for its parent to read, whatever
+    // the settings it was given say
+    if report::json_errors() {
+        return dispatch(cli.command, &config);
+    }
+    match config.format() {
+        Format::Text => dispatch(cli.command, &config),
+        // --format json runs the command again as a child process, and prints what
+        // it did as a single JSON object (see report.rs)
+        Format::Json => {
+            let run = report::run_as_json(args).map_err(ReadError::from)?;
+            println!("{}", serde_json::to_string(&run).expect("reports always serialize"));
+            process::exit(run.exit_code)
+        }
+    }
+}
+
+fn print_error_report(e: &DemoError) {
+    eprintln!("{}", serde_json::to_string(&ErrorReport::of(e)).expect("reports always serialize"));
+}
+
+// How to use the subcommand which went wrong, or the runner if there wasn't one
+fn print_usage(command: &mut clap::Command, subcommand: Option<&str>) {
+    let usage = match subcommand.and_then(|name| command.find_subcommand_mut(name)) {
+        Some(subcommand) => subcommand.render_usage(),
+        None             => command.render_usage()
+    };
+    eprintln!("{}", usage);
+    eprintln!("\nFor more information, try '--help'.");
+}
+
+fn list() {
+    for demo in registry::all() {
+        println!("{:<10} {:<30} {}", demo.name(), demo.description(), demo.technique());
+    }
+}
+
+fn dispatch(command: Command, config: &Config) -> Result<(), DemoError> {
+    let (name, args) = match command {
+        Command::Run { demo, args } => (demo, args),
+        Command::List => {
+            list();
+            return Ok(());
+        },
+        Command::Compare { file, runs } => return compare::run(&file, runs),
+        Command::Stats { file } => return stats::run(&file),
+        Command::Hist { buckets, width, file } => return hist::run(&file, buckets, width),
+        Command::Head { lines, file } => return head::run(lines, file.as_deref()),
+        Command::Tail { follow, lines, file } => return tail::run(follow, lines, file.as_deref()),
+        Command::Completions { shell } => return cli::completions(shell, &mut io::stdout()).map_err(DemoError::Write),
+        Command::Manpage => return cli::manpage(&mut io::stdout()).map_err(DemoError::Write)
+    };
+
+    // Each demo sees its own name in place of the program name,
+    // so `demo run 5 numbers.txt` looks like `demo5 numbers.txt` to demo5
+    let mut demo_args = vec![name.clone()];
+    // demo.toml can give a demo arguments of its own, and a file to read when it's
+    // given none
+    demo_args.append(&mut config.demo_args(&name, args::expand_globs(&args)?));
+    debug!("running {} with {:?}", name, &demo_args[1..]);
+    match registry::find(&name) {
+        Some(demo) => demo.run(&demo_args),
+        None       => Err(DemoError::Usage(format!("Unknown demo: {} (see `demo list`)", name)))
+    }
+}
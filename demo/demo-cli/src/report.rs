@@ -0,0 +1,160 @@
+//! Machine-readable reports of a run, for `demo --format json`.
+//!
+//! The demos print as they go, straight to stdout, so the runner can't simply ask
+//! one for its result. Instead it runs itself again as a child process, with
+//! `DEMO_ERRORS=json` set, and collects what the child prints - the way a C#
+//! program would start a `Process` with `RedirectStandardOutput`. The child reports
+//! any error it returns as JSON on stderr rather than as text, so the parent gets
+//! the error's kind and where it happened without having to parse a message.
+
+use std::env;
+use std::error::Error;
+use std::io;
+use std::process::{ Command, Stdio };
+
+use log::debug;
+use serde::{ Deserialize, Serialize };
+
+use demo_core::ReadError;
+use demo_core::exit_codes;
+use demo_core::registry::DemoError;
+
+/// The environment variable which tells a child process to report its error as JSON
+pub const ERRORS_VAR: &str = "DEMO_ERRORS";
+
+/// An error, as the JSON output describes it
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorReport {
+    /// What went wrong: `usage`, `io`, `parse`, `decode`, `lineTooLong`, `timedOut`,
+    /// `write`, `mismatch`, `cancelled`, `handler`, `config`, `overflow` or `invalid` -
+    /// or `other`, for anything on stderr which wasn't a report, such as a panic
+    pub kind: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// The error's source chain - what the text output prints as "Caused by"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>
+}
+
+impl ErrorReport {
+    /// Describes `e`, looking through any file it's attributed to for the error
+    /// underneath
+    pub fn of(e: &DemoError) -> ErrorReport {
+        let mut report = ErrorReport {
+            kind: String::new(),
+            message: e.to_string(),
+            file: None,
+            line: None,
+            column: None,
+            causes: Vec::new()
+        };
+        report.kind = match *e {
+            DemoError::Usage(_)     => "usage".to_string(),
+            DemoError::Read(ref e)  => report.describe(e),
+            DemoError::Write(_)     => "write".to_string(),
+            DemoError::Parse(ref e) => report.describe(e),
+            DemoError::Mismatch(_)  => "mismatch".to_string(),
+            DemoError::Cancelled    => "cancelled".to_string(),
+            DemoError::Handler(_)   => "handler".to_string(),
+            DemoError::Config(_)    => "config".to_string(),
+            DemoError::Overflow(_)  => "overflow".to_string(),
+            DemoError::Invalid(_)   => "invalid".to_string()
+        };
+        let mut cause = e.source();
+        while let Some(e) = cause {
+            report.causes.push(e.to_string());
+            cause = e.source();
+        }
+        report
+    }
+
+    // Fills in the file and position, and returns the kind
+    fn describe<E>(&mut self, e: &ReadError<E>) -> String {
+        let kind = match *e {
+            ReadError::Io(_)            => "io",
+            ReadError::Parse(_)         => "parse",
+            ReadError::BadLine(ref e)   => {
+                self.line = Some(e.line);
+                self.column = e.column;
+                "parse"
+            },
+            ReadError::Decode(_)        => "decode",
+            ReadError::LineTooLong(line, _) => {
+                self.line = Some(line);
+                "lineTooLong"
+            },
+            ReadError::TimedOut(_)      => "timedOut",
+            ReadError::InFile(ref path, ref e) => {
+                self.file = Some(path.display().to_string());
+                return self.describe(e);
+            }
+        };
+        kind.to_string()
+    }
+}
+
+/// One run of a command, as `--format json` prints it
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunReport {
+    /// The arguments the command was run with, such as
+    /// `["--format", "json", "run", "5", "numbers.txt"]`
+    pub command: Vec<String>,
+    pub exit_code: i32,
+    /// What the command printed, a line at a time
+    pub output: Vec<String>,
+    pub error: Option<ErrorReport>
+}
+
+/// Whether this process is a child of `run_as_json`, and should print text, with
+/// its error reported as JSON
+pub fn json_errors() -> bool {
+    env::var_os(ERRORS_VAR).is_some_and(|format| format == "json")
+}
+
+/// Runs this program again with `args`, collecting what it prints into a `RunReport`.
+///
+/// Stdin is passed through, so a demo which reads it still can. Demos 0 and 2 are
+/// written the way the slides show them, so they panic rather than return their
+/// errors - that's an `error` of kind `other`, with the panic message as its message.
+pub fn run_as_json(args: &[String]) -> io::Result<RunReport> {
+    // The child is given the same arguments, --format json and all, and the same
+    // environment, so it reads the same settings - but ERRORS_VAR tells it to print
+    // text for this to read, whatever they say, and NO_COLOR to leave the colour out.
+    // Its log messages would go to the stderr this reads, among its report, so it
+    // doesn't get RUST_LOG
+    debug!("running {:?} as a child", args);
+    let child = Command::new(env::current_exe()?)
+        .args(args)
+        .env(ERRORS_VAR, "json")
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_LOG")
+        .stdin(Stdio::inherit())
+        .output()?;
+    let stderr = String::from_utf8_lossy(&child.stderr);
+    let error = match serde_json::from_str(stderr.trim()) {
+        Ok(report) => Some(report),
+        Err(_) if stderr.trim().is_empty() => None,
+        Err(_) => Some(ErrorReport {
+            kind: "other".to_string(),
+            message: stderr.trim().to_string(),
+            file: None,
+            line: None,
+            column: None,
+            causes: Vec::new()
+        })
+    };
+    Ok(RunReport {
+        command: args.to_vec(),
+        // A child killed by a signal has no exit code; report it as a bug in the demos
+        exit_code: child.status.code().unwrap_or(exit_codes::SOFTWARE),
+        output: String::from_utf8_lossy(&child.stdout).lines().map(String::from).collect(),
+        error
+    })
+}
@@ -0,0 +1,72 @@
+//! The runner's command line, as clap parses it.
+
+extern crate clap;
+extern crate clap_complete;
+extern crate demo_cli;
+
+use clap::{ CommandFactory, Parser };
+use clap::error::ErrorKind;
+use clap_complete::Shell;
+
+use demo_cli::cli::{ completions, manpage, Cli, Command };
+use demo_cli::config::Format;
+
+fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+    Cli::try_parse_from(["demo"].iter().chain(args))
+}
+
+#[test]
+fn the_declaration_is_consistent() {
+    Cli::command().debug_assert();
+}
+
+#[test]
+fn runner_options_come_before_the_command() {
+    let cli = parse(&["--format", "json", "--config", "a.toml", "stats", "numbers.txt"]).unwrap();
+    assert_eq!(cli.format, Some(Format::Json));
+    assert_eq!(cli.config.as_deref(), Some("a.toml"));
+    assert_eq!(cli.command, Command::Stats { file: "numbers.txt".to_string() });
+}
+
+#[test]
+fn a_demo_gets_every_argument_after_its_name() {
+    let cli = parse(&["run", "5", "-l", "--width", "3", "numbers.txt"]).unwrap();
+    let args = ["-l", "--width", "3", "numbers.txt"].iter().map(|a| a.to_string()).collect();
+    assert_eq!(cli.command, Command::Run { demo: "5".to_string(), args });
+}
+
+#[test]
+fn options_have_defaults_and_are_checked() {
+    assert_eq!(parse(&["hist", "numbers.txt"]).unwrap().command, Command::Hist { buckets: 10, width: 40, file: "numbers.txt".to_string() });
+    assert_eq!(parse(&["tail", "-f", "-n", "3", "a.txt"]).unwrap().command, Command::Tail { follow: true, lines: 3, file: Some("a.txt".to_string()) });
+    assert_eq!(parse(&["hist", "--buckets", "0", "numbers.txt"]).unwrap_err().kind(), ErrorKind::ValueValidation);
+    assert_eq!(parse(&["head", "-n", "x"]).unwrap_err().kind(), ErrorKind::ValueValidation);
+}
+
+#[test]
+fn misspellings_are_errors_with_a_suggestion() {
+    let e = parse(&["lsit"]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidSubcommand);
+    assert!(e.to_string().contains("'list'"));
+    let e = parse(&["run", "sortng"]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidValue);
+    assert!(e.to_string().contains("a similar value exists: 'sorting'"));
+}
+
+#[test]
+fn completions_know_the_commands_and_the_demos() {
+    let mut script = Vec::new();
+    completions(Shell::Bash, &mut script).unwrap();
+    let script = String::from_utf8(script).unwrap();
+    assert!(script.contains("complete -F _demo"));
+    assert!(script.contains("completions") && script.contains("sorting"));
+}
+
+#[test]
+fn the_man_page_documents_each_command() {
+    let mut page = Vec::new();
+    manpage(&mut page).unwrap();
+    let page = String::from_utf8(page).unwrap();
+    assert!(page.starts_with(".ie") && page.contains(".TH demo 1"));
+    assert!(page.contains("Draws a histogram of a file of numbers"));
+}
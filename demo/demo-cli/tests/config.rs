@@ -0,0 +1,51 @@
+//! The runner's settings, layered from a file, the environment and the command line.
+
+extern crate demo_cli;
+
+use std::path::Path;
+
+use demo_cli::config::{ Config, DemoConfig, Format };
+
+fn strings(args: &[&str]) -> Vec<String> {
+    args.iter().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn later_layers_win_where_they_say_anything() {
+    let file = Config::load(Path::new("tests/fixtures/demo.toml")).unwrap();
+    let env = Config::from_env(|name| match name {
+        "DEMO_FORMAT" => Some("json".to_string()),
+        _             => None
+    }).unwrap();
+    let cli = Config { format: Some(Format::Text), ..Config::default() };
+
+    let config = file.clone().over(env.clone());
+    assert_eq!(config.format(), Format::Json);
+    assert_eq!(config.input.as_deref(), Some("numbers.txt"));
+    assert_eq!(config.over(cli).format(), Format::Text);
+    assert_eq!(Config::default().format(), Format::Text);
+}
+
+#[test]
+fn demos_get_their_args_and_an_input_when_given_none() {
+    let config = Config::load(Path::new("tests/fixtures/demo.toml")).unwrap();
+    assert_eq!(config.demo_args("dedup", vec![]), strings(&["-c", "visits.txt"]));
+    assert_eq!(config.demo_args("dedup", strings(&["text.txt"])), strings(&["-c", "text.txt"]));
+    assert_eq!(config.demo_args("sorting", vec![]), strings(&["numbers.txt"]));
+}
+
+#[test]
+fn demo_settings_layer_field_by_field() {
+    let mut below = Config::default();
+    below.demos.insert("hist".to_string(), DemoConfig { args: strings(&["--width", "9"]), input: Some("a.txt".to_string()) });
+    let mut above = Config::default();
+    above.demos.insert("hist".to_string(), DemoConfig { args: vec![], input: Some("b.txt".to_string()) });
+    assert_eq!(below.over(above).demo_args("hist", vec![]), strings(&["--width", "9", "b.txt"]));
+}
+
+#[test]
+fn bad_settings_say_where_they_are() {
+    let e = Config::load(Path::new("tests/fixtures/bad-config.toml")).unwrap_err();
+    assert_eq!(e.to_string(), "tests/fixtures/bad-config.toml:2: unknown field `inptu`, expected one of `format`, `input`, `demos`");
+    assert!(Config::from_env(|_| Some("xml".to_string())).is_err());
+}
@@ -0,0 +1,95 @@
+//! Errors and warnings on stderr, coloured unless told otherwise.
+
+extern crate demo_core;
+
+use std::fs::File;
+use std::process::{ Command, Output };
+
+use demo_core::diagnostics::Severity;
+use demo_core::exit_codes;
+
+// Where the runner is run from: the workspace's directory, as for `cargo run`
+const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+// Runs `demo` as if stderr were a terminal, which a test's pipe isn't
+fn run_coloured(args: &[&str], no_color: bool) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_demo"));
+    command.args(args)
+        .current_dir(WORKSPACE_DIR)
+        .env("CLICOLOR_FORCE", "1")
+        .env_remove("NO_COLOR");
+    if no_color {
+        command.env("NO_COLOR", "1");
+    }
+    command.output().expect("could not start demo")
+}
+
+#[test]
+fn errors_are_red_and_warnings_yellow() {
+    assert_eq!(Severity::Error.style().render().to_string(), "\x1b[1m\x1b[31m");
+    assert_eq!(Severity::Warning.style().render().to_string(), "\x1b[1m\x1b[33m");
+
+    let output = run_coloured(&["run", "lenient", "demo-core/tests/fixtures/non-numeric.txt"], false);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("\x1b[1m\x1b[33mWarning:\x1b[0m Skipped 1 lines:"), "{:?}", stderr);
+    // Results stay plain, on stdout
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "1\n3\nRead 2 numbers, sum 4, from 1 to 3, mean 2.00\n");
+}
+
+#[test]
+fn no_color_turns_the_colour_off() {
+    for (args, no_color) in [(&["--no-color", "run", "6", "missing.txt"][..], false), (&["run", "6", "missing.txt"][..], true)] {
+        let output = run_coloured(args, no_color);
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.starts_with("Error: missing.txt: "), "{:?}", stderr);
+        assert!(!stderr.contains('\x1b'));
+    }
+}
+
+// Runs `demo` with RUST_BACKTRACE set to `backtrace`, returning what it printed to stderr
+fn stderr_with_backtrace(args: &[&str], backtrace: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_demo"))
+        .args(args)
+        .current_dir(WORKSPACE_DIR)
+        .env("RUST_BACKTRACE", backtrace)
+        .env_remove("RUST_LIB_BACKTRACE")
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("could not start demo");
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn each_cause_is_indented_under_the_one_before() {
+    let stderr = stderr_with_backtrace(&["run", "anyhow", "demo-core/tests/fixtures/missing.txt"], "0");
+    assert!(stderr.starts_with(
+        "Error: could not read file\n  Caused by: in demo-core/tests/fixtures/missing.txt\n    Caused by: reading numbers file\n      Caused by: "
+    ), "{:?}", stderr);
+}
+
+#[test]
+fn rust_backtrace_adds_a_backtrace() {
+    let stderr = stderr_with_backtrace(&["run", "6", "missing.txt"], "0");
+    assert!(!stderr.contains("Backtrace:"), "{:?}", stderr);
+    let stderr = stderr_with_backtrace(&["run", "6", "missing.txt"], "1");
+    assert!(stderr.starts_with("Error: missing.txt: could not read file\n"), "{:?}", stderr);
+    assert!(stderr.contains("  Backtrace:\n"), "{:?}", stderr);
+    assert!(stderr.contains("report_error"), "{:?}", stderr);
+}
+
+// /dev/full fails every write, as a stdout closed before the output is done would
+#[cfg(target_os = "linux")]
+#[test]
+fn output_which_cant_be_written_is_an_error_not_a_panic() {
+    for args in [&["completions", "bash"][..], &["manpage"][..]] {
+        let output = Command::new(env!("CARGO_BIN_EXE_demo"))
+            .args(args)
+            .stdout(File::create("/dev/full").unwrap())
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("could not start demo");
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.starts_with("Error: could not write output\n  Caused by: "), "{:?}", stderr);
+        assert_eq!(output.status.code(), Some(exit_codes::IO));
+    }
+}
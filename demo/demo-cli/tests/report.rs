@@ -0,0 +1,37 @@
+//! How errors are described for `--format json`.
+
+extern crate demo_cli;
+extern crate demo_core;
+
+use std::io;
+
+use demo_cli::report::ErrorReport;
+use demo_core::ReadError;
+use demo_core::numbers::parse_line;
+use demo_core::registry::DemoError;
+
+#[test]
+fn parse_errors_say_which_file_and_line() {
+    let e = parse_line::<u64>(3, "x").unwrap_err();
+    let report = ErrorReport::of(&DemoError::Read(ReadError::from(e).in_file("numbers.txt")));
+    assert_eq!(report.kind, "parse");
+    assert_eq!(report.file.as_deref(), Some("numbers.txt"));
+    assert_eq!(report.line, Some(3));
+    assert_eq!(report.column, None);
+}
+
+#[test]
+fn io_errors_carry_their_causes() {
+    let e = ReadError::Io(io::Error::new(io::ErrorKind::NotFound, "gone")).in_file("a.txt");
+    let report = ErrorReport::of(&DemoError::Read(e));
+    assert_eq!((&report.kind[..], &report.message[..]), ("io", "a.txt: could not read file"));
+    assert_eq!(report.causes, vec!["gone"]);
+}
+
+#[test]
+fn other_errors_have_only_a_kind_and_message() {
+    let report = ErrorReport::of(&DemoError::Cancelled);
+    assert_eq!(report.kind, "cancelled");
+    assert_eq!(report.file, None);
+    assert!(report.causes.is_empty());
+}
@@ -0,0 +1,338 @@
+//! Golden output tests: each test runs the `demo` binary, captures its stdout,
+//! stderr and exit code, and compares them with a snapshot in `tests/snapshots`.
+//!
+//! A change to any message - deliberate or not - fails the test. To accept the
+//! new output, run `UPDATE_SNAPSHOTS=1 cargo test` and review the diff.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{ Command, Stdio };
+
+// The workspace's directory, where `cargo run` is run from and the sample files are.
+// The demos' fixtures are demo-core's, in demo-core/tests/fixtures, and the runner's
+// settings files are in demo-cli/tests/fixtures
+const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+// The settings file a run is given unless it names one of its own, so a demo.toml
+// in the workspace's directory can't change what the demos print. A bare `demo`
+// stops at its usage before it reads any
+const NO_SETTINGS: &str = "demo-cli/tests/fixtures/empty.toml";
+
+// Runs `demo` with `args` (and `stdin`, if any) from the workspace's directory, so
+// file paths are the same on every machine, and without any of the runner's
+// settings from the environment or demo.toml
+fn run(args: &[&str], stdin: Option<&str>) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_demo"));
+    if !args.is_empty() && !args.contains(&"--config") {
+        command.args(["--config", NO_SETTINGS]);
+    }
+    for (name, _) in env::vars_os() {
+        if name.to_string_lossy().starts_with("DEMO_") {
+            command.env_remove(name);
+        }
+    }
+    let mut child = command
+        .args(args)
+        .current_dir(WORKSPACE_DIR)
+        .env("RUST_BACKTRACE", "0")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_LOG")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not start demo");
+    // Dropping stdin closes it, so demos reading it see the end of their input.
+    // A demo which doesn't read stdin may already have exited, so a failed write is fine
+    let _ = child.stdin.take().unwrap().write_all(stdin.unwrap_or("").as_bytes());
+    let output = child.wait_with_output().unwrap();
+
+    format!("$ {}\nexit code: {}\n--- stdout\n{}--- stderr\n{}",
+        ["demo"].iter().chain(args).cloned().collect::<Vec<_>>().join(" "),
+        output.status.code().map_or("none".to_string(), |code| code.to_string()),
+        String::from_utf8_lossy(&output.stdout),
+        normalize(&String::from_utf8_lossy(&output.stderr)))
+}
+
+// Panic messages include the thread's id and the line of source which panicked,
+// neither of which is worth failing a test over
+fn normalize(stderr: &str) -> String {
+    stderr.lines()
+        .map(|line| if line.starts_with("thread '") && line.contains(" panicked at ") {
+            "thread 'main' panicked at:".to_string()
+        } else {
+            line.to_string()
+        })
+        .map(|line| line + "\n")
+        .collect()
+}
+
+// A duration as Debug prints it - `1.5µs`, `20ms` - which no two runs agree on
+fn is_duration(word: &str) -> bool {
+    ["ns", "µs", "ms", "s"].iter()
+        .filter_map(|unit| word.strip_suffix(unit))
+        .next()
+        .is_some_and(|number| number.starts_with(|c: char| c.is_ascii_digit()) && number.chars().all(|c| c.is_ascii_digit() || c == '.'))
+}
+
+// A duration padded to a width takes up more or less of it from run to run, so
+// runs of spaces are collapsed along with it
+fn without_times(output: &str) -> String {
+    output.lines()
+        .map(|line| line.split_whitespace().map(|word| if is_duration(word) { "<time>" } else { word }).collect::<Vec<_>>().join(" "))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+fn check(name: &str, args: &[&str], stdin: Option<&str>) {
+    compare(name, run(args, stdin));
+}
+
+// For a demo which times itself: its durations are left out of the comparison
+fn check_timed(name: &str, args: &[&str], stdin: Option<&str>) {
+    compare(name, without_times(&run(args, stdin)));
+}
+
+fn compare(name: &str, actual: String) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join(format!("{}.txt", name));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("no snapshot at {} - run with UPDATE_SNAPSHOTS=1 to create it", path.display()));
+    assert_eq!(actual, expected, "output differs from {}", path.display());
+}
+
+#[test]
+fn demo0() {
+    check("demo0-number", &["run", "0", "42"], None);
+    check("demo0-not-a-number", &["run", "0", "abc"], None);
+}
+
+#[test]
+fn demo1() {
+    check("demo1-number", &["run", "1", "42"], None);
+    check("demo1-not-a-number", &["run", "1", "abc"], None);
+    check("demo1-no-arguments", &["run", "1"], None);
+}
+
+#[test]
+fn demo2() {
+    check("demo2-valid", &["run", "2", "demo-core/tests/fixtures/valid.txt"], None);
+    check("demo2-missing", &["run", "2", "demo-core/tests/fixtures/missing.txt"], None);
+    check("demo2-several", &["run", "2", "demo-core/tests/fixtures/valid.txt", "demo-core/tests/fixtures/non-numeric.txt"], None);
+}
+
+#[test]
+fn demo3_and_5() {
+    for demo in &["3", "5"] {
+        for fixture in &["valid", "missing", "bad-utf8"] {
+            let file = format!("demo-core/tests/fixtures/{}.txt", fixture);
+            check(&format!("demo{}-{}", demo, fixture), &["run", demo, &file], None);
+        }
+        check(&format!("demo{}-several", demo), &["run", demo, "demo-core/tests/fixtures/valid.txt", "demo-core/tests/fixtures/non-numeric.txt"], None);
+        check(&format!("demo{}-several-missing", demo), &["run", demo, "demo-core/tests/fixtures/valid.txt", "demo-core/tests/fixtures/missing.txt"], None);
+    }
+}
+
+#[test]
+fn demo4() {
+    check("demo4-valid", &["run", "4", "demo-core/tests/fixtures/valid.txt"], None);
+    check("demo4-missing", &["run", "4", "demo-core/tests/fixtures/missing.txt"], None);
+    check("demo4-bad-utf8", &["run", "4", "demo-core/tests/fixtures/bad-utf8.txt"], None);
+    check("demo4-non-numeric", &["run", "4", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("demo4-stdin", &["run", "4"], Some("1\n0x1F\ntwo\n"));
+    check("demo4-several", &["run", "4", "demo-core/tests/fixtures/valid.txt", "demo-core/tests/fixtures/valid.txt"], None);
+}
+
+#[test]
+fn demo6() {
+    check("demo6-valid", &["run", "6", "demo-core/tests/fixtures/valid.txt"], None);
+    check("demo6-missing", &["run", "6", "demo-core/tests/fixtures/missing.txt"], None);
+    check("demo6-bad-utf8", &["run", "6", "demo-core/tests/fixtures/bad-utf8.txt"], None);
+    check("demo6-non-numeric", &["run", "6", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("demo6-stdin", &["run", "6"], Some("0x1F\n# comment\n1_000\n"));
+    check("demo6-split", &["run", "6", "--split-on", ","], Some("1, 2,3\n4,,5\n"));
+    check("demo6-port", &["run", "6", "--as", "port"], Some("80\n0\n"));
+}
+
+#[test]
+fn demo7() {
+    check("demo7-valid", &["run", "7", "demo-core/tests/fixtures/valid.txt"], None);
+    check("demo7-non-numeric", &["run", "7", "demo-core/tests/fixtures/non-numeric.txt"], None);
+}
+
+#[test]
+fn topic_demos() {
+    check("streaming", &["run", "streaming", "demo-core/tests/fixtures/valid.txt", "2"], None);
+    check("streaming-progress", &["run", "streaming", "--progress", "demo-core/tests/fixtures/valid.txt", "2"], None);
+    check("walk", &["run", "walk", "demo-core/tests/fixtures"], None);
+    check("encoding", &["run", "encoding", "demo-core/tests/fixtures/bad-utf8.txt"], None);
+    check("lenient", &["run", "lenient", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("cancel", &["run", "cancel", "demo-core/tests/fixtures/valid.txt"], None);
+    check("timeout-usage", &["run", "timeout"], None);
+    check("atomics", &["run", "atomics", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("scoped", &["run", "scoped", "demo-core/tests/fixtures/valid.txt"], None);
+    check("scoped-non-numeric", &["run", "scoped", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("linq", &["run", "linq"], Some("5\n12\n7\n100\n3\n42\n"));
+    check("linq-empty", &["run", "linq"], Some(""));
+    check("lazy", &["run", "lazy"], Some("1\n2\n3\n40\n5\n600\n7\n"));
+    check("iterator", &["run", "iterator", "3"], Some("1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n"));
+    check("iterator-overflow", &["run", "iterator", "2", "demo-core/tests/fixtures/u64-max.txt"], None);
+    check("option", &["run", "option"], Some("5\n12\n7\n40\n"));
+    check("option-none", &["run", "option"], Some("5\n7\n"));
+    check("ownership", &["run", "ownership"], Some(" 1\n\n2 \n3\n"));
+    check("borrowing", &["run", "borrowing"], Some("1\n2\n\n3\n4\n5\n"));
+    check("lifetimes", &["run", "lifetimes", "numbers-annotated.txt"], None);
+    check("traits", &["run", "traits", "demo-core/tests/fixtures/valid.txt"], None);
+    check_timed("dispatch", &["run", "dispatch", "demo-core/tests/fixtures/valid.txt"], None);
+    check("dispatch-non-numeric", &["run", "dispatch", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("generics", &["run", "generics", "demo-core/tests/fixtures/valid.txt"], None);
+    check("generics-mixed", &["run", "generics"], Some("1\n-2\n0.5\n"));
+    check("storage", &["run", "storage", "demo-core/tests/fixtures/valid.txt"], None);
+    check("enums", &["run", "enums"], Some("# numbers\n4\n\n7\n// done\n"));
+    check("enums-non-numeric", &["run", "enums", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("patterns", &["run", "patterns", "settings.txt"], None);
+    check("structs", &["run", "structs", "demo-core/tests/fixtures/valid.txt"], None);
+    check("structs-overflow", &["run", "structs", "demo-core/tests/fixtures/u64-max.txt"], None);
+    check("rc", &["run", "rc", "demo-core/tests/fixtures/valid.txt"], None);
+    check("refcell", &["run", "refcell", "demo-core/tests/fixtures/valid.txt", "numbers.txt"], None);
+    check("pointers", &["run", "pointers"], Some("1\n 2\n1_000\n4\n5\n"));
+    check("pointers-overflow", &["run", "pointers"], Some("5000000000\n5000000000\n"));
+    check("closures", &["run", "closures"], Some("10\n\n7\n250\n3\n"));
+    check("process", &["run", "process", "numbers-annotated.txt"], None);
+    check("process-keep-comments", &["run", "process", "--keep-comments", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("conversions", &["run", "conversions"], Some("7\n255\n300\n"));
+    check("conversions-overflow", &["run", "conversions", "demo-core/tests/fixtures/u64-max.txt"], None);
+    check("validate-port", &["run", "validate", "port"], Some("80\n0\n70000\nhttp\n-1\n8080\n"));
+    check("validate-score", &["run", "validate", "score"], Some("50\n101\n75\n"));
+    check("units", &["run", "units", "runs.txt"], None);
+    check("defaults", &["run", "defaults", "numbers-annotated.txt"], None);
+    check("defaults-verbose", &["run", "defaults", "--verbose"], Some("1\n\n 2\n# three\n"));
+    check("extensions", &["run", "extensions", "numbers-annotated.txt"], None);
+    check("extensions-errors", &["run", "extensions"], Some("  42\n0x2A\n// answer\n4_2_\nforty-two\n"));
+    check("equality", &["run", "equality", "records.txt"], None);
+    check("equality-u64-max", &["run", "equality"], Some("a 18446744073709551615\nb 18446744073709551615\n"));
+    check("formatting", &["run", "formatting", "numbers.txt"], None);
+    check("formatting-empty", &["run", "formatting"], Some("none\n"));
+    check("strings", &["run", "strings", "words.txt"], None);
+    check("unicode", &["run", "unicode", "unicode.txt"], None);
+    check("collections", &["run", "collections", "text.txt"], None);
+    check("entry", &["run", "entry", "visits.txt"], None);
+    check("slices", &["run", "slices"], Some("5\n12\n40\n7\n8\n9\n300\n1\n"));
+    check("slices-overflow", &["run", "slices", "demo-core/tests/fixtures/u64-max.txt"], None);
+    check("slices-empty", &["run", "slices"], Some(""));
+    check("sorting", &["run", "sorting"], Some("30\n5\n12\n5\n40\n12\n5\n"));
+    check("wc", &["run", "wc", "-lwmc", "text.txt", "-"], Some("one two\nthree\n"));
+    check("wc-progress", &["run", "wc", "--progress", "text.txt"], None);
+    check("wc-no-final-newline", &["run", "wc"], Some("a"));
+    check("wc-bad-flag", &["run", "wc", "-lx"], None);
+    check("grep", &["run", "grep", "-i", "the \\w+", "text.txt"], None);
+    check("grep-invert", &["run", "grep", "-v", "a"], Some("a\nb\nab\n"));
+    check("grep-bad-pattern", &["run", "grep", "(", "text.txt"], None);
+    check("dedup", &["run", "dedup", "visits.txt"], None);
+    check("dedup-count", &["run", "dedup", "-c", "visits.txt"], None);
+    check("dedup-adjacent", &["run", "dedup", "-c", "--adjacent"], Some("a\na\nb\na\na\na\n"));
+    check("csv", &["run", "csv", "orders.csv"], None);
+    check("csv-invalid-utf8", &["run", "csv", "demo-core/tests/fixtures/bad-utf8.txt"], None);
+    check("json", &["run", "json", "jobs.json"], None);
+    check("json-unknown-kind", &["run", "json"], Some("[{ \"name\": \"a\", \"sourceFile\": \"a.txt\", \"output\": { \"kind\": \"pie\" } }]\n"));
+    check("env", &["run", "env"], None);
+    check("env-bad-flag", &["run", "env", "--envy"], None);
+    check("logging", &["run", "logging", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("anyhow", &["run", "anyhow", "demo-core/tests/fixtures/valid.txt"], None);
+    check("anyhow-bad", &["run", "anyhow", "demo-core/tests/fixtures/valid.txt", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("thiserror", &["run", "thiserror", "demo-core/tests/fixtures/valid.txt", "demo-core/tests/fixtures/missing.txt"], None);
+    check("panics", &["run", "panics"], None);
+    check("panics-uncaught", &["run", "panics", "uncaught"], None);
+    check("retry", &["run", "retry", "demo-core/tests/fixtures/valid.txt"], None);
+    check("retry-gives-up", &["run", "retry", "demo-core/tests/fixtures/valid.txt", "3"], None);
+    check("accumulate", &["run", "accumulate", "demo-core/tests/fixtures/servers-valid.csv"], None);
+    check("accumulate-errors", &["run", "accumulate", "demo-core/tests/fixtures/servers.csv"], None);
+    check("generator", &["run", "generator", "demo-core/tests/fixtures/valid.txt"], None);
+    check("generator-non-numeric", &["run", "generator", "demo-core/tests/fixtures/non-numeric.txt"], None);
+}
+
+// tracing is only built with its feature. Its totals are u128s, so two u64::MAX lines add up
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing() {
+    check("tracing-u64-max", &["run", "tracing", "demo-core/tests/fixtures/u64-max.txt"], None);
+}
+
+// The list includes demos from optional features, so it's only checked with the defaults
+#[cfg(not(any(feature = "async", feature = "rayon", feature = "tracing")))]
+#[test]
+fn list() {
+    check("list", &["list"], None);
+}
+
+#[test]
+fn stats() {
+    check("stats", &["stats", "numbers.txt"], None);
+    check("stats-non-numeric", &["stats", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("stats-no-file", &["stats"], None);
+}
+
+#[test]
+fn hist() {
+    check("hist", &["hist", "--buckets", "5", "--width", "30", "numbers.txt"], None);
+    check("hist-non-numeric", &["hist", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("hist-bad-option", &["hist", "--buckets", "0", "numbers.txt"], None);
+}
+
+#[test]
+fn head_and_tail() {
+    check("head", &["head", "-n", "3", "numbers.txt"], None);
+    check("tail", &["tail", "-n", "3", "text.txt"], None);
+    check("tail-stdin", &["tail", "-n", "2"], Some("1\n2\n3\n"));
+    check("tail-large-count", &["tail", "-n", "18446744073709551615"], Some("1\n2\n3\n"));
+    check("tail-bad-count", &["tail", "-n", "x", "numbers.txt"], None);
+    check("tail-follow-stdin", &["tail", "-f"], None);
+}
+
+#[test]
+fn json_format() {
+    check("format-json", &["--format", "json", "run", "5", "numbers.txt"], None);
+    check("format-json-parse-error", &["--format", "json", "run", "sorting", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("format-json-bad-line", &["--format", "json", "run", "6", "demo-core/tests/fixtures/non-numeric.txt"], None);
+    check("format-json-json-error", &["--format", "json", "run", "json", "demo-core/tests/fixtures/bad-jobs.json"], None);
+    check("format-json-invalid", &["--format", "json", "run", "accumulate", "demo-core/tests/fixtures/servers.csv"], None);
+    check("format-json-stdin", &["--format", "json", "run", "sorting"], Some("3\n1\n"));
+    check("format-unknown", &["--format", "xml", "list"], None);
+}
+
+#[test]
+fn config() {
+    check("config", &["--config", "demo-cli/tests/fixtures/demo.toml", "run", "dedup"], None);
+    check("config-json", &["--config", "demo-cli/tests/fixtures/demo.toml", "--format", "json", "run", "5"], None);
+    check("config-bad", &["--config", "demo-cli/tests/fixtures/bad-config.toml", "list"], None);
+}
+
+// Demo 6 would skip the annotated file's blank line and comment, but it isn't
+// compared, so the annotations are a parse error rather than a mismatch
+#[test]
+fn compare_command() {
+    check_timed("compare", &["compare", "demo-core/tests/fixtures/valid.txt", "2"], None);
+    check("compare-annotated", &["compare", "demo-core/tests/fixtures/annotated.txt", "2"], None);
+}
+
+#[test]
+fn runner() {
+    check("no-command", &[], None);
+    check("misspelt-command", &["lsit"], None);
+    check("run-help", &["run", "--help"], None);
+    check("completions-unknown-shell", &["completions", "fsh"], None);
+}
+
+// clap's error lists every demo it could have been, including those from optional
+// features, so like the list it's only checked with the defaults
+#[cfg(not(any(feature = "async", feature = "rayon", feature = "tracing")))]
+#[test]
+fn unknown_demo() {
+    check("unknown-demo", &["run", "nope"], None);
+}
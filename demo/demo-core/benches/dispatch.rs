@@ -0,0 +1,41 @@
+//! Benchmarks calling a `Parser` through a generic against calling it through `dyn`.
+//!
+//! For a parser which does real work, the indirect call is lost in the noise. For one
+//! which does next to nothing, the direct call can be inlined and the difference
+//! shows - which is about as large as the cost of dynamic dispatch ever gets.
+
+#[macro_use]
+extern crate criterion;
+extern crate demo_core;
+
+use std::hint::black_box;
+
+use criterion::{ BenchmarkId, Criterion, Throughput };
+
+use demo_core::demo_dispatch::{ parse_dyn, parse_static, Decimal, Length };
+
+const SIZES: &[usize] = &[100, 10_000];
+
+fn dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dispatch");
+    for &size in SIZES {
+        let lines: Vec<String> = (0..size).map(|n| (n * 7).to_string()).collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("decimal/static", size), &lines, |b, lines| {
+            b.iter(|| parse_static(&Decimal, lines).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("decimal/dyn", size), &lines, |b, lines| {
+            b.iter(|| parse_dyn(black_box(&Decimal), lines).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("length/static", size), &lines, |b, lines| {
+            b.iter(|| parse_static(&Length, lines).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("length/dyn", size), &lines, |b, lines| {
+            b.iter(|| parse_dyn(black_box(&Length), lines).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, dispatch);
+criterion_main!(benches);
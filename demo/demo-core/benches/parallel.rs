@@ -0,0 +1,34 @@
+//! Benchmarks parsing lines on one thread against parsing them with rayon.
+//!
+//! Spreading work across threads has a cost of its own, so for a handful of lines
+//! the sequential version wins. Given enough lines (and cores) the parallel one
+//! takes over - run `cargo bench --features rayon` to see where on your machine.
+
+#[macro_use]
+extern crate criterion;
+extern crate demo_core;
+
+use criterion::{ BenchmarkId, Criterion, Throughput };
+
+use demo_core::demo_parallel::{ parse_parallel, parse_sequential };
+
+const SIZES: &[usize] = &[100, 10_000, 1_000_000];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.sample_size(10);
+    for &size in SIZES {
+        let lines: Vec<String> = (0..size).map(|n| (n * 7).to_string()).collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("sequential", size), &lines, |b, lines| {
+            b.iter(|| parse_sequential(lines).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", size), &lines, |b, lines| {
+            b.iter(|| parse_parallel(lines).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
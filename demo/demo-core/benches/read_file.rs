@@ -0,0 +1,54 @@
+//! Benchmarks for the `read_file` styles of demos 3 and 5, along with the shared
+//! library's `read_lines` which they both call.
+//!
+//! Each style is run over files of a few sizes, and Criterion reports the throughput
+//! in lines per second - if matching and `try!` really are zero-cost
+//! abstractions, the per-line figures should be all but identical to the library's.
+
+#[macro_use]
+extern crate criterion;
+extern crate demo_core;
+extern crate tempfile;
+
+use std::io::Write;
+
+use criterion::{ BenchmarkId, Criterion, Throughput };
+use tempfile::NamedTempFile;
+
+use demo_core::{ demo3, demo5, read_lines };
+
+// Small, medium and large fixtures, by number of lines
+const SIZES: &[usize] = &[100, 10_000, 1_000_000];
+
+// Writes a fixture of `lines` numbers to a temp file of its own, which is deleted
+// when it's dropped - so two runs at once can't share one, or find one half written
+fn fixture(lines: usize) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("could not create fixture");
+    let text: String = (0..lines).map(|n| format!("{}\n", n * 7)).collect();
+    file.write_all(text.as_bytes()).expect("could not write fixture");
+    file
+}
+
+fn read_file(c: &mut Criterion) {
+    let mut group = c.benchmark_group("read_file");
+    // The large fixture takes a while to read, so fewer samples keep the run reasonable
+    group.sample_size(10);
+    for &lines in SIZES {
+        let file = fixture(lines);
+        let path = file.path();
+        group.throughput(Throughput::Elements(lines as u64));
+        group.bench_with_input(BenchmarkId::new("match", lines), path, |b, path| {
+            b.iter(|| demo3::read_file(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("try", lines), path, |b, path| {
+            b.iter(|| demo5::read_file(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("library", lines), path, |b, path| {
+            b.iter(|| read_lines(path).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, read_file);
+criterion_main!(benches);
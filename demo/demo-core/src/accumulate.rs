@@ -0,0 +1,98 @@
+//! Checking everything and reporting every failure, rather than stopping at the first.
+//!
+//! `?` and `collect::<Result<Vec<_>, _>>()` stop at the first error, which is what
+//! a program wants when it can't go on without the value. A form, or a file
+//! someone has to go back and fix, wants the opposite: every problem at once, as
+//! FluentValidation's `ValidationResult.Errors` lists every rule that failed.
+//!
+//! `Validated` is a `Result` whose error side is a list, and whose combinators add
+//! the lists together instead of giving up. Two checks which have nothing to do with
+//! each other both run, and `and` keeps both values or both sets of errors - in
+//! functional terms, it's applicative rather than monadic.
+
+use std::iter::FromIterator;
+
+/// The result of one or more checks: a value if they all passed, or every error
+/// from the ones which didn't
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Validated<T, E> {
+    Valid(T),
+    Invalid(Vec<E>)
+}
+
+impl<T, E> Validated<T, E> {
+    /// A check which failed with `error`
+    pub fn invalid(error: E) -> Validated<T, E> {
+        Validated::Invalid(vec![error])
+    }
+
+    /// Turns the value into another if it's valid, keeping the errors if not -
+    /// `Result::map`
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Validated<U, E> {
+        match self {
+            Validated::Valid(value)    => Validated::Valid(f(value)),
+            Validated::Invalid(errors) => Validated::Invalid(errors)
+        }
+    }
+
+    /// Turns each error into another - `Result::map_err`
+    pub fn map_err<F, G: FnMut(E) -> F>(self, f: G) -> Validated<T, F> {
+        match self {
+            Validated::Valid(value)    => Validated::Valid(value),
+            Validated::Invalid(errors) => Validated::Invalid(errors.into_iter().map(f).collect())
+        }
+    }
+
+    /// Both values if both are valid, or the errors from either or both.
+    ///
+    /// Unlike `Result::and_then`, `other` has already been worked out whatever
+    /// `self` was, so its errors aren't lost when `self` has some of its own.
+    pub fn and<U>(self, other: Validated<U, E>) -> Validated<(T, U), E> {
+        match (self, other) {
+            (Validated::Valid(a), Validated::Valid(b))         => Validated::Valid((a, b)),
+            (Validated::Valid(_), Validated::Invalid(errors))  => Validated::Invalid(errors),
+            (Validated::Invalid(errors), Validated::Valid(_))  => Validated::Invalid(errors),
+            (Validated::Invalid(mut a), Validated::Invalid(b)) => {
+                a.extend(b);
+                Validated::Invalid(a)
+            }
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(*self, Validated::Valid(_))
+    }
+
+    /// The value, or every error - for `?`, once the checking is done
+    pub fn into_result(self) -> Result<T, Vec<E>> {
+        match self {
+            Validated::Valid(value)    => Ok(value),
+            Validated::Invalid(errors) => Err(errors)
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Validated<T, E> {
+    fn from(result: Result<T, E>) -> Validated<T, E> {
+        match result {
+            Ok(value) => Validated::Valid(value),
+            Err(e)    => Validated::invalid(e)
+        }
+    }
+}
+
+/// Collecting checks gives every value if they all passed, or every error from
+/// all of them. Collecting `Result`s stops at the first `Err` instead.
+impl<T, E> FromIterator<Validated<T, E>> for Validated<Vec<T>, E> {
+    fn from_iter<I: IntoIterator<Item = Validated<T, E>>>(iter: I) -> Validated<Vec<T>, E> {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for validated in iter {
+            match validated {
+                Validated::Valid(value)      => values.push(value),
+                Validated::Invalid(mut more) => errors.append(&mut more)
+            }
+        }
+        if errors.is_empty() { Validated::Valid(values) } else { Validated::Invalid(errors) }
+    }
+}
@@ -0,0 +1,40 @@
+use glob::glob;
+
+use crate::ReadError;
+use crate::registry::DemoError;
+
+/// Expands any glob patterns (such as `data/*.txt`) in a demo's arguments.
+///
+/// Unix shells expand globs before a program ever sees them, but the Windows
+/// command prompt passes them through untouched. Expanding them ourselves means
+/// the demos behave the same everywhere. Arguments which aren't patterns, or
+/// which don't match anything, are passed through unchanged - as a shell would.
+pub fn expand_globs(args: &[String]) -> Result<Vec<String>, DemoError> {
+    let mut expanded = Vec::new();
+    for arg in args {
+        if !is_pattern(arg) {
+            expanded.push(arg.clone());
+            continue;
+        }
+        let paths = glob(arg)
+            .map_err(|e| DemoError::Usage(format!("Invalid pattern {}: {}", arg, e)))?;
+        let mut matched = Vec::new();
+        for path in paths {
+            let path = path.map_err(|e| {
+                let path = e.path().to_path_buf();
+                ReadError::Io(e.into()).in_file(path)
+            })?;
+            matched.push(path.to_string_lossy().into_owned());
+        }
+        if matched.is_empty() {
+            expanded.push(arg.clone());
+        } else {
+            expanded.append(&mut matched);
+        }
+    }
+    Ok(expanded)
+}
+
+fn is_pattern(arg: &str) -> bool {
+    arg.contains(&['*', '?', '['][..])
+}
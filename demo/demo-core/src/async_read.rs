@@ -0,0 +1,58 @@
+//! Asynchronous versions of the shared reading code, for the demos behind the
+//! `async` feature.
+
+use std::path::Path;
+use std::time::Duration;
+
+use futures::stream::{ self, Stream };
+use tokio::fs::{ self, File };
+use tokio::io::{ self, AsyncBufRead, AsyncBufReadExt, BufReader };
+use tokio::time;
+
+use crate::ReadError;
+use crate::encoding::decode;
+use crate::read::split_lines;
+
+/// Opens the named file for reading asynchronously, or stdin if no file is named.
+/// The async twin of `open_input`.
+pub async fn open_input_async(file_name: Option<&str>) -> io::Result<Box<dyn AsyncBufRead + Unpin + Send>> {
+    match file_name {
+        Some(file_name) => {
+            let file = File::open(file_name).await?;
+            Ok(Box::new(BufReader::new(file)))
+        },
+        None => Ok(Box::new(BufReader::new(io::stdin())))
+    }
+}
+
+/// The lines of an async reader, as a `Stream` - the async version of an iterator,
+/// and Rust's equivalent of `IAsyncEnumerable<string>`.
+///
+/// Like `LineStream`, nothing is read until the next line is asked for. A read error
+/// is the last item in the stream.
+pub fn line_stream<R: AsyncBufRead + Unpin>(read: R) -> impl Stream<Item = Result<String, ReadError>> {
+    // unfold builds a stream from a state and an async function which turns the
+    // state into the next item and the next state. Here the state is the lines
+    // still to read, or None once there's been an error
+    stream::unfold(Some(read.lines()), |lines| async move {
+        let mut lines = lines?;
+        match lines.next_line().await {
+            Ok(Some(line)) => Some((Ok(line), Some(lines))),
+            Ok(None)       => None,
+            Err(e)         => Some((Err(ReadError::from(e)), None))
+        }
+    })
+}
+
+/// Reads the file at `path` as a vector of lines, giving up with `ReadError::TimedOut`
+/// if that takes longer than `timeout`. The async twin of `read_with_timeout`.
+///
+/// `time::timeout` races the read against a timer, like C#'s `Task.WaitAsync(TimeSpan)`,
+/// and drops the read if the timer wins. Most futures stop there, because a future
+/// only runs while it's polled - but tokio reads files on a pool of blocking threads
+/// behind the scenes, so as with `WaitAsync` the read itself carries on regardless.
+pub async fn read_with_timeout_async<P: AsRef<Path>>(path: P, timeout: Duration) -> Result<Vec<String>, ReadError> {
+    let bytes = time::timeout(timeout, fs::read(path)).await
+        .map_err(|_| ReadError::TimedOut(timeout))??;
+    Ok(split_lines(&decode(&bytes)?))
+}
@@ -0,0 +1,69 @@
+//! The `compare` command: runs the read paths of demos 3 to 5 and the shared
+//! library on the same file, checks they agree, and times them.
+//!
+//! Matching, combinators, `try!` and the shared library are four ways of writing
+//! the same thing. This is the evidence: same numbers, similar times - so pick
+//! whichever reads best.
+//!
+//! Demo 6 reads with the shared library too, but it skips blank lines and comments,
+//! which the others would fail on. It reads annotated files differently on purpose,
+//! so it isn't one of the paths compared.
+
+use std::path::Path;
+use std::time::Instant;
+
+use crate::{ demo3, demo4, demo5, read_input_numbers, read_lines };
+use crate::demo4::Source;
+use crate::numbers::parse_line;
+use crate::registry::DemoError;
+
+// Each read path, boiled down to the same signature so they can go in one list.
+// Their error types all differ, so errors are compared as messages
+type ReadPath = fn(&Path) -> Result<Vec<u64>, String>;
+
+/// How many times to read the file unless told otherwise
+pub const DEFAULT_RUNS: u32 = 100;
+
+/// Runs the comparison, reading `file_name` `runs` times with each read path.
+pub fn run(file_name: &str, runs: u32) -> Result<(), DemoError> {
+    let path = Path::new(file_name);
+
+    // The shared library reports errors properly - if it can't read the file there's
+    // nothing to compare, so that's an ordinary error. Like demos 3 to 5, it reads
+    // every line, so a blank line or a comment is a parse error here as well
+    let expected = read_input_numbers(Some(file_name))?;
+
+    // Demos 3 and 5 only read lines, so their lines are parsed the same way the
+    // shared library's are
+    let paths: Vec<(&str, ReadPath)> = vec![
+        ("demo3", |path| parse(demo3::read_file(path).map_err(String::from)?)),
+        ("demo4", |path| demo4::with_map_err(&Source::File(path)).map_err(|e| e.to_string())),
+        ("demo5", |path| parse(demo5::read_file(path).map_err(|e| e.to_string())?)),
+        ("shared", |path| parse(read_lines(path).map_err(|e| e.to_string())?)),
+    ];
+
+    for (name, read) in paths {
+        let start = Instant::now();
+        let mut result = read(path);
+        for _ in 1..runs {
+            result = read(path);
+        }
+        let elapsed = start.elapsed();
+
+        match result {
+            Ok(ref numbers) if *numbers == expected => {},
+            Ok(_)  => return Err(DemoError::Mismatch(format!("{} read different numbers from the shared library", name))),
+            Err(e) => return Err(DemoError::Mismatch(format!("{} failed where the shared library succeeded: {}", name, e)))
+        }
+        println!("{:<6} {:>12.3?} per run", name, elapsed / runs.max(1));
+    }
+    println!("All four read the same {} numbers from {} ({} runs each)", expected.len(), file_name, runs);
+    Ok(())
+}
+
+fn parse(lines: Vec<String>) -> Result<Vec<u64>, String> {
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map_err(|e| e.to_string()))
+        .collect()
+}
@@ -0,0 +1,23 @@
+use crate::registry::{ Demo, DemoError };
+
+pub fn main(args: &[String]) {
+    let mut argv = args.iter().cloned();
+    let arg: String = argv.nth(1).unwrap();
+    let n: i32 = arg.parse().unwrap();
+    println!("{}", n);
+}
+
+pub struct Demo0;
+
+impl Demo for Demo0 {
+    fn name(&self) -> &'static str { "demo0" }
+
+    fn description(&self) -> &'static str { "Introducing unwrap" }
+
+    fn technique(&self) -> &'static str { "unwrap, panicking on failure" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args);
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::ReadError;
+use crate::registry::{ Demo, DemoError };
+
+// Reading arguments by hand - the runner declares its own for clap instead (see demo-cli/src/cli.rs)
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let mut argv = args.iter().cloned();
+    let arg: String = match argv.nth(1) {
+        Some(s) => s, // Success - resolve to the string value
+        None    => {
+            //Return an error, for the runner to report
+            return Err(DemoError::Usage("Not enough arguments".to_string()))
+        }
+    };
+    let n: i32 = match arg.parse() {
+        Ok(i)  => i, // Success - resolve to the i32 value
+        Err(e) => {
+            //Return an error, for the runner to report
+            return Err(DemoError::Read(ReadError::Parse(e)))
+        }
+    };
+    println!("{}", n);
+    Ok(())
+}
+
+pub struct Demo1;
+
+impl Demo for Demo1 {
+    fn name(&self) -> &'static str { "demo1" }
+
+    fn description(&self) -> &'static str { "Match your way to success" }
+
+    fn technique(&self) -> &'static str { "match on Option and Result" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,35 @@
+use std::io;
+use crate::{ read_lines_all, read_lines_from };
+use crate::registry::{ Demo, DemoError };
+
+pub fn main(args: &[String]) {
+    let file_names = &args[1..];
+    
+    //Read the files one after another (or stdin, if no file was given) as lines of
+    //text, with the shared library's readers. Either can fail, and we unwrap whatever
+    //they return
+    let lines = if file_names.is_empty() {
+        read_lines_from(io::stdin().lock()).unwrap()
+    } else {
+        read_lines_all(file_names).unwrap()
+    };
+    
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+pub struct Demo2;
+
+impl Demo for Demo2 {
+    fn name(&self) -> &'static str { "demo2" }
+
+    fn description(&self) -> &'static str { "A more complicated example" }
+
+    fn technique(&self) -> &'static str { "unwrap around file I/O" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+use std::io;
+use crate::{ read_lines, read_lines_from, ReadError };
+use crate::registry::{ Demo, DemoError };
+
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_names = &args[1..];
+    let mut lines = Vec::new();
+    if file_names.is_empty() {
+        //No file given - read whatever is piped in on stdin instead
+        lines = describe(read_lines_from(io::stdin().lock())).map_err(|message| error(message, None))?;
+    }
+    //Otherwise read each file in turn, stopping at the first which fails
+    for file_name in file_names {
+        match read_file(Path::new(file_name)) {
+            //Success! Have a vec of strings, to add to the ones before
+            Ok(file_lines) => lines.extend(file_lines),
+            Err(message) => return Err(error(message, Some(file_name)))
+        }
+    }
+    
+    for line in lines.iter() {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn error(message: &str, file_name: Option<&String>) -> DemoError {
+    //Error! Something went wrong - but all we know about it is the message,
+    //so the runner can only report it as an I/O error
+    let e = ReadError::Io(io::Error::other(message));
+    DemoError::Read(e.in_input(file_name))
+}
+
+pub fn read_file(path: &Path) -> Result<Vec<String>, &'static str> {
+    //The shared library opens and reads the file - all we do is handle its errors
+    describe(read_lines(path))
+}
+
+fn describe(result: Result<Vec<String>, ReadError>) -> Result<Vec<String>, &'static str> {
+    
+    //Match on every way the read can go, and turn each error into a message
+    match result {
+        Ok(lines) => Ok(lines),
+        Err(ReadError::Io(_)) => Err("Could not open file"),
+        Err(_) => Err("An error occured while reading a line")
+    }
+}
+
+pub struct Demo3;
+
+impl Demo for Demo3 {
+    fn name(&self) -> &'static str { "demo3" }
+
+    fn description(&self) -> &'static str { "Let's try matching again" }
+
+    fn technique(&self) -> &'static str { "match, returning Result<_, &str>" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
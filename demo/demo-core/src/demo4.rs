@@ -0,0 +1,142 @@
+use std::io;
+use std::path::Path;
+
+use crate::{ read_lines, read_lines_from, ReadError };
+use crate::literal::Literal;
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+
+// What each version of the read comes up with
+type Numbers = Result<Vec<u64>, ReadError>;
+// Each version has the same signature, so they can go in one list
+type ReadPath = fn(&Source) -> Numbers;
+
+/// Where the lines come from. Each way reads a file afresh, but stdin can only be
+/// read once, so its lines are read up front and each way is handed a copy.
+pub enum Source<'a> {
+    File(&'a Path),
+    Stdin(&'a [String])
+}
+
+impl Source<'_> {
+    fn read_lines(&self) -> Result<Vec<String>, ReadError> {
+        match *self {
+            Source::File(path)   => read_lines(path),
+            Source::Stdin(lines) => Ok(lines.to_vec())
+        }
+    }
+
+    fn path(&self) -> Option<&Path> {
+        match *self {
+            Source::File(path) => Some(path),
+            Source::Stdin(_)   => None
+        }
+    }
+}
+
+// Functional style: rather than matching on each result, read and parse the file
+// five ways, each built around a different combinator, and show what each makes of
+// the same file
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let stdin_lines;
+    let source = match &args[1..] {
+        [] => {
+            stdin_lines = read_lines_from(io::stdin().lock())?;
+            Source::Stdin(&stdin_lines)
+        },
+        [file_name] => Source::File(Path::new(file_name)),
+        _ => return Err(DemoError::Usage("Expected at most one file to read".to_string()))
+    };
+
+    let ways: Vec<(&str, ReadPath)> = vec![
+        ("map",            with_map),
+        ("map_err",        with_map_err),
+        ("and_then",       with_and_then),
+        ("or_else",        with_or_else),
+        ("unwrap_or_else", with_unwrap_or_else)
+    ];
+    for (name, read) in ways {
+        println!("{}:", name);
+        match read(&source) {
+            Ok(numbers) => println!("    {:?}", numbers),
+            Err(e)      => println!("    Error: {}", e)
+        }
+    }
+    Ok(())
+}
+
+/// `map` changes the value inside an `Ok`, and passes an `Err` through untouched.
+///
+/// Its closure can't fail, so a step which can - parsing - leaves a `Result` inside a
+/// `Result`, and it takes a `?` for each to get the numbers out.
+pub fn with_map(source: &Source) -> Numbers {
+    let parsed: Result<Result<Vec<u64>, LineError>, ReadError> = source.read_lines().map(|lines| parse_all(&lines));
+    Ok(parsed??)
+}
+
+/// `map_err` is `map` for the `Err` side. Here it attributes each error to the file,
+/// the way `File.ReadAllLines` puts the path in its `FileNotFoundException`.
+pub fn with_map_err(source: &Source) -> Numbers {
+    let lines = source.read_lines().map_err(|e| e.in_input(source.path()))?;
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map_err(|e| ReadError::from(e).in_input(source.path())))
+        .collect()
+}
+
+/// `and_then` chains on a step which can fail itself: its closure returns a `Result`,
+/// which becomes the result - no nesting. It's LINQ's `SelectMany`, for one value.
+pub fn with_and_then(source: &Source) -> Numbers {
+    source.read_lines().and_then(|lines| parse_all(&lines).map_err(ReadError::from))
+}
+
+/// `or_else` is `and_then` for the `Err` side: a second chance, which might fail too.
+///
+/// A line which isn't a decimal number gets another try as a hex or binary literal,
+/// and only if that fails as well is the first error kept - in C#, a catch block
+/// which retries.
+pub fn with_or_else(source: &Source) -> Numbers {
+    let lines = source.read_lines()?;
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line)
+            .or_else(|e| line.trim().parse::<Literal>().map(|literal| literal.0).map_err(|_| e)))
+        .collect::<Result<_, _>>()
+        .map_err(ReadError::from)
+}
+
+/// `unwrap_or_else` gives up on the error altogether, and makes a value in its place.
+///
+/// A line which doesn't parse is reported and skipped, like a catch block which logs
+/// and carries on, so only reading the file can fail.
+pub fn with_unwrap_or_else(source: &Source) -> Numbers {
+    let lines = source.read_lines()?;
+    Ok(lines.iter()
+        .enumerate()
+        .filter_map(|(i, line)| parse_line(i + 1, line).map(Some).unwrap_or_else(|e| {
+            println!("    Skipping {}", e);
+            None
+        }))
+        .collect())
+}
+
+fn parse_all(lines: &[String]) -> Result<Vec<u64>, LineError> {
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line))
+        .collect()
+}
+
+pub struct Demo4;
+
+impl Demo for Demo4 {
+    fn name(&self) -> &'static str { "demo4" }
+
+    fn description(&self) -> &'static str { "Functional style" }
+
+    fn technique(&self) -> &'static str { "map, map_err, and_then, or_else and unwrap_or_else" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,60 @@
+// try! is deprecated in favour of the `?` operator, but it is what this demo is about.
+// Since the 2018 edition `try` is a keyword, so the macro has to be called r#try!
+#![allow(deprecated)]
+
+use std::path::Path;
+use std::io;
+use crate::{ read_lines, read_lines_all, read_lines_from, ReadError };
+use crate::registry::{ Demo, DemoError };
+
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_names = &args[1..];
+    let result = match file_names {
+        //No file given - read whatever is piped in on stdin instead
+        [] => read_stdin(),
+        [file_name] => read_file(Path::new(file_name)).map_err(|e| e.in_file(file_name)),
+        //Several files - their lines one after another, each error naming its file
+        _ => read_lines_all(file_names)
+    };
+    
+    match result {
+        Ok(lines) => {
+            //Success! Have a vec of strings
+            for line in lines.iter() {
+                println!("{}", line);
+            }
+        },
+        Err(e) => {
+            //Error! Something went wrong - hand the whole error to the runner
+            return Err(DemoError::Read(e))
+        }
+    }
+    Ok(())
+}
+
+// try! macro style
+// See: http://doc.rust-lang.org/stable/std/macro.try!.html for the full macro
+
+pub fn read_file(path: &Path) -> Result<Vec<String>, ReadError> {
+    let lines = r#try!(read_lines(path));
+    Ok(lines)
+}
+
+fn read_stdin() -> Result<Vec<String>, ReadError> {
+    let lines = r#try!(read_lines_from(io::stdin().lock()));
+    Ok(lines)
+}
+
+pub struct Demo5;
+
+impl Demo for Demo5 {
+    fn name(&self) -> &'static str { "demo5" }
+
+    fn description(&self) -> &'static str { "Getting closer" }
+
+    fn technique(&self) -> &'static str { "try! for early returns" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
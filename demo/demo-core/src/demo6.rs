@@ -0,0 +1,144 @@
+// try! is deprecated in favour of the `?` operator, but it is what this demo is about.
+// Since the 2018 edition `try` is a keyword, so the macro has to be called r#try!
+#![allow(deprecated)]
+
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use crate::{ read_lines_from_with, read_lines_with, ReadError, ReadOptions };
+use crate::literal::Literal;
+use crate::numbers::{ parse_line, parse_tokens, Delimiter };
+use crate::validated::{ Port, Score };
+use crate::registry::{ Demo, DemoError };
+
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let mut argv = args.iter().skip(1).cloned().peekable();
+    //Options come first. --as <type> picks what each value is parsed as - by default
+    //a Literal, which understands 0x1F, 0b1010 and 1_000_000 as well as plain numbers.
+    //--split reads every whitespace separated value on a line rather than one per
+    //line, and --split-on <char> splits on that character instead
+    let mut type_name = "literal".to_string();
+    let mut delimiter = None;
+    while let Some(option) = argv.next_if(|a| a.starts_with("--")) {
+        match &option[..] {
+            "--as"       => type_name = argv.next().unwrap_or_default(),
+            "--split"    => delimiter = Some(Delimiter::Whitespace),
+            "--split-on" => delimiter = Some(Delimiter::Char(single_char(argv.next())?)),
+            other => return Err(DemoError::Usage(format!("Unknown option: {}", other)))
+        }
+    }
+    //Every argument after that is a file to read
+    let file_names: Vec<String> = argv.collect();
+
+    //The same code, parsing a different type each time
+    match &type_name[..] {
+        "literal" => print_values::<Literal>(&file_names, delimiter),
+        "u64"     => print_values::<u64>(&file_names, delimiter),
+        "i64"     => print_values::<i64>(&file_names, delimiter),
+        "f64"     => print_values::<f64>(&file_names, delimiter),
+        "port"    => print_values::<Port>(&file_names, delimiter),
+        "score"   => print_values::<Score>(&file_names, delimiter),
+        other => Err(DemoError::Usage(format!("Unknown type: {} (expected literal, u64, i64, f64, port or score)", other)))
+    }
+}
+
+fn single_char(arg: Option<String>) -> Result<char, DemoError> {
+    let arg = arg.unwrap_or_default();
+    let mut chars = arg.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(DemoError::Usage("--split-on expects a single character".to_string()))
+    }
+}
+
+// T can be anything which parses from a string and prints back out. Its parse
+// error has to be a proper error too, so that it can be boxed up in a DemoError for
+// the runner to report - the equivalent of `where T : IParsable<T>` in C#
+fn print_values<T>(file_names: &[String], delimiter: Option<Delimiter>) -> Result<(), DemoError>
+    where T: FromStr + Display, T::Err: Error + Send + Sync + 'static
+{
+    let result = if file_names.is_empty() {
+        //No files given - read whatever is piped in on stdin instead
+        read_stdin::<T>(delimiter)
+    } else {
+        read_files::<T>(file_names, delimiter)
+    };
+    
+    match result {
+        Ok(values) => {
+            //Success! Have a vec of values
+            for v in values.iter() {
+                println!("{}", v);
+            }
+        },
+        Err(e) => {
+            //Error! Something went wrong - the parse error's type depends on T, so
+            //it's boxed to fit in the one DemoError
+            return Err(DemoError::Parse(e.boxed()))
+        }
+    }
+    Ok(())
+}
+
+// Read each file in turn, stopping at the first error. Errors are tagged with
+// the file they came from, so we know which one to blame
+fn read_files<T: FromStr>(file_names: &[String], delimiter: Option<Delimiter>) -> Result<Vec<T>, ReadError<T::Err>> {
+    let mut values = Vec::new();
+    for file_name in file_names {
+        let path = Path::new(file_name);
+        let mut file_values = r#try!(read_values(path, delimiter).map_err(|e| e.in_file(path)));
+        values.append(&mut file_values);
+    }
+    Ok(values)
+}
+
+// The error type depends on T: reading f64s can fail with a ParseFloatError,
+// so the ReadError has to be able to hold one
+pub fn read_values<T: FromStr>(path: &Path, delimiter: Option<Delimiter>) -> Result<Vec<T>, ReadError<T::Err>> {
+    let lines = r#try!(read_lines_with(path, &read_options()).map_err(ReadError::unparsed));
+    parse_values(lines, delimiter)
+}
+
+fn read_stdin<T: FromStr>(delimiter: Option<Delimiter>) -> Result<Vec<T>, ReadError<T::Err>> {
+    let lines = r#try!(read_lines_from_with(io::stdin().lock(), &read_options()).map_err(ReadError::unparsed));
+    parse_values(lines, delimiter)
+}
+
+// Blank lines and comments are skipped, so input files can be annotated
+fn read_options() -> ReadOptions {
+    ReadOptions::new().skip_blank(true).skip_comments(true)
+}
+
+// ReadError and its From conversions live in the shared library (see read.rs),
+// as does reading the lines - this demo only has to parse the lines it gets back.
+// parse_line returns a LineError saying which line failed, and try! converts
+// that into a ReadError with From, just like the io::Error above
+fn parse_values<T: FromStr>(lines: Vec<(usize, String)>, delimiter: Option<Delimiter>) -> Result<Vec<T>, ReadError<T::Err>> {
+    //Several values to a line? parse_tokens deals with splitting them up, and tells
+    //us the column as well as the line of a value which doesn't parse
+    if let Some(delimiter) = delimiter {
+        return Ok(r#try!(parse_tokens(&lines, delimiter)));
+    }
+    let mut values = Vec::new();
+    for (line_number, line) in lines {
+        let v = r#try!(parse_line(line_number, &line));
+        values.push(v);
+    }
+    Ok(values)
+}
+
+pub struct Demo6;
+
+impl Demo for Demo6 {
+    fn name(&self) -> &'static str { "demo6" }
+
+    fn description(&self) -> &'static str { "Introducing moar complexity" }
+
+    fn technique(&self) -> &'static str { "custom error enum with From conversions, generic over the parsed type" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,69 @@
+use std::io;
+use std::path::Path;
+use crate::{ read_lines, read_lines_from, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// main can return a Result too. When a program's main returns Err, Rust prints
+// the error (using its Debug representation) and exits with a failure code.
+// The std::process::Termination trait is what turns a return type into an exit code
+// (see exit_codes.rs for our own implementation, which the runner uses).
+pub fn main(args: &[String]) -> Result<(), ReadError> {
+    let argv = args.iter().cloned();
+    let file_names: Vec<String> = argv.skip(1).collect();
+    
+    // No need to match on the results - any error goes straight back to our caller
+    let numbers = if file_names.is_empty() {
+        //No files given - read whatever is piped in on stdin instead
+        read_stdin()?
+    } else {
+        read_files(&file_names)?
+    };
+    
+    for n in numbers.iter() {
+        println!("{}", n);
+    }
+    Ok(())
+}
+
+// Each file gives us a Result of Vec. Collecting those into a Result stops at
+// the first Err, and flattening the Vecs that are left concatenates them
+fn read_files(file_names: &[String]) -> Result<Vec<u64>, ReadError> {
+    let per_file = file_names.iter()
+        .map(|file_name| read_file(Path::new(file_name)).map_err(|e| e.in_file(file_name)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(per_file.into_iter().flatten().collect())
+}
+
+// The ? operator does exactly what try! does: resolve to the value on Ok, or
+// convert the error with From and return it early on Err
+pub fn read_file(path: &Path) -> Result<Vec<u64>, ReadError> {
+    parse_numbers(read_lines(path)?)
+}
+
+fn read_stdin() -> Result<Vec<u64>, ReadError> {
+    parse_numbers(read_lines_from(io::stdin().lock())?)
+}
+
+fn parse_numbers(lines: Vec<String>) -> Result<Vec<u64>, ReadError> {
+    let mut numbers = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        numbers.push(parse_line(i + 1, line)?);
+    }
+    Ok(numbers)
+}
+
+pub struct Demo7;
+
+impl Demo for Demo7 {
+    fn name(&self) -> &'static str { "demo7" }
+
+    fn description(&self) -> &'static str { "The question mark operator" }
+
+    fn technique(&self) -> &'static str { "? and returning Result from main" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use tokio::fs::File;
+use tokio::io::{ self, AsyncBufRead, AsyncBufReadExt, BufReader };
+
+use crate::ReadError;
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// Demo 6 again, with asynchronous I/O. Something has to run the futures, and in
+// a program of its own that would be
+//
+//   #[tokio::main]
+//   async fn main() -> Result<(), ReadError> { ... }
+//
+// - Rust's `async Task Main`. The attribute turns main back into an ordinary fn,
+// which starts tokio's runtime and blocks until the async body finishes. Demos are
+// handed their arguments, which #[tokio::main] doesn't allow, so we do that by hand
+pub fn main(args: &[String]) -> Result<(), ReadError> {
+    tokio::runtime::Runtime::new()?.block_on(async_main(args))
+}
+
+async fn async_main(args: &[String]) -> Result<(), ReadError> {
+    let file_names = &args[1..];
+    let numbers = if file_names.is_empty() {
+        //No files given - read whatever is piped in on stdin instead
+        read_stdin().await?
+    } else {
+        read_files(file_names).await?
+    };
+
+    for n in numbers.iter() {
+        println!("{}", n);
+    }
+    Ok(())
+}
+
+// Calling an async fn doesn't run it - it returns a future, which does nothing
+// until it's awaited. A C# Task is already running by the time you get it
+async fn read_files(file_names: &[String]) -> Result<Vec<u64>, ReadError> {
+    let mut numbers = Vec::new();
+    for file_name in file_names {
+        let mut file_numbers = read_file(file_name).await.map_err(|e| e.in_file(file_name))?;
+        numbers.append(&mut file_numbers);
+    }
+    Ok(numbers)
+}
+
+// ? works in an async fn exactly as it does anywhere else
+async fn read_file(file_name: &str) -> Result<Vec<u64>, ReadError> {
+    let file = File::open(file_name).await?;
+    parse_numbers(BufReader::new(file)).await
+}
+
+async fn read_stdin() -> Result<Vec<u64>, ReadError> {
+    parse_numbers(BufReader::new(io::stdin())).await
+}
+
+// AsyncBufRead is BufRead's async twin. Unpin is needed to call methods on it
+// through a reference while it's being awaited
+async fn parse_numbers<R: AsyncBufRead + Unpin>(read: R) -> Result<Vec<u64>, ReadError> {
+    let mut numbers = Vec::new();
+    let mut lines = read.lines();
+    let mut line_number = 0;
+    // await ReadLineAsync() until it returns null, in C# terms
+    while let Some(line) = lines.next_line().await? {
+        line_number += 1;
+        numbers.push(parse_line(line_number, &line)?);
+    }
+    Ok(numbers)
+}
+
+pub struct Demo8;
+
+impl Demo for Demo8 {
+    fn name(&self) -> &'static str { "demo8" }
+
+    fn description(&self) -> &'static str { "Async all the way down" }
+
+    fn technique(&self) -> &'static str { "async/await with tokio" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+use crate::validated::{ Port, Score };
+use crate::accumulate::Validated;
+
+/// One broken rule: which line, which field, and what was wrong with it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pub line: usize,
+    pub field: &'static str,
+    pub problem: String
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}: {}", self.line, self.field, self.problem)
+    }
+}
+
+/// A line of the file, once every field in it has been checked
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Server {
+    pub name: String,
+    pub port: Port,
+    pub score: Score
+}
+
+/// Checks a `name,port,score` line, returning every field which is wrong with it.
+///
+/// Each field is checked on its own, and `and` puts the results together - the
+/// `RuleFor(x => x.Port)` lines of a FluentValidation `AbstractValidator`, which
+/// all run however many of them fail.
+pub fn validate_line(line: usize, text: &str) -> Validated<Server, ValidationError> {
+    let error = |field, problem: String| ValidationError { line, field, problem };
+    let fields: Vec<&str> = text.split(',').map(str::trim).collect();
+    if fields.len() != 3 {
+        // The fields can't be checked if we don't know which is which
+        return Validated::invalid(error("line", format!("expected 3 fields, found {}", fields.len())));
+    }
+
+    let name = match fields[0] {
+        ""   => Validated::invalid(error("name", "is empty".to_string())),
+        name => Validated::Valid(name.to_string())
+    };
+    let port = Validated::from(Port::try_from(fields[1]))
+        .map_err(|e| error("port", format!("{}: '{}'", e, fields[1])));
+    let score = Validated::from(Score::try_from(fields[2]))
+        .map_err(|e| error("score", format!("{}: '{}'", e, fields[2])));
+
+    name.and(port).and(score).map(|((name, port), score)| Server { name, port, score })
+}
+
+/// Checks every line, returning every server if they're all valid, or every error
+/// in every line if not. Blank lines are skipped.
+pub fn validate_all(lines: &[String]) -> Validated<Vec<Server>, ValidationError> {
+    lines.iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| validate_line(i + 1, line))
+        .collect()
+}
+
+// accumulate: `accumulate [file]`, where each line is `name,port,score`. Prints the
+// servers if every line is valid, or every problem with every line if not
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    match validate_all(&lines) {
+        Validated::Valid(servers) => {
+            for server in servers {
+                println!("{:<10} port {:>5}  score {:>3}%", server.name, server.port.get(), server.score.get());
+            }
+            Ok(())
+        },
+        Validated::Invalid(errors) => {
+            // The problems are what the demo works out, so they go to stdout, and the
+            // error it returns only says how many there were
+            for e in &errors {
+                println!("{}", e);
+            }
+            let lines: BTreeSet<usize> = errors.iter().map(|e| e.line).collect();
+            let summary = format!("{} problems on {} lines", errors.len(), lines.len());
+            Err(DemoError::Invalid(match args.get(1) {
+                Some(file_name) => format!("{}: {}", file_name, summary),
+                None => summary
+            }))
+        }
+    }
+}
+
+pub struct AccumulateDemo;
+
+impl Demo for AccumulateDemo {
+    fn name(&self) -> &'static str { "accumulate" }
+
+    fn description(&self) -> &'static str { "Reporting every invalid field" }
+
+    fn technique(&self) -> &'static str { "an applicative Validated type which collects errors, in place of FluentValidation" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,94 @@
+use std::fs;
+use std::io::{ self, Read };
+use std::num::ParseIntError;
+use std::path::Path;
+
+use anyhow::{ Context, Result };
+
+use crate::{ AnyParseError, ReadError };
+use crate::registry::{ Demo, DemoError };
+
+/// Reads a file of numbers, one to a line, skipping blank lines and comments.
+///
+/// This is demo 6 again, without the `ReadError` enum. `anyhow::Result<T>` is a
+/// `Result<T, anyhow::Error>`, and `anyhow::Error` holds any error at all, so `?`
+/// converts an `io::Error` and a `ParseIntError` alike with no `From` to write.
+/// `context` wraps the error in a message saying what was being done - what C#
+/// does with `throw new Exception("...", inner)` - and the original is kept as its
+/// cause. `with_context` is the same, with the message only built if it's needed.
+pub fn read_values(path: &Path) -> Result<Vec<u64>> {
+    let text = fs::read_to_string(path).context("reading numbers file")?;
+    parse_values(&text)
+}
+
+/// Reads the numbers piped in on stdin, as `read_values` reads a file
+pub fn read_stdin() -> Result<Vec<u64>> {
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text).context("reading numbers from stdin")?;
+    parse_values(&text)
+}
+
+fn parse_values(text: &str) -> Result<Vec<u64>> {
+    let mut values = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+        let value = line.parse().with_context(|| format!("line {}: '{}' is not a number", i + 1, line))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Reads each file in turn, stopping at the first error, which is tagged with the
+/// file it came from
+pub fn read_files(file_names: &[String]) -> Result<Vec<u64>> {
+    let mut values = Vec::new();
+    for file_name in file_names {
+        let mut file_values = read_values(Path::new(file_name)).with_context(|| format!("in {}", file_name))?;
+        values.append(&mut file_values);
+    }
+    Ok(values)
+}
+
+/// An error from this demo, as the runner's `DemoError`.
+///
+/// An `anyhow::Error` doesn't say what kind of error it is in its type, so the
+/// kind is found by asking each error in the chain whether it's one we know -
+/// `downcast_ref`, which is C#'s `ex is IOException` or `catch (FormatException)`.
+/// Where the kind of error matters this much to the caller, it's a sign the code
+/// wants an enum of its own, as demo 6 has. The whole chain goes into the
+/// `DemoError`, so every context is still reported as a cause.
+pub fn demo_error(e: anyhow::Error) -> DemoError {
+    if e.chain().any(|cause| cause.is::<ParseIntError>()) {
+        return DemoError::Parse(ReadError::Parse(AnyParseError(e.into())));
+    }
+    // Reading is the only other thing which fails in this demo
+    DemoError::Read(ReadError::Io(io::Error::other(e)))
+}
+
+// anyhow: `anyhow [file]...`
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_names = &args[1..];
+    let result = if file_names.is_empty() { read_stdin() } else { read_files(file_names) };
+    let values = result.map_err(demo_error)?;
+    for v in values {
+        println!("{}", v);
+    }
+    Ok(())
+}
+
+pub struct AnyhowDemo;
+
+impl Demo for AnyhowDemo {
+    fn name(&self) -> &'static str { "anyhow" }
+
+    fn description(&self) -> &'static str { "Application errors with anyhow" }
+
+    fn technique(&self) -> &'static str { "anyhow::Result and context in place of a hand-written error enum" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,56 @@
+use std::pin::pin;
+
+use futures::StreamExt;
+
+use crate::ReadError;
+use crate::async_read::{ line_stream, open_input_async };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let result = tokio::runtime::Runtime::new()
+        .map_err(ReadError::from)
+        .and_then(|runtime| runtime.block_on(sum_numbers(file_name)));
+    match (result, file_name) {
+        (Ok(()), _)               => Ok(()),
+        (Err(e), Some(file_name)) => Err(e.in_file(file_name).into()),
+        (Err(e), None)            => Err(e.into())
+    }
+}
+
+async fn sum_numbers(file_name: Option<&str>) -> Result<(), ReadError> {
+    let input = open_input_async(file_name).await?;
+
+    // Polling a stream needs it pinned in place, since the future inside it may
+    // hold references to itself. pin! does that on the stack; C# never moves a
+    // Task's state machine once it's on the heap, so it doesn't have to ask
+    let mut lines = pin!(line_stream(input));
+
+    // `await foreach (var line in lines)` in C#. StreamExt::next is a future which
+    // resolves to the next item, or None at the end of the stream
+    let mut stats = Stats::default();
+    while let Some(line) = lines.next().await {
+        let line_number = stats.count + 1;
+        let n = parse_line(line_number, &line?)?;
+        stats = stats.with(n);
+        println!("{:>4}: {:>8} (running total {})", line_number, n, stats.sum);
+    }
+    println!("Read {}", stats);
+    Ok(())
+}
+
+pub struct AsyncStreamDemo;
+
+impl Demo for AsyncStreamDemo {
+    fn name(&self) -> &'static str { "async-stream" }
+
+    fn description(&self) -> &'static str { "Asynchronous streams" }
+
+    fn technique(&self) -> &'static str { "Stream and while let ... .await" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
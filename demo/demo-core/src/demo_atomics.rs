@@ -0,0 +1,108 @@
+use std::panic;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicU64, Ordering };
+use std::thread;
+
+use crate::read_input_lines;
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// How many threads share the counting
+const WORKERS: usize = 4;
+
+/// How many lines parsed as numbers, and how many didn't
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub parsed: u64,
+    pub failed: u64
+}
+
+// Counts the good and bad lines in the input on one thread, then again on several
+// threads sharing a pair of atomic counters, once with each memory ordering
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    let sequential = tally_sequential(&lines);
+    print_tally("1 thread", sequential);
+    for &(name, ordering) in &[("Relaxed", Ordering::Relaxed), ("SeqCst", Ordering::SeqCst)] {
+        let parallel = tally_parallel(&lines, ordering);
+        // However the threads interleave, no increment is lost
+        assert_eq!(parallel, sequential, "the {} tally doesn't match", name);
+        print_tally(&format!("{} threads, {}", WORKERS, name), parallel);
+    }
+    Ok(())
+}
+
+fn print_tally(label: &str, tally: Tally) {
+    println!("{:<20} {} parsed, {} failed", format!("{}:", label), tally.parsed, tally.failed);
+}
+
+/// Tallies the lines on the current thread, with ordinary integers
+pub fn tally_sequential(lines: &[String]) -> Tally {
+    let mut tally = Tally::default();
+    for (i, line) in lines.iter().enumerate() {
+        match parse_line::<u64>(i + 1, line) {
+            Ok(_)  => tally.parsed += 1,
+            Err(_) => tally.failed += 1
+        }
+    }
+    tally
+}
+
+/// Tallies the lines spread across several threads, which all add to the same
+/// counters using `ordering`
+pub fn tally_parallel(lines: &[String], ordering: Ordering) -> Tally {
+    // `parsed += 1` from several threads at once would lose counts, and the compiler
+    // won't allow it anyway: a u64 can't be changed through a shared reference.
+    // AtomicU64 can, because fetch_add reads, adds and writes back as one indivisible
+    // step - Interlocked.Increment, as a type rather than a static method
+    let parsed = Arc::new(AtomicU64::new(0));
+    let failed = Arc::new(AtomicU64::new(0));
+    // thread::spawn needs everything it's given to be 'static, so the threads can't
+    // borrow the lines from our caller. Instead they share a copy of their own
+    let lines = Arc::new(lines.to_vec());
+
+    let handles: Vec<_> = (0..WORKERS)
+        .map(|worker| {
+            let (parsed, failed, lines) = (Arc::clone(&parsed), Arc::clone(&failed), Arc::clone(&lines));
+            thread::spawn(move || {
+                for (i, line) in lines.iter().enumerate().skip(worker).step_by(WORKERS) {
+                    // The ordering says what other memory accesses the increment is
+                    // ordered with. Interlocked always uses a full fence, the same as
+                    // SeqCst. A counter which nothing else depends on only needs the
+                    // increment itself to be atomic, which is all Relaxed promises
+                    match parse_line::<u64>(i + 1, line) {
+                        Ok(_)  => parsed.fetch_add(1, ordering),
+                        Err(_) => failed.fetch_add(1, ordering)
+                    };
+                }
+            })
+        })
+        .collect();
+
+    // Joining a thread makes everything it did visible to us, so even Relaxed
+    // counts are complete by the time we read them
+    for handle in handles {
+        if let Err(panic) = handle.join() {
+            panic::resume_unwind(panic);
+        }
+    }
+    Tally {
+        parsed: parsed.load(Ordering::Relaxed),
+        failed: failed.load(Ordering::Relaxed)
+    }
+}
+
+pub struct AtomicsDemo;
+
+impl Demo for AtomicsDemo {
+    fn name(&self) -> &'static str { "atomics" }
+
+    fn description(&self) -> &'static str { "Counting across threads" }
+
+    fn technique(&self) -> &'static str { "AtomicU64 in place of Interlocked.Increment" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,90 @@
+use std::mem;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// Changes a Vec while looking at it, which is where `&` and `&mut` come in.
+//
+// Any number of `&` borrows of a value can exist at once, or one `&mut` - never both.
+// Iterating a Vec borrows it, so while the loop runs nothing can change it. In C#
+//
+//   foreach (var n in numbers) { if (n % 2 == 1) numbers.Add(n * 2); }
+//
+// compiles, then throws InvalidOperationException: "Collection was modified;
+// enumeration operation may not execute". Rust rejects it before it runs:
+//
+//   for n in &numbers { if n % 2 == 1 { numbers.push(n * 2); } }
+//                                       ^^^^^^^ error[E0502]: cannot borrow `numbers`
+//                                       as mutable because it is also borrowed as immutable
+//
+// It's more than a rule for its own sake: push may need to move the Vec's contents
+// somewhere bigger, which would leave the loop's reference pointing at freed memory.
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    // Numbered before any are removed, so errors give the right line
+    let mut lines: Vec<(usize, String)> = lines.into_iter().enumerate().map(|(i, line)| (i + 1, line)).collect();
+
+    // Removing while iterating: C#'s RemoveAll, rather than a foreach calling Remove
+    let before = lines.len();
+    lines.retain(|(_, line)| !line.trim().is_empty());
+    println!("retain:       {} of {} lines kept", lines.len(), before);
+
+    let mut numbers = parse(&lines).map_err(|e| e.in_input(file_name))?;
+
+    // Adding while iterating: loop over indices instead. Each numbers[i] is a brief
+    // borrow which ends before push needs its &mut. The range is worked out once, up
+    // front, so the new numbers aren't visited - a `for` loop over Count in C#
+    let original_len = numbers.len();
+    for i in 0..original_len {
+        if numbers[i] % 2 == 1 {
+            let doubled = numbers[i].checked_mul(2)
+                .ok_or_else(|| DemoError::Overflow(format!("{} is too big to double", numbers[i])))?;
+            numbers.push(doubled);
+        }
+    }
+    println!("indices:      {:?}", numbers);
+
+    // drain moves a range out of the Vec - no cloning, and the Vec is still ours.
+    // Taking the doubled numbers back off the end undoes the last step
+    let doubled: Vec<u64> = numbers.drain(original_len..).collect();
+    println!("drain:        {:?} and {:?}", numbers, doubled);
+
+    // Two &mut borrows of one Vec can't exist even if they point at different elements -
+    // the compiler doesn't track which indices are which:
+    //
+    //   mem::swap(&mut numbers[0], &mut numbers[1]);
+    //                                   ^^^^^^^ error[E0499]: cannot borrow `numbers`
+    //                                   as mutable more than once at a time
+    //
+    // split_at_mut splits one &mut into two which can't overlap, so it's allowed. Here
+    // each half changes places with the other, one pair at a time
+    let middle = numbers.len() / 2;
+    let (front, back) = numbers.split_at_mut(middle);
+    for (a, b) in front.iter_mut().zip(back.iter_mut().rev()) {
+        mem::swap(a, b);
+    }
+    println!("split_at_mut: {:?}", numbers);
+    Ok(())
+}
+
+fn parse(lines: &[(usize, String)]) -> Result<Vec<u64>, ReadError> {
+    lines.iter()
+        .map(|(line, text)| parse_line(*line, text).map_err(ReadError::from))
+        .collect()
+}
+
+pub struct BorrowingDemo;
+
+impl Demo for BorrowingDemo {
+    fn name(&self) -> &'static str { "borrowing" }
+
+    fn description(&self) -> &'static str { "Changing a collection while using it" }
+
+    fn technique(&self) -> &'static str { "& and &mut in place of InvalidOperationException" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
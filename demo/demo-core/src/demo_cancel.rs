@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::thread;
+use std::time::Duration;
+
+use crate::{ open_input, LineStream, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// How long each line pretends to take, so there's time to press Ctrl-C
+const WORK_PER_LINE: Duration = Duration::from_millis(250);
+
+// Rust has no CancellationToken built in, but it's only a flag which every thread
+// can see. The Arc shares it, and an AtomicBool can be set and read from any
+// thread without a lock. Cloning the token clones the Arc, so every clone sees
+// the same flag - CancellationTokenSource and CancellationToken in one type
+#[derive(Clone, Default)]
+struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+// Adds up the numbers in a file (or stdin), slowly, until it's done or cancelled.
+// --after <ms> cancels it automatically, like CancellationTokenSource.CancelAfter
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let mut argv = args.iter().skip(1).peekable();
+    let after = match argv.next_if(|a| *a == "--after") {
+        Some(_) => {
+            let ms = argv.next().and_then(|ms| ms.parse().ok())
+                .ok_or_else(|| DemoError::Usage("--after expects a number of milliseconds".to_string()))?;
+            Some(Duration::from_millis(ms))
+        },
+        None => None
+    };
+    let file_name = argv.next().map(|s| &s[..]);
+
+    let token = CancellationToken::default();
+
+    // The handler runs on a thread of its own when Ctrl-C is pressed, so it gets
+    // its own clone of the token. Without a handler, Ctrl-C kills the process
+    // on the spot - like C# without a Console.CancelKeyPress handler
+    let handler_token = token.clone();
+    ctrlc::set_handler(move || handler_token.cancel()).map_err(DemoError::Handler)?;
+
+    if let Some(after) = after {
+        let timer_token = token.clone();
+        thread::spawn(move || {
+            thread::sleep(after);
+            timer_token.cancel();
+        });
+    }
+
+    sum_numbers(file_name, &token).map_err(|e| match (e, file_name) {
+        (DemoError::Read(e), Some(file_name)) => DemoError::Read(e.in_file(file_name)),
+        (e, _) => e
+    })
+}
+
+// The token is passed down to the code doing the work, just as it would be in
+// C#, and it's up to that code to check it - cancellation is cooperative
+fn sum_numbers(file_name: Option<&str>, token: &CancellationToken) -> Result<(), DemoError> {
+    let mut stats = Stats::default();
+    for (i, line) in LineStream::new(open_input(file_name).map_err(ReadError::from)?).enumerate() {
+        // ThrowIfCancellationRequested, except that we return an error rather than throw one
+        if token.is_cancelled() {
+            println!("Cancelled after {}", stats);
+            return Err(DemoError::Cancelled);
+        }
+        let n = parse_line(i + 1, &line?).map_err(ReadError::from)?;
+        stats = stats.with(n);
+        println!("{:>4}: {:>8} (running total {})", i + 1, n, stats.sum);
+        thread::sleep(WORK_PER_LINE);
+    }
+    println!("Finished: {}", stats);
+    Ok(())
+}
+
+pub struct CancelDemo;
+
+impl Demo for CancelDemo {
+    fn name(&self) -> &'static str { "cancel" }
+
+    fn description(&self) -> &'static str { "Cancelling with Ctrl-C" }
+
+    fn technique(&self) -> &'static str { "a shared AtomicBool as a CancellationToken" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
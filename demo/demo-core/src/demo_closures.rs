@@ -0,0 +1,118 @@
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// One step of a fold: the total so far and the next number, giving the new total, or
+// None if it's too big for a u64
+type Step = fn(u64, u64) -> Option<u64>;
+
+/// A named test for lines, kept in a field like a `Func<string, bool>`.
+///
+/// Every closure has its own type, which has no name we can write, so a field which
+/// can hold any of them has to box it as a `dyn Fn` - one pointer to the closure's
+/// captured variables, one to its code. That's what a C# delegate is, too.
+pub struct LineFilter {
+    pub name: String,
+    test: Box<dyn Fn(&str) -> bool>
+}
+
+impl LineFilter {
+    /// `'static` means the closure can't borrow anything which might go away before it
+    /// does - it has to own whatever it captures
+    pub fn new<F: Fn(&str) -> bool + 'static>(name: &str, test: F) -> LineFilter {
+        LineFilter { name: name.to_string(), test: Box::new(test) }
+    }
+
+    pub fn matches(&self, line: &str) -> bool {
+        // The brackets say to call the field, rather than a method called `test`
+        (self.test)(line)
+    }
+}
+
+/// A filter for lines longer than `len` characters.
+///
+/// Without `move`, the closure would borrow `len` - a local variable, gone as soon as
+/// this returns - and the compiler would refuse (E0373). `move` copies it into the
+/// closure instead. A C# lambda would capture the variable itself, hoisting it into a
+/// hidden class so that it lives as long as the delegate does.
+pub fn longer_than(len: usize) -> LineFilter {
+    LineFilter::new(&format!("longer than {}", len), move |line| line.trim().chars().count() > len)
+}
+
+// Filters the lines with closures, then folds the numbers with more of them
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+
+    // Fn: only reads what it captures, so it can be called any number of times, even
+    // from several places at once. The first one captures nothing at all
+    let filters = vec![
+        LineFilter::new("blank", |line| line.trim().is_empty()),
+        longer_than(2),
+        LineFilter::new("ending in 0", |line| line.trim().ends_with('0'))
+    ];
+    for filter in &filters {
+        let matching = lines.iter().filter(|line| filter.matches(line)).count();
+        println!("{:<14} {} of {} lines", filter.name, matching, lines.len());
+    }
+
+    let blank = &filters[0];
+    let numbers = lines.iter()
+        .enumerate()
+        .filter(|(_, line)| !blank.matches(line))
+        .map(|(i, line)| parse_line(i + 1, line).map_err(ReadError::from))
+        .collect::<Result<Vec<u64>, ReadError>>()
+        .map_err(|e| e.in_input(file_name))?;
+
+    // FnMut: changes what it captures, so it borrows `largest` mutably while it exists -
+    // nothing else can look at `largest` until for_each is done with the closure. A C#
+    // lambda could change a captured variable from anywhere, at any time
+    let mut largest = None;
+    numbers.iter().for_each(|&n| if largest < Some(n) { largest = Some(n) });
+    println!("{:<14} {:?}", "largest", largest);
+
+    // Closures which capture nothing can also be plain function pointers, `fn` types,
+    // so these accumulators can share one type and go in a list. try_fold stops at the
+    // first step which gives None
+    let folds: [(&str, u64, Step); 3] = [
+        ("sum", 0, |total, n| total.checked_add(n)),
+        ("product", 1, |total, n| total.checked_mul(n)),
+        ("xor", 0, |total, n| Some(total ^ n))
+    ];
+    for (name, start, step) in folds.iter() {
+        match numbers.iter().try_fold(*start, |total, &n| step(total, n)) {
+            Some(total) => println!("{:<14} {}", name, total),
+            None        => println!("{:<14} too big for a u64", name)
+        }
+    }
+
+    // FnOnce: gives away something it captured, so it can only be called once. `move`
+    // puts `report` inside the closure, and calling it hands the String back out
+    let report = format!("{} numbers read", numbers.len());
+    let finish = move || report;
+    println!("{}", finish());
+    // println!("{}", finish());
+    //                ^^^^^^ error[E0382]: use of moved value: `finish`
+    //
+    // Func<> and Action<> can't say any of this: every delegate can be called as often
+    // as you like, by anyone. Rust's three traits say what a closure does with what it
+    // captures - read it, change it or use it up - and a function taking a closure asks
+    // for the least it needs: F: FnOnce for Option::map, which only ever calls it once,
+    // F: FnMut for Iterator::filter and for_each, which call it in turn, and F: Fn where
+    // it may be called from several threads at once, as rayon's par_iter does
+    Ok(())
+}
+
+pub struct ClosuresDemo;
+
+impl Demo for ClosuresDemo {
+    fn name(&self) -> &'static str { "closures" }
+
+    fn description(&self) -> &'static str { "Closures and delegates" }
+
+    fn technique(&self) -> &'static str { "Fn, FnMut and FnOnce in place of Func and Action" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,134 @@
+use std::cmp::Reverse;
+use std::collections::{ BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque };
+use std::collections::hash_map::Entry;
+
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+
+/// How many lines the sliding window covers
+pub const WINDOW: usize = 3;
+
+/// How many of the most common words to keep
+pub const TOP: usize = 5;
+
+/// The words in a line, lowercased, without the punctuation around them
+pub fn words(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+        .filter(|word| !word.is_empty())
+}
+
+/// How often each word appears - `Dictionary<string, int>`.
+///
+/// `entry` looks the key up once, and says whether it was there: C# needs
+/// `TryGetValue` and then an assignment, which looks it up twice.
+pub fn word_counts(lines: &[String]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in lines.iter().flat_map(|line| words(line)) {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// The `n` most common words, most common first, alphabetical among equals.
+///
+/// A `BinaryHeap` is a max-heap, like .NET's `PriorityQueue` with the comparer
+/// reversed. Wrapping each entry in `Reverse` makes it a min-heap instead, so the
+/// smallest of the `n` kept so far is on top, ready to be pushed out.
+pub fn top_words(counts: &HashMap<String, usize>, n: usize) -> Vec<(&str, usize)> {
+    let mut heap = BinaryHeap::new();
+    for (word, &count) in counts {
+        heap.push(Reverse((count, Reverse(&word[..]))));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    // into_sorted_vec is smallest first, and the smallest Reverse is the largest count
+    heap.into_sorted_vec().into_iter().map(|Reverse((count, Reverse(word)))| (word, count)).collect()
+}
+
+// Builds one of each collection from the same lines
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    // HashMap - Dictionary<TKey, TValue>
+    let counts = word_counts(&lines);
+    println!("HashMap:    {} different words in {} lines", counts.len(), lines.len());
+
+    // BinaryHeap - PriorityQueue<TElement, TPriority>
+    let top: Vec<String> = top_words(&counts, TOP).iter().map(|(word, count)| format!("{} ({})", word, count)).collect();
+    println!("BinaryHeap: the top {} are {}", TOP, top.join(", "));
+
+    // BTreeMap - SortedDictionary<TKey, TValue>. Iterating it goes in key order, so
+    // this is an index of the words, and the lines each is on
+    let mut index: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        for word in words(line) {
+            let numbers = index.entry(word).or_default();
+            if numbers.last() != Some(&(i + 1)) {
+                numbers.push(i + 1);
+            }
+        }
+    }
+    // range works because the keys are in order - C# would need a SortedSet's GetViewBetween
+    let t_words: Vec<String> = index.range("t".to_string().."u".to_string()).map(|(word, numbers)| format!("{} {:?}", word, numbers)).collect();
+    println!("BTreeMap:   {} words indexed, and the ones starting with t are on lines {}", index.len(), t_words.join(", "));
+
+    // HashSet - HashSet<T>. insert says whether the value was new, like C#'s Add
+    let mut seen = HashSet::new();
+    for (i, line) in lines.iter().enumerate() {
+        if !line.trim().is_empty() && !seen.insert(line.trim()) {
+            println!("HashSet:    line {} repeats an earlier line", i + 1);
+        }
+    }
+
+    // VecDeque - Queue<T>, but with both ends open. Here it's a window over the last
+    // few lines, pushed on the back and popped off the front
+    let mut window = VecDeque::with_capacity(WINDOW);
+    let mut busiest = (0, 0);
+    for (i, line) in lines.iter().enumerate() {
+        if window.len() == WINDOW {
+            window.pop_front();
+        }
+        window.push_back(words(line).count());
+        let total: usize = window.iter().sum();
+        if total > busiest.1 {
+            busiest = (i + 1, total);
+        }
+    }
+    println!("VecDeque:   the busiest {} lines end on line {}, with {} words", WINDOW, busiest.0, busiest.1);
+
+    // entry also hands back the Entry itself, for when the two cases need different code
+    let mut first_seen: HashMap<String, usize> = HashMap::new();
+    let mut first_repeat = None;
+    for (i, line) in lines.iter().enumerate() {
+        for word in words(line) {
+            match first_seen.entry(word) {
+                Entry::Occupied(entry) => {
+                    if first_repeat.is_none() {
+                        first_repeat = Some((entry.key().clone(), *entry.get(), i + 1));
+                    }
+                },
+                Entry::Vacant(entry) => { entry.insert(i + 1); }
+            }
+        }
+    }
+    if let Some((word, first, again)) = first_repeat {
+        println!("Entry:      the first repeat was '{}', on line {} and again on line {}", word, first, again);
+    }
+    Ok(())
+}
+
+pub struct CollectionsDemo;
+
+impl Demo for CollectionsDemo {
+    fn name(&self) -> &'static str { "collections" }
+
+    fn description(&self) -> &'static str { "A tour of the collections" }
+
+    fn technique(&self) -> &'static str { "std::collections in place of System.Collections.Generic" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,114 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+
+/// A line as it was read, before anyone has looked at what's on it
+#[derive(Debug)]
+pub struct RawLine {
+    pub number: usize,
+    pub text: String
+}
+
+// From is for conversions which can't fail - C#'s `implicit operator`. Implementing it
+// gives the other direction, Into, for free: `(1, text).into()` makes a RawLine
+impl From<(usize, String)> for RawLine {
+    fn from((number, text): (usize, String)) -> RawLine {
+        RawLine { number, text }
+    }
+}
+
+/// A line which held a number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub line: usize,
+    pub value: u64
+}
+
+// TryFrom is for conversions which can - C#'s `explicit operator`, but with the
+// failure in the signature rather than an InvalidCastException. It gives TryInto too
+impl TryFrom<RawLine> for Record {
+    type Error = LineError;
+
+    fn try_from(raw: RawLine) -> Result<Record, LineError> {
+        let value = parse_line(raw.number, &raw.text)?;
+        Ok(Record { line: raw.number, value })
+    }
+}
+
+/// What a set of records adds up to
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub count: usize,
+    /// Wider than the values, as a `From` can't fail - so the sum mustn't overflow
+    pub sum: u128,
+    pub max: Option<Record>
+}
+
+impl From<&[Record]> for Summary {
+    fn from(records: &[Record]) -> Summary {
+        Summary {
+            count: records.len(),
+            sum: records.iter().map(|record| u128::from(record.value)).sum(),
+            max: records.iter().copied().max_by_key(|record| record.value)
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.max {
+            Some(max) => write!(f, "{} records adding up to {}, the largest {} on line {}", self.count, self.sum, max.value, max.line),
+            None      => write!(f, "no records")
+        }
+    }
+}
+
+// Turns lines into RawLines, RawLines into Records, and Records into a Summary - and
+// then into a number type which might be too small for them
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+
+    // Into works out what to convert to from where the result goes
+    let raw: Vec<RawLine> = lines.into_iter().enumerate().map(|(i, text)| (i + 1, text).into()).collect();
+    let records = raw.into_iter()
+        .map(|raw| Record::try_from(raw).map_err(ReadError::from))
+        .collect::<Result<Vec<Record>, ReadError>>()
+        .map_err(|e| e.in_input(file_name))?;
+    let summary = Summary::from(&records[..]);
+    println!("{}", summary);
+
+    // The standard library converts between number types the same way. Widening can't
+    // fail, so it's From - the sum above is each u64 made a u128 - and narrowing can, so
+    // it's TryFrom. C# does widening implicitly, and a narrowing cast like (byte)value
+    // throws away the high bits unless it's in a checked block. Rust's `as` does that
+    // too, but it's there to be spotted
+    match u64::try_from(summary.sum) {
+        Ok(sum) => println!("The sum fits in a u64: {}", sum),
+        Err(e)  => println!("The sum doesn't fit in a u64 ({})", e)
+    }
+    for record in &records {
+        match u8::try_from(record.value) {
+            Ok(byte) => println!("line {}: {} fits in a u8", record.line, byte),
+            Err(e)   => println!("line {}: {} doesn't fit in a u8 ({}) - `as u8` would give {}", record.line, record.value, e, record.value as u8)
+        }
+    }
+    Ok(())
+}
+
+pub struct ConversionsDemo;
+
+impl Demo for ConversionsDemo {
+    fn name(&self) -> &'static str { "conversions" }
+
+    fn description(&self) -> &'static str { "Converting between types" }
+
+    fn technique(&self) -> &'static str { "From, Into and TryFrom in place of conversion operators" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
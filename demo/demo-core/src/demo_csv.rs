@@ -0,0 +1,106 @@
+use std::io::Read;
+
+use csv::{ DeserializeError, ErrorKind, ReaderBuilder };
+use serde::Deserialize;
+
+use crate::ReadError;
+use crate::diagnostics;
+use crate::encoding::Encoding;
+use crate::numbers::LineError;
+use crate::progress;
+use crate::registry::{ Demo, DemoError };
+
+/// One row of orders.csv.
+///
+/// `Deserialize` is derived, so serde writes the code which fills the fields from a
+/// row - CsvHelper's `GetRecords<Order>()`, done at compile time rather than by
+/// reflection. The fields are matched to the header by name, and `rename` is
+/// CsvHelper's `[Name]` attribute, for a header which isn't a valid identifier.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Order {
+    pub id: u32,
+    pub customer: String,
+    pub quantity: u32,
+    #[serde(rename = "unit price")]
+    pub unit_price: f64,
+    /// An empty field is `None`, where CsvHelper would need to be told to treat it as null
+    pub note: Option<String>
+}
+
+impl Order {
+    pub fn total(&self) -> f64 {
+        f64::from(self.quantity) * self.unit_price
+    }
+}
+
+/// Reads the orders from CSV, keeping the rows which don't fit an `Order` as errors
+/// rather than stopping at the first.
+///
+/// This is `read_numbers_lenient` for CSV. A row which won't deserialize is a
+/// `LineError`, like a line which won't parse, with the CSV crate's
+/// `DeserializeError` - which names the field - in place of a `ParseIntError`. Only
+/// failing to read the input at all is an error. Quoted fields, commas inside them
+/// and doubled quotes are all the reader's problem, not ours.
+pub fn read_orders<R: Read>(read: R) -> Result<(Vec<Order>, Vec<LineError<DeserializeError>>), ReadError> {
+    // flexible lets a row be shorter than the header; it's deserializing that notices
+    let mut reader = ReaderBuilder::new().flexible(true).from_reader(read);
+    let headers = reader.headers().map_err(read_error)?.clone();
+    let mut orders = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(read_error)?;
+        let line = record.position().map_or(0, |position| position.line() as usize);
+        match record.deserialize::<Order>(Some(&headers)) {
+            Ok(order) => orders.push(order),
+            Err(e) => match e.kind() {
+                ErrorKind::Deserialize { err, .. } => {
+                    let text = record.iter().collect::<Vec<&str>>().join(",");
+                    errors.push(LineError { line, column: None, text, error: err.clone() });
+                },
+                _ => return Err(read_error(e))
+            }
+        }
+    }
+    Ok((orders, errors))
+}
+
+// The errors which stop the whole read are the ones ReadError already has
+fn read_error(e: csv::Error) -> ReadError {
+    match e.kind() {
+        ErrorKind::Utf8 { .. } => ReadError::Decode(Encoding::Utf8),
+        _                      => ReadError::Io(e.into())
+    }
+}
+
+// csv: `csv [--progress] [file]`. Reads a CSV of orders into typed structs, and adds
+// them up, with a bar while it reads given --progress
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let (with_progress, args) = progress::take_flag(&args[1..]);
+    let file_name = args.first().map(|s| &s[..]);
+    let (orders, errors) = progress::read_input(file_name, with_progress, read_orders).map_err(|e| e.in_input(file_name))?;
+
+    for order in &orders {
+        let note = order.note.as_deref().map(|note| format!(" ({})", note)).unwrap_or_default();
+        println!("#{:<3} {:<16} {:>3} x {:>6.2} = {:>7.2}{}", order.id, order.customer, order.quantity, order.unit_price, order.total(), note);
+    }
+    for e in &errors {
+        diagnostics::warning(format!("Skipped {}", e));
+    }
+    let total: f64 = orders.iter().map(Order::total).sum();
+    println!("{} orders, {} skipped, worth {:.2}", orders.len(), errors.len(), total);
+    Ok(())
+}
+
+pub struct CsvDemo;
+
+impl Demo for CsvDemo {
+    fn name(&self) -> &'static str { "csv" }
+
+    fn description(&self) -> &'static str { "Reading typed records from CSV" }
+
+    fn technique(&self) -> &'static str { "the csv crate and #[derive(Deserialize)] in place of CsvHelper" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::read_lines_from;
+use crate::progress;
+use crate::registry::{ Demo, DemoError };
+
+/// Each different line once, in the order they first appear - LINQ's `Distinct`.
+///
+/// `insert` returns whether the value was new, like `HashSet<T>.Add`, so it's the
+/// filter. The set holds `&str`s borrowed from `lines` rather than copies of them:
+/// nothing is cloned, but the set can't outlive the lines it points into.
+pub fn unique(lines: &[String]) -> Vec<&str> {
+    let mut seen = HashSet::new();
+    lines.iter().map(|line| &line[..]).filter(|line| seen.insert(*line)).collect()
+}
+
+/// Each different line once, with how many times it appears.
+///
+/// A set can only say whether a line has been seen, so counting needs a map - from
+/// the line to where it is in the result, so the first-seen order is kept.
+pub fn count_unique(lines: &[String]) -> Vec<(&str, usize)> {
+    let mut found: Vec<(&str, usize)> = Vec::new();
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    for line in lines {
+        let position = *positions.entry(line).or_insert_with(|| {
+            found.push((line, 0));
+            found.len() - 1
+        });
+        found[position].1 += 1;
+    }
+    found
+}
+
+/// Collapses each run of the same line into one, with the length of the run - what
+/// `uniq -c` does, and why it's usually given sorted input.
+///
+/// `dedup_by` calls the closure with each element and the last one kept, and drops
+/// the element when it returns true. Both are `&mut`, so the kept one can count
+/// what's dropped. This takes the `Vec` by value and moves the strings into the
+/// result, so the lines are neither copied nor borrowed - there's one owner, before
+/// and after.
+pub fn collapse_runs(lines: Vec<String>) -> Vec<(String, usize)> {
+    let mut runs: Vec<(String, usize)> = lines.into_iter().map(|line| (line, 1)).collect();
+    runs.dedup_by(|next, kept| {
+        let same = next.0 == kept.0;
+        if same {
+            kept.1 += next.1;
+        }
+        same
+    });
+    runs
+}
+
+// dedup: `dedup [-c] [--adjacent] [--progress] [file]`. By default it drops every
+// repeat, wherever it is, without sorting anything. --adjacent only collapses repeats
+// which are next to each other, like uniq, -c says how many there were, and
+// --progress shows a bar while the input is read
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let (with_progress, args) = progress::take_flag(&args[1..]);
+    let mut argv = args.iter().peekable();
+    let mut counting = false;
+    let mut adjacent = false;
+    while let Some(flag) = argv.next_if(|a| a.starts_with('-') && a.len() > 1) {
+        match &flag[..] {
+            "-c"         => counting = true,
+            "--adjacent" => adjacent = true,
+            other        => return Err(DemoError::Usage(format!("Unknown flag {}; expected -c or --adjacent", other)))
+        }
+    }
+    let file_name = argv.next().map(|s| &s[..]);
+    let lines = progress::read_input(file_name, with_progress, read_lines_from).map_err(|e| e.in_input(file_name))?;
+    let total = lines.len();
+
+    // The borrowing versions return &strs into lines, and the owning one consumes it,
+    // so the lines have to be printed before they're given away
+    let kept = if adjacent {
+        let runs = collapse_runs(lines);
+        for (line, count) in &runs {
+            print_line(line, *count, counting);
+        }
+        runs.len()
+    } else if counting {
+        let found = count_unique(&lines);
+        for &(line, count) in &found {
+            print_line(line, count, counting);
+        }
+        found.len()
+    } else {
+        let found = unique(&lines);
+        for line in &found {
+            print_line(line, 1, counting);
+        }
+        found.len()
+    };
+    println!("{} lines, {} kept", total, kept);
+    Ok(())
+}
+
+fn print_line(line: &str, count: usize, counting: bool) {
+    if counting {
+        println!("{:>4} {}", count, line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+pub struct DedupDemo;
+
+impl Demo for DedupDemo {
+    fn name(&self) -> &'static str { "dedup" }
+
+    fn description(&self) -> &'static str { "Dropping repeated lines" }
+
+    fn technique(&self) -> &'static str { "HashSet<&str> and Vec::dedup_by in place of Distinct()" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,105 @@
+use std::io;
+
+use crate::{ read_lines_from_with, read_lines_with, ReadOptions };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+/// How the report is laid out.
+///
+/// A report with no title and no width would be no use, so the defaults aren't the
+/// zero values `derive(Default)` would pick - Default is written out by hand instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    pub title: String,
+    pub width: usize,
+    pub show_total: bool
+}
+
+impl Default for Layout {
+    fn default() -> Layout {
+        Layout {
+            title: "Numbers".to_string(),
+            width: 24,
+            show_total: true
+        }
+    }
+}
+
+/// Everything the demo can be told.
+///
+/// Every field has a Default of its own - `false`, `None`, and the two above - so
+/// the whole struct can derive one, which calls each of them in turn.
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    pub verbose: bool,
+    /// The most numbers to list, or `None` for all of them
+    pub limit: Option<usize>,
+    pub read: ReadOptions,
+    pub layout: Layout
+}
+
+// Reads numbers with a Config built from defaults, naming only what's different.
+//
+// The C# for this would be optional parameters - `Report(string path, bool verbose =
+// false, int? limit = null)` - or an object initializer, `new Config { Verbose = true
+// }`. Rust has neither; struct update syntax covers both. `..Config::default()` fills
+// in every field which isn't named, and has to come last
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let verbose = args.get(1).map(|a| &a[..]) == Some("--verbose");
+    let file_name = args.get(if verbose { 2 } else { 1 });
+    let config = Config {
+        verbose,
+        // ReadOptions has builder setters as well, and this is the same as
+        // ReadOptions::new().skip_blank(true).skip_comments(true).trim(true)
+        read: ReadOptions { skip_blank: true, skip_comments: true, trim: true, ..ReadOptions::default() },
+        layout: Layout { title: "Numbers, without the comments".to_string(), ..Layout::default() },
+        ..Config::default()
+    };
+    if config.verbose {
+        println!("{:#?}", config);
+    }
+
+    let lines = match file_name {
+        Some(file_name) => read_lines_with(file_name, &config.read).map_err(|e| e.in_file(file_name))?,
+        None            => read_lines_from_with(io::stdin().lock(), &config.read)?
+    };
+    let mut numbers = Vec::new();
+    for (line, text) in &lines {
+        let parsed: Result<u64, LineError> = parse_line(*line, text);
+        match parsed {
+            Ok(number) => numbers.push(number),
+            Err(e)     => println!("{}", e)
+        }
+    }
+    report(&numbers, &config);
+    Ok(())
+}
+
+fn report(numbers: &[u64], config: &Config) {
+    let layout = &config.layout;
+    let rule = "-".repeat(layout.width);
+    println!("{}", layout.title);
+    println!("{}", rule);
+    for number in numbers.iter().take(config.limit.unwrap_or(numbers.len())) {
+        println!("{:>width$}", number, width = layout.width);
+    }
+    if layout.show_total {
+        println!("{}", rule);
+        println!("{:>width$}", Stats::of(numbers).sum, width = layout.width);
+    }
+}
+
+pub struct DefaultsDemo;
+
+impl Demo for DefaultsDemo {
+    fn name(&self) -> &'static str { "defaults" }
+
+    fn description(&self) -> &'static str { "Settings with defaults" }
+
+    fn technique(&self) -> &'static str { "Default and struct update syntax in place of optional parameters" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
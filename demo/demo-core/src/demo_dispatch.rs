@@ -0,0 +1,100 @@
+use std::hint::black_box;
+use std::time::{ Duration, Instant };
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+
+// How many times each version parses the input, to give the timings something to measure
+const RUNS: u32 = 100;
+
+/// Turns one line into a number
+pub trait Parser {
+    fn parse(&self, line: usize, text: &str) -> Result<u64, LineError>;
+}
+
+/// Parses each line as a decimal number
+pub struct Decimal;
+
+impl Parser for Decimal {
+    fn parse(&self, line: usize, text: &str) -> Result<u64, LineError> {
+        parse_line(line, text)
+    }
+}
+
+/// The length of each line - next to no work, so the cost of calling it is most of the cost
+pub struct Length;
+
+impl Parser for Length {
+    fn parse(&self, _: usize, text: &str) -> Result<u64, LineError> {
+        Ok(text.len() as u64)
+    }
+}
+
+/// Parses every line with `parser`, whose type is known at compile time.
+///
+/// The compiler makes a copy of this function for each type it's called with
+/// (*monomorphisation*), and each copy calls its parser directly - often inlining it
+/// altogether. In C# that happens for generics over value types; over reference types,
+/// one shared copy makes interface calls.
+pub fn parse_static<P: Parser>(parser: &P, lines: &[String]) -> Result<Vec<u64>, LineError> {
+    lines.iter().enumerate().map(|(i, line)| parser.parse(i + 1, line)).collect()
+}
+
+/// Parses every line with `parser`, whose type is only known at run time.
+///
+/// `&dyn Parser` is a pair of pointers: one to the parser, one to a table of its
+/// methods - a vtable, the same mechanism as a C# virtual or interface call. There's
+/// one copy of this function, and each call to `parse` is an indirect jump which
+/// can't be inlined.
+pub fn parse_dyn(parser: &dyn Parser, lines: &[String]) -> Result<Vec<u64>, LineError> {
+    lines.iter().enumerate().map(|(i, line)| parser.parse(i + 1, line)).collect()
+}
+
+// Parses the input both ways with each parser, checks they agree, and times them.
+// For accurate numbers see `cargo bench --bench dispatch`
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    let in_file = |e: LineError| ReadError::from(e).in_input(file_name);
+
+    println!("{} lines, parsed {} times each way", lines.len(), RUNS);
+    let (by_static, static_time) = time(|| parse_static(&Decimal, &lines));
+    // black_box hides which parser this is, or the optimiser could see through the
+    // dyn and make a direct call anyway
+    let (by_dyn, dyn_time) = time(|| parse_dyn(black_box(&Decimal), &lines));
+    assert_eq!(by_static.map_err(in_file)?, by_dyn.map_err(in_file)?);
+    println!("Decimal, static:  {:?}", static_time);
+    println!("Decimal, dyn:     {:?}", dyn_time);
+
+    let (by_static, static_time) = time(|| parse_static(&Length, &lines));
+    let (by_dyn, dyn_time) = time(|| parse_dyn(black_box(&Length), &lines));
+    assert_eq!(by_static.map_err(in_file)?, by_dyn.map_err(in_file)?);
+    println!("Length, static:   {:?}", static_time);
+    println!("Length, dyn:      {:?}", dyn_time);
+    Ok(())
+}
+
+// Runs `parse` RUNS times, returning its result and the average time it took
+fn time<F: Fn() -> Result<Vec<u64>, LineError>>(parse: F) -> (Result<Vec<u64>, LineError>, Duration) {
+    let start = Instant::now();
+    let mut result = parse();
+    for _ in 1..RUNS {
+        result = black_box(parse());
+    }
+    (result, start.elapsed() / RUNS)
+}
+
+pub struct DispatchDemo;
+
+impl Demo for DispatchDemo {
+    fn name(&self) -> &'static str { "dispatch" }
+
+    fn description(&self) -> &'static str { "Static and dynamic dispatch" }
+
+    fn technique(&self) -> &'static str { "generics and dyn in place of virtual calls" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,121 @@
+use std::env;
+use std::fs;
+use std::io::{ self, Read };
+use std::mem;
+use std::path::{ Path, PathBuf };
+use std::process;
+use std::time::Instant;
+
+use crate::{ open_input, read_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+/// Prints how long it was alive for, when it goes out of scope.
+///
+/// Drop is Rust's `Dispose`, with the `using` built in: the compiler calls it at the
+/// end of the scope which owns the value, whichever way the scope is left - falling
+/// off the end, `return`, `?` or a panic. There's no way to forget it.
+pub struct TimedScope {
+    name: &'static str,
+    start: Instant
+}
+
+impl TimedScope {
+    pub fn new(name: &'static str) -> TimedScope {
+        TimedScope { name, start: Instant::now() }
+    }
+}
+
+impl Drop for TimedScope {
+    fn drop(&mut self) {
+        println!("{} took {:?}", self.name, self.start.elapsed());
+    }
+}
+
+/// A file in the temp directory, deleted when the guard is dropped
+pub struct TempFileGuard {
+    path: PathBuf
+}
+
+impl TempFileGuard {
+    /// Writes `contents` to a new temp file named after `name`
+    pub fn create(name: &str, contents: &[u8]) -> io::Result<TempFileGuard> {
+        let path = env::temp_dir().join(format!("{}-{}.txt", name, process::id()));
+        fs::write(&path, contents)?;
+        Ok(TempFileGuard { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFileGuard {
+    // Drop can't return an error, so there's nothing to do with one but ignore it -
+    // the same as an exception thrown from Dispose, which C# guidance says not to do
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+        println!("TempFileGuard: deleted {}", self.path.file_name().unwrap_or_default().to_string_lossy());
+    }
+}
+
+// Copies the input to temp files and reads it back, showing when each guard cleans up
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    // Dropped last, as main returns, so it times everything
+    let _whole = TimedScope::new("the whole demo");
+
+    let file_name = args.get(1).map(|s| &s[..]);
+    let in_file = |e: ReadError| e.in_input(file_name);
+    let mut bytes = Vec::new();
+    open_input(file_name).and_then(|mut input| input.read_to_end(&mut bytes)).map_err(|e| in_file(e.into()))?;
+
+    // A block is a scope, so it does the job of a `using` block. If the input doesn't
+    // parse, `?` leaves the block early - and the file is still deleted
+    let path = {
+        let _reading = TimedScope::new("reading");
+        let temp = TempFileGuard::create("drop-demo", &bytes).map_err(ReadError::from)?;
+        let stats = stats_of_lines(temp.path()).map_err(in_file)?;
+        println!("Read back from the temp file: {}", stats);
+        temp.path().to_path_buf()
+        // Dropped in reverse order of declaration: temp, then _reading
+    };
+    println!("Still there after the block: {}", path.exists());
+
+    // To clean up before the end of the scope, drop the value early. mem::drop is
+    // nothing more than `fn drop<T>(_x: T) {}` - it takes ownership, so the value's
+    // scope ends inside it
+    let temp = TempFileGuard::create("drop-demo-early", &bytes).map_err(ReadError::from)?;
+    let path = temp.path().to_path_buf();
+    mem::drop(temp);
+    println!("Still there after mem::drop: {}", path.exists());
+
+    // There are no finalizers, and no finalizer queue, because there's no garbage
+    // collector to notice a value is unreachable. A value is dropped exactly when its
+    // owner is done with it, so C#'s Dispose(bool disposing) pattern, with
+    // GC.SuppressFinalize for the case where Dispose was called, has nothing to do.
+    // (mem::forget skips the drop entirely - leaking is safe, just rarely wanted)
+    Ok(())
+}
+
+fn stats_of_lines(path: &Path) -> Result<Stats, ReadError> {
+    let mut stats = Stats::default();
+    for (i, line) in read_lines(path)?.iter().enumerate() {
+        stats = stats.with(parse_line(i + 1, line)?);
+    }
+    Ok(stats)
+}
+
+pub struct DropDemo;
+
+impl Demo for DropDemo {
+    fn name(&self) -> &'static str { "drop" }
+
+    fn description(&self) -> &'static str { "Cleaning up at the end of a scope" }
+
+    fn technique(&self) -> &'static str { "Drop in place of IDisposable and using" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,72 @@
+use std::fs;
+use std::io::{ self, Read };
+
+use crate::{ LineStream, ReadError, ReadMode };
+use crate::encoding::{ decode, decode_lossy, Encoding };
+use crate::registry::{ Demo, DemoError };
+
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    // Start with raw bytes - we can't have a String until we know the bytes are valid text
+    let bytes = match args.get(1) {
+        Some(file_name) => fs::read(file_name).map_err(|e| ReadError::from(e).in_file(file_name))?,
+        None => {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes).map_err(ReadError::from)?;
+            bytes
+        }
+    };
+
+    let (encoding, bom_len) = Encoding::detect(&bytes);
+    println!("{} bytes, with a {} byte byte order mark - looks like {}", bytes.len(), bom_len, encoding);
+
+    // A C# string is a sequence of UTF-16 code units, and can hold anything - even
+    // half of a surrogate pair. A Rust String is *always* valid UTF-8, so the only
+    // way to make one from raw bytes is to check them first
+    match String::from_utf8(bytes.clone()) {
+        Ok(text) => println!("As-is: valid UTF-8, {} chars", text.chars().count()),
+        Err(e)   => println!("As-is: not valid UTF-8 ({})", e)
+    }
+
+    // decode sniffs the byte order mark first, the way StreamReader does, and
+    // converts UTF-16 into UTF-8 as it goes
+    let decoded: Result<String, ReadError> = decode(&bytes);
+    match decoded {
+        Ok(text) => println!("Decoded: {} chars in {} lines", text.chars().count(), text.lines().count()),
+        Err(e) => {
+            println!("Decoded: {}", e);
+            // The lossy version always succeeds, but we have to ask for it - invalid
+            // data becomes U+FFFD rather than quietly disappearing
+            let text = decode_lossy(&bytes);
+            let replaced = text.chars().filter(|&c| c == '\u{FFFD}').count();
+            println!("Decoded lossily: {} chars, {} of them replacement characters", text.chars().count(), replaced);
+        }
+    }
+
+    // Reading line by line, ReadMode decides what happens to invalid bytes -
+    // and we decide on the ReadMode for each call
+    for &mode in [ReadMode::Strict, ReadMode::Lossy].iter() {
+        let lines: Result<Vec<String>, ReadError> = LineStream::with_mode(&bytes[..], mode).collect();
+        match lines {
+            Ok(lines) => {
+                let damaged = lines.iter().filter(|line| line.contains('\u{FFFD}')).count();
+                println!("{:?}, line by line: {} lines, {} with replacement characters", mode, lines.len(), damaged);
+            },
+            Err(e) => println!("{:?}, line by line: {}", mode, e)
+        }
+    }
+    Ok(())
+}
+
+pub struct EncodingDemo;
+
+impl Demo for EncodingDemo {
+    fn name(&self) -> &'static str { "encoding" }
+
+    fn description(&self) -> &'static str { "Why Rust strings are always UTF-8" }
+
+    fn technique(&self) -> &'static str { "validating bytes before they become a String" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread;
+
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+
+// How many threads share the counting at the end
+const WORKERS: usize = 2;
+
+/// How many times each line appears, ignoring case and surrounding whitespace.
+///
+/// The C# is the TryGetValue dance - look the key up, and then look it up again
+/// to store the new count:
+///
+/// ```text
+/// counts.TryGetValue(key, out var count);
+/// counts[key] = count + 1;
+/// ```
+///
+/// `entry` does one lookup and returns the slot, and `or_insert` fills it if it
+/// was empty. Either way it hands back a `&mut usize` to add to.
+pub fn count_lines(lines: &[String]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for line in lines {
+        *counts.entry(line.trim().to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Counts the lines three ways, and then again from two threads at once
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    let counts = count_lines(&lines);
+    let mut sorted: Vec<(&String, &usize)> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    println!("or_insert:");
+    for (line, count) in sorted {
+        println!("  {:<10} {}", line, count);
+    }
+
+    // or_insert_with takes a closure, and only calls it when the key is missing.
+    // or_insert(Vec::new()) would build a Vec for every line, to throw most away
+    let mut places: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut created = 0;
+    for (i, line) in lines.iter().enumerate() {
+        places.entry(line.trim().to_lowercase())
+            .or_insert_with(|| {
+                created += 1;
+                Vec::new()
+            })
+            .push(i + 1);
+    }
+    println!("or_insert_with: {} lists made for {} lines", created, lines.len());
+    if let Some((line, numbers)) = places.iter().max_by_key(|(line, numbers)| (numbers.len(), Reverse(*line))) {
+        println!("  {:?} is on lines {:?}", line, numbers);
+    }
+
+    // and_modify runs on a value which was there, or_insert supplies one which wasn't -
+    // C#'s AddOrUpdate(key, addValue, updateValueFactory), and no more lookups than that
+    let mut spellings: HashMap<String, String> = HashMap::new();
+    for line in &lines {
+        let line = line.trim();
+        spellings.entry(line.to_lowercase())
+            .and_modify(|seen| if !seen.split(" / ").any(|s| s == line) { *seen = format!("{} / {}", seen, line) })
+            .or_insert_with(|| line.to_string());
+    }
+    let mut varied: Vec<&String> = spellings.values().filter(|seen| seen.contains(" / ")).collect();
+    varied.sort();
+    for seen in varied {
+        println!("and_modify: written as {}", seen);
+    }
+
+    // ConcurrentDictionary.AddOrUpdate is safe from several threads at once. Here the
+    // HashMap goes in a Mutex, and each thread holds the lock for its whole entry call -
+    // which is what AddOrUpdate can't promise, since it may call the update twice
+    let shared: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+    let chunk_len = lines.len().div_ceil(WORKERS).max(1);
+    thread::scope(|scope| {
+        for chunk in lines.chunks(chunk_len) {
+            let shared = &shared;
+            scope.spawn(move || {
+                for line in chunk {
+                    *shared.lock().unwrap().entry(line.trim().to_lowercase()).or_insert(0) += 1;
+                }
+            });
+        }
+    });
+    let shared = shared.into_inner().unwrap();
+    println!("Mutex<HashMap>: {} threads, and the counts {}", WORKERS, if shared == counts { "agree" } else { "disagree" });
+    Ok(())
+}
+
+pub struct EntryDemo;
+
+impl Demo for EntryDemo {
+    fn name(&self) -> &'static str { "entry" }
+
+    fn description(&self) -> &'static str { "Counting with one lookup" }
+
+    fn technique(&self) -> &'static str { "the Entry API in place of TryGetValue and AddOrUpdate" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+/// One line of input, by what it holds.
+///
+/// Each variant carries its own data, and only that data: a `Comment` has no number
+/// to forget to check, a `Blank` has nothing at all. C# has no built-in equivalent
+/// yet - the usual stand-in is an abstract record with a sealed record deriving from
+/// it for each case, which the compiler can't tell is a closed set.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Record {
+    Number(u64),
+    Comment(String),
+    Blank
+}
+
+impl Record {
+    /// Works out what `text`, the line numbered `line`, holds. A line which is neither
+    /// blank nor a comment has to be a number.
+    pub fn parse(line: usize, text: &str) -> Result<Record, LineError> {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            Ok(Record::Blank)
+        } else if let Some(comment) = trimmed.strip_prefix('#').or_else(|| trimmed.strip_prefix("//")) {
+            Ok(Record::Comment(comment.trim().to_string()))
+        } else {
+            parse_line(line, text).map(Record::Number)
+        }
+    }
+}
+
+// Parses every line into a Record, then looks through them three ways
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    let records = lines.iter()
+        .enumerate()
+        .map(|(i, line)| Record::parse(i + 1, line).map_err(ReadError::from))
+        .collect::<Result<Vec<Record>, ReadError>>()
+        .map_err(|e| e.in_input(file_name))?;
+
+    // match has to cover every variant, or it doesn't compile. Leave out Blank and:
+    //
+    //   match record {
+    //         ^^^^^^ error[E0004]: non-exhaustive patterns: `&Record::Blank` not covered
+    //
+    // That's what makes adding a variant safe: every match which needs to know about
+    // it is pointed out. A C# switch expression over the records can only warn that it
+    // might not be exhaustive, and needs a `_ => throw` arm to keep quiet - which then
+    // quietly swallows any case added later.
+    //
+    // `if n % 2 == 0` is a guard: the arm is only taken if it's true, and otherwise
+    // matching carries on with the next arm, as with C#'s `when`. Guards aren't counted
+    // towards exhaustiveness, so the unguarded Number(n) arm is still needed
+    for record in &records {
+        let description = match record {
+            Record::Number(n) if n % 2 == 0 => format!("even number {}", n),
+            Record::Number(n)               => format!("odd number {}", n),
+            Record::Comment(text)           => format!("comment \"{}\"", text),
+            Record::Blank                   => "blank".to_string()
+        };
+        println!("{}", description);
+    }
+
+    // When only one variant matters, if let is a match with a single arm - like C#'s
+    // `if (record is Comment c)`, and just as unbothered by the others
+    let mut comments = 0;
+    for record in &records {
+        if let Record::Comment(_) = record {
+            comments += 1;
+        }
+    }
+
+    // A match is an expression, so it can be the body of a closure - this one is a
+    // C# switch expression, with an or-pattern for the two variants with no number and
+    // no `_` arm needed
+    let stats = records.iter()
+        .fold(Stats::default(), |stats, record| match record {
+            Record::Number(n)                  => stats.with(*n),
+            Record::Comment(_) | Record::Blank => stats
+        });
+    println!("{} records, {} comments, {}", records.len(), comments, stats);
+    Ok(())
+}
+
+pub struct EnumsDemo;
+
+impl Demo for EnumsDemo {
+    fn name(&self) -> &'static str { "enums" }
+
+    fn description(&self) -> &'static str { "Enums with data" }
+
+    fn technique(&self) -> &'static str { "enums and exhaustive match in place of class hierarchies" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
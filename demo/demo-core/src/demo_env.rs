@@ -0,0 +1,121 @@
+use std::env::{ self, VarError };
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::registry::{ Demo, DemoError };
+
+/// The prefix of every variable this demo reads, so `workers` is `DEMO_ENV_WORKERS` -
+/// `AddEnvironmentVariables("DEMO_ENV_")`
+pub const PREFIX: &str = "DEMO_ENV_";
+
+/// The settings for a made-up job, each of which can be set in the environment.
+///
+/// Both ways of reading it produce this struct. `Deserialize` is only needed for
+/// the second, where envy maps each field to a variable by name, the way
+/// `IConfiguration.Get<T>()` binds a section to a class. `default` fills in any
+/// field with no variable from `Default`, rather than failing on it.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub name: String,
+    pub workers: usize,
+    pub timeout_ms: u64,
+    pub verbose: bool,
+    /// A comma-separated list: `DEMO_ENV_TAGS=a,b`
+    pub tags: Vec<String>
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings { name: "demo".to_string(), workers: 4, timeout_ms: 1000, verbose: false, tags: Vec::new() }
+    }
+}
+
+/// Reads the settings one variable at a time, parsing each into its field's type.
+///
+/// `var` looks a variable up, which is `env::var` apart from in tests. Unlike
+/// `Environment.GetEnvironmentVariable`, which returns null for both, `env::var`
+/// says whether a variable is missing or isn't valid Unicode - only a missing one
+/// takes the default.
+pub fn from_vars<F: Fn(&str) -> Result<String, VarError>>(var: F) -> Result<Settings, DemoError> {
+    let defaults = Settings::default();
+    Ok(Settings {
+        name: setting(&var, "NAME", defaults.name)?,
+        workers: setting(&var, "WORKERS", defaults.workers)?,
+        timeout_ms: setting(&var, "TIMEOUT_MS", defaults.timeout_ms)?,
+        verbose: setting(&var, "VERBOSE", defaults.verbose)?,
+        // Vec<String> isn't FromStr, so the list is split here, as envy splits it
+        tags: lookup(&var, "TAGS")?
+            .map(|tags| tags.split(',').map(String::from).collect())
+            .unwrap_or(defaults.tags)
+    })
+}
+
+/// Reads the settings in one go, with envy deserializing `vars` - the
+/// environment, as `env::vars()` lists it - into a `Settings`.
+///
+/// The parsing and the defaults are the same, but written as attributes on the
+/// struct rather than a line each in `from_vars`.
+pub fn deserialize<I: IntoIterator<Item = (String, String)>>(vars: I) -> Result<Settings, DemoError> {
+    envy::prefixed(PREFIX).from_iter(vars).map_err(|e| DemoError::Config(e.to_string()))
+}
+
+// The value of PREFIX + name, or None if it isn't set
+fn lookup<F: Fn(&str) -> Result<String, VarError>>(var: &F, name: &str) -> Result<Option<String>, DemoError> {
+    let name = format!("{}{}", PREFIX, name);
+    match var(&name) {
+        Ok(value)                    => Ok(Some(value)),
+        Err(VarError::NotPresent)    => Ok(None),
+        Err(VarError::NotUnicode(_)) => Err(DemoError::Config(format!("{}: not valid Unicode", name)))
+    }
+}
+
+// PREFIX + name parsed as a T, or `default` if it isn't set. A value which is set
+// but doesn't parse is an error, not the default: a typo in WORKERS shouldn't
+// quietly run with 4
+fn setting<T, F>(var: &F, name: &str, default: T) -> Result<T, DemoError>
+    where T: FromStr, T::Err: Display, F: Fn(&str) -> Result<String, VarError>
+{
+    match lookup(var, name)? {
+        Some(value) => value.parse().map_err(|e| DemoError::Config(format!("{}{}: {}: '{}'", PREFIX, name, e, value))),
+        None        => Ok(default)
+    }
+}
+
+// env: `env [--serde]`. Reads the DEMO_ENV_ variables into Settings, by hand or
+// with --serde through envy, and prints each with where it came from
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let settings = match args.get(1).map(|a| &a[..]) {
+        None            => from_vars(|name| env::var(name))?,
+        Some("--serde") => deserialize(env::vars())?,
+        Some(other)     => return Err(DemoError::Usage(format!("Unknown flag {}; expected --serde", other)))
+    };
+    show("name", &settings.name);
+    show("workers", &settings.workers);
+    show("timeout_ms", &settings.timeout_ms);
+    show("verbose", &settings.verbose);
+    show("tags", &format!("{:?}", settings.tags));
+    Ok(())
+}
+
+fn show(field: &str, value: &dyn Display) {
+    let name = format!("{}{}", PREFIX, field.to_uppercase());
+    let source = if env::var_os(&name).is_some() { &name[..] } else { "default" };
+    println!("{:<10} = {:<10} ({})", field, value.to_string(), source);
+}
+
+pub struct EnvDemo;
+
+impl Demo for EnvDemo {
+    fn name(&self) -> &'static str { "env" }
+
+    fn description(&self) -> &'static str { "Settings from environment variables" }
+
+    fn technique(&self) -> &'static str { "env::var, FromStr and envy in place of IConfiguration's environment provider" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
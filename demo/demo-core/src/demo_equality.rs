@@ -0,0 +1,156 @@
+use std::cmp::Ordering;
+use std::collections::{ HashMap, HashSet };
+use std::hash::{ Hash, Hasher };
+use std::io;
+
+use crate::{ read_lines_from_with, read_lines_with, ReadOptions };
+use crate::registry::{ Demo, DemoError };
+use crate::str_ext::StrExt;
+
+/// A name and a count, compared exactly as written.
+///
+/// Everything here is derived: two entries are equal when every field is, the hash
+/// combines the hash of every field, and the ordering compares the fields in the
+/// order they're declared - by name, then by count. It's what a C# `record` gives
+/// for Equals and GetHashCode, plus an IComparable it doesn't.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Entry {
+    pub name: String,
+    pub count: u64
+}
+
+/// A name and a count, read from a line, with the name compared ignoring case.
+///
+/// Which line it came from doesn't matter to what it *is*, and `apple` and `APPLE`
+/// are the same fruit, so derive won't do - every trait is written by hand.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub line: usize,
+    pub name: String,
+    pub count: u64
+}
+
+impl Record {
+    fn key(&self) -> String {
+        self.name.to_lowercase()
+    }
+}
+
+// C#'s Equals(object)
+impl PartialEq for Record {
+    fn eq(&self, other: &Record) -> bool {
+        self.key() == other.key() && self.count == other.count
+    }
+}
+
+// No methods: Eq is a promise that every value equals itself, which PartialEq alone
+// doesn't make (see the floats below). HashMap keys need it
+impl Eq for Record {}
+
+// C#'s GetHashCode, with the same rule: records which are equal must hash the same,
+// so this hashes exactly what eq compares, and leaves the line out too
+impl Hash for Record {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+        self.count.hash(state);
+    }
+}
+
+// C#'s IComparable<Record>.CompareTo - the biggest count first, then by name. It has
+// to agree with eq, so that only equal records compare as Equal
+impl Ord for Record {
+    fn cmp(&self, other: &Record) -> Ordering {
+        other.count.cmp(&self.count).then_with(|| self.key().cmp(&other.key()))
+    }
+}
+
+// Ord is a total order, and every total order is a partial one - so this just
+// defers to it
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Record) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads a `name count` line
+pub fn parse_record(line: usize, text: &str) -> Result<Record, String> {
+    let fields: Vec<&str> = text.split_whitespace().collect();
+    match fields[..] {
+        [name, count] => {
+            let count = count.parse_u64_friendly().map_err(|e| format!("bad count '{}': {}", count, e))?;
+            Ok(Record { line, name: name.to_string(), count })
+        },
+        _ => Err(format!("expected a name and a count, got '{}'", text.trim()))
+    }
+}
+
+// Reads `name count` lines, then groups and sorts them two ways - with the derived
+// traits, and with the hand-written ones
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let options = ReadOptions::new().skip_blank(true).skip_comments(true);
+    let lines = match args.get(1) {
+        Some(file_name) => read_lines_with(file_name, &options).map_err(|e| e.in_file(file_name))?,
+        None            => read_lines_from_with(io::stdin().lock(), &options)?
+    };
+    let mut records = Vec::new();
+    for (line, text) in &lines {
+        match parse_record(*line, text) {
+            Ok(record) => records.push(record),
+            Err(e)     => println!("line {}: {}", line, e)
+        }
+    }
+
+    // Derived: a HashSet drops exact duplicates, and sort orders by name - capitals
+    // first, since strings compare by their bytes
+    let entries: HashSet<Entry> = records.iter().map(|r| Entry { name: r.name.clone(), count: r.count }).collect();
+    let mut entries: Vec<Entry> = entries.into_iter().collect();
+    entries.sort();
+    println!("{} different entries:", entries.len());
+    for entry in &entries {
+        println!("  {} {}", entry.name, entry.count);
+    }
+
+    // Hand-written: `apple 3` and `Apple 3` are one key, wherever they were in the file
+    let mut seen: HashMap<&Record, Vec<usize>> = HashMap::new();
+    for record in &records {
+        seen.entry(record).or_default().push(record.line);
+    }
+    let mut keys: Vec<&Record> = seen.keys().copied().collect();
+    keys.sort();
+    println!("{} different records, by count:", keys.len());
+    for record in keys {
+        println!("  {} {} (lines {:?})", record.key(), record.count, seen[record]);
+    }
+
+    // Floats are only PartialEq and PartialOrd, because NaN isn't equal to anything,
+    // itself included. So a Vec<f64> has no sort() at all:
+    //
+    //   error[E0277]: the trait bound `f64: Ord` is not satisfied
+    //
+    // C# sorts doubles without complaint, since CompareTo puts NaN first - even
+    // though `NaN == NaN` is false there too. Rust makes the choice explicit
+    // The counts come from the input, so they're totalled as u128s, which two u64::MAX can't overflow
+    let total: u128 = entries.iter().map(|entry| u128::from(entry.count)).sum();
+    let mut shares: Vec<f64> = entries.iter().map(|entry| entry.count as f64 / total as f64).collect();
+    // What 0.0 / 0.0 gives. Once it's in, contains can't find it - contains uses ==
+    shares.push(f64::NAN);
+    println!("Contains NaN: {}, NaN compared to 1: {:?}", shares.contains(&f64::NAN), f64::NAN.partial_cmp(&1.0));
+    shares.sort_by(|a, b| a.total_cmp(b));
+    let shares: Vec<String> = shares.iter().map(|share| format!("{:.2}", share)).collect();
+    println!("Sorted with total_cmp: {}", shares.join(", "));
+    Ok(())
+}
+
+pub struct EqualityDemo;
+
+impl Demo for EqualityDemo {
+    fn name(&self) -> &'static str { "equality" }
+
+    fn description(&self) -> &'static str { "Comparing, hashing and sorting records" }
+
+    fn technique(&self) -> &'static str { "Eq, Hash and Ord in place of Equals, GetHashCode and IComparable" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
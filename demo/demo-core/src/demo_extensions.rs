@@ -0,0 +1,56 @@
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+// Without this line, none of the calls below compile:
+//
+//   error[E0599]: no method named `parse_u64_friendly` found for reference `&str`
+//   help: trait `StrExt` which provides `parse_u64_friendly` is implemented but not
+//         in scope; perhaps you want to import it
+//
+// The C# equivalent is the `using` for the namespace holding the static class
+use crate::str_ext::StrExt;
+
+// Reads numbers written any way a Rust literal can be, using methods which str
+// doesn't have - until StrExt adds them
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    let mut numbers = Vec::new();
+    // Each line is a String, and the methods are on str - Deref bridges the gap, the
+    // same as it does for str's own methods
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_blank() || line.is_comment() {
+            continue;
+        }
+        match line.parse_u64_friendly() {
+            Ok(number) => numbers.push(number),
+            Err(e)     => println!("line {}: {}: '{}'", i + 1, e, line.trim())
+        }
+    }
+    println!("{}", Stats::of(&numbers));
+
+    // The orphan rule is why StrExt is a trait of our own. Display is someone else's
+    // trait and Vec someone else's type, so this would be a clash waiting to happen:
+    //
+    //   impl fmt::Display for Vec<u64> { ... }
+    //   error[E0117]: only traits defined in the current crate can be implemented for
+    //                 types defined outside of the crate
+    //
+    // C# has no such rule, and two libraries can both add a `Parse` extension method
+    // to string - which then fails to compile as ambiguous, wherever both are imported
+    Ok(())
+}
+
+pub struct ExtensionsDemo;
+
+impl Demo for ExtensionsDemo {
+    fn name(&self) -> &'static str { "extensions" }
+
+    fn description(&self) -> &'static str { "New methods on a type we don't own" }
+
+    fn technique(&self) -> &'static str { "extension traits in place of extension methods" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
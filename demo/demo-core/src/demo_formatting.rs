@@ -0,0 +1,65 @@
+use std::fmt;
+
+use crate::read_input_lines;
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+/// One number, where it was, and how much of the total it makes up
+pub struct Row {
+    pub line: usize,
+    pub value: u64,
+    pub share: f64
+}
+
+// The specifiers are C#'s alignment and format strings under another name:
+// `{:>8}` is `{value,8}`, `{:<8}` is `{value,-8}`, and `{:.1}` is `{share:F1}`.
+// format! and println! check them against the arguments at compile time, which
+// string.Format doesn't, and interpolated strings only partly do
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {:>3}: {:>8} {:>5.1}%", self.line, self.value, self.share * 100.0)
+    }
+}
+
+// Prints the input as a table, then the same Stats every way it knows how. Nothing
+// here builds its own strings: every line comes from a Display or Debug impl
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+    let mut numbered = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let parsed: Result<u64, LineError> = parse_line(i + 1, line);
+        match parsed {
+            Ok(value) => numbered.push((i + 1, value)),
+            Err(e)    => println!("{}", e)
+        }
+    }
+
+    let numbers: Vec<u64> = numbered.iter().map(|&(_, value)| value).collect();
+    let stats = Stats::of(&numbers);
+    for &(line, value) in &numbered {
+        println!("{}", Row { line, value, share: value as f64 / stats.sum as f64 });
+    }
+
+    println!("{{}}      {}", stats);
+    println!("{{:.4}}   {:.4}", stats);
+    println!("{{:>70.1}} [{:>70.1}]", stats);
+    println!("{{:?}}    {:?}", stats);
+    println!("{{:#}}\n{:#}", stats);
+    println!("{{:#?}}\n{:#?}", stats);
+    Ok(())
+}
+
+pub struct FormattingDemo;
+
+impl Demo for FormattingDemo {
+    fn name(&self) -> &'static str { "formatting" }
+
+    fn description(&self) -> &'static str { "Formatting output" }
+
+    fn technique(&self) -> &'static str { "Display, Debug and format specifiers in place of ToString and format strings" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::mpsc;
+use std::thread;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+
+// "Where's yield return?" Rust has no stable answer yet, so this demo writes the same
+// generator three ways. In C# it would be:
+//
+//   static IEnumerable<ulong> Numbers(IEnumerable<string> lines) {
+//       foreach (var line in lines) {
+//           if (string.IsNullOrWhiteSpace(line)) continue;
+//           yield return ulong.Parse(line);
+//       }
+//   }
+//
+// Each version yields a Result per line, so a bad line stops whoever is consuming it
+type Item = Result<u64, LineError>;
+
+// Reads the input and puts it through each version of the generator in turn
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    let in_file = |e: LineError| ReadError::from(e).in_input(file_name);
+
+    let by_hand: Vec<u64> = Numbers::new(&lines).collect::<Result<_, _>>().map_err(in_file)?;
+    println!("State machine: {:?}", by_hand);
+
+    let on_thread: Vec<u64> = numbers_on_thread(lines.clone()).collect::<Result<_, _>>().map_err(in_file)?;
+    println!("Channel:       {:?}", on_thread);
+    assert_eq!(by_hand, on_thread);
+
+    #[cfg(feature = "nightly")]
+    {
+        let coroutine: Vec<u64> = numbers_coroutine(lines).collect::<Result<_, _>>().map_err(in_file)?;
+        println!("Coroutine:     {:?}", coroutine);
+        assert_eq!(by_hand, coroutine);
+    }
+    Ok(())
+}
+
+// 1. An explicit state machine, which is what the C# compiler turns a yield return
+// method into for us. The state is everything the loop would have kept in local
+// variables between yields: where we are in the lines
+struct Numbers<'a> {
+    lines: std::iter::Enumerate<std::slice::Iter<'a, String>>
+}
+
+impl<'a> Numbers<'a> {
+    fn new(lines: &'a [String]) -> Numbers<'a> {
+        Numbers { lines: lines.iter().enumerate() }
+    }
+}
+
+impl<'a> Iterator for Numbers<'a> {
+    type Item = Item;
+
+    // Each call picks up where the last left off, runs to the next yield - the return -
+    // and leaves the state ready for next time
+    fn next(&mut self) -> Option<Item> {
+        for (i, line) in self.lines.by_ref() {
+            if !line.trim().is_empty() {
+                return Some(parse_line(i + 1, line));
+            }
+        }
+        None
+    }
+}
+
+// 2. The loop, written just as it would be in C#, on a thread of its own - with each
+// yield return a send down a channel, and the Receiver as the iterator. A channel of
+// size 0 makes each send wait until the item is received, so the generator runs one
+// item ahead of its consumer and no further, like a real one. It costs a thread, and
+// the thread needs its own copy of the lines
+fn numbers_on_thread(lines: Vec<String>) -> impl Iterator<Item = Item> {
+    let (sender, receiver) = mpsc::sync_channel(0);
+    thread::spawn(move || {
+        for (i, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            // The consumer has stopped listening - the equivalent of a foreach which
+            // breaks early, disposing the enumerator - so stop generating
+            if sender.send(parse_line(i + 1, line)).is_err() {
+                return;
+            }
+        }
+    });
+    receiver.into_iter()
+}
+
+// 3. The real thing, on nightly Rust - in a module of its own, as even code which is
+// compiled out has to parse, and only a nightly compiler can parse `yield`
+#[cfg(feature = "nightly")]
+mod coroutine;
+#[cfg(feature = "nightly")]
+use self::coroutine::numbers_coroutine;
+
+pub struct GeneratorDemo;
+
+impl Demo for GeneratorDemo {
+    fn name(&self) -> &'static str { "generator" }
+
+    fn description(&self) -> &'static str { "Where's yield return?" }
+
+    fn technique(&self) -> &'static str { "state machines, channels and coroutines" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,23 @@
+use std::iter;
+
+use crate::numbers::parse_line;
+
+use super::Item;
+
+// `gen` blocks are the planned syntax for generators, but they need the 2024 edition.
+// The coroutines underneath them work in any edition, and from_coroutine turns one
+// into an iterator. The body is the C# almost word for word.
+//
+// The coroutine owns the lines and takes each one out in turn. Borrowing them instead
+// would leave a borrow alive across a yield, pointing into the coroutine's own state,
+// and the compiler only allows that for a coroutine which is pinned in place
+pub fn numbers_coroutine(lines: Vec<String>) -> impl Iterator<Item = Item> {
+    iter::from_coroutine(#[coroutine] move || {
+        for (i, line) in lines.into_iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            yield parse_line(i + 1, &line);
+        }
+    })
+}
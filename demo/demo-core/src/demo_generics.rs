@@ -0,0 +1,107 @@
+use std::fmt::{ self, Display };
+use std::str::FromStr;
+
+use crate::read_input_lines;
+use crate::literal::Literal;
+use crate::numbers::{ parse_line, CheckedAdd, LineError };
+use crate::registry::{ Demo, DemoError };
+
+/// Why `sum_values` stopped
+#[derive(Debug)]
+pub enum SumError<E> {
+    /// A line which isn't a `T`
+    Parse(LineError<E>),
+    /// The line with this number took the sum past the largest `T`
+    Overflow(usize)
+}
+
+impl<E: Display> Display for SumError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SumError::Parse(ref e)   => write!(f, "{}", e),
+            SumError::Overflow(line) => write!(f, "line {}: the sum is too big", line)
+        }
+    }
+}
+
+impl<E> From<LineError<E>> for SumError<E> {
+    fn from(e: LineError<E>) -> SumError<E> {
+        SumError::Parse(e)
+    }
+}
+
+/// Adds up every line, parsed as a `T`, stopping at the first which doesn't parse,
+/// or which makes the sum too big for a `T`.
+///
+/// The bounds say what `T` has to be able to do: parse (`FromStr`), be added to
+/// another `T`, saying when the sum doesn't fit (`CheckedAdd`), and start from a
+/// zero (`Default`). The C# equivalent is `where T : INumber<T>`, with `T.Zero` in
+/// place of `Default` - though INumber asks for a great deal more than this function
+/// uses. A Rust generic asks for exactly what it needs, and nothing else will compile.
+pub fn sum_values<T: FromStr + CheckedAdd + Default>(lines: &[String]) -> Result<T, SumError<T::Err>> {
+    let mut sum = T::default();
+    for (i, line) in lines.iter().enumerate() {
+        sum = sum.checked_add(parse_line(i + 1, line)?).ok_or(SumError::Overflow(i + 1))?;
+    }
+    Ok(sum)
+}
+
+// The same bounds as a where clause, which is where they go when they get long - and
+// where they have to go when they're about something other than T itself. `T::Err:
+// Display` constrains T's parse error, which C# has no way to say at all
+fn report<T>(type_name: &str, lines: &[String])
+    where T: FromStr + CheckedAdd + Default + Display,
+          T::Err: Display
+{
+    match sum_values::<T>(lines) {
+        Ok(sum) => println!("{:<8} {}", type_name, sum),
+        Err(e)  => println!("{:<8} {}", type_name, e)
+    }
+}
+
+// Sums the input as several types, using one generic function for all of them. A line
+// one type can't parse may suit another, so a failure is reported and the demo moves on
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    // Nothing in the arguments says what T is, so the compiler works it out from where
+    // the result goes - here, a variable of type Result<u64, _>. It's the same
+    // inference which picks the type for parse and collect, and the _ leaves the error
+    // type for it to work out too
+    let total: Result<u64, _> = sum_values(&lines);
+    match total {
+        Ok(sum) => println!("{:<8} {}", "u64", sum),
+        Err(e)  => println!("{:<8} {}", "u64", e)
+    }
+
+    // When there's nowhere to infer it from, T is given with the *turbofish*, ::<>.
+    // C# writes Sum<long>(lines); Rust needs the :: so that < can't be mistaken for
+    // less-than
+    report::<i64>("i64", &lines);
+    report::<f64>("f64", &lines);
+    // Literal is our own type, and already derives Default (zero), so its CheckedAdd
+    // (in literal.rs) is all it takes to sum it. Making a C# type an INumber<T> means
+    // implementing dozens of members
+    report::<Literal>("Literal", &lines);
+
+    // A type which doesn't meet the bounds is refused where it's used, with the bound
+    // it's missing - not somewhere deep inside sum_values:
+    //
+    //   report::<String>("String", &lines);
+    //            ^^^^^^ error[E0277]: the trait bound `String: CheckedAdd` is not satisfied
+    Ok(())
+}
+
+pub struct GenericsDemo;
+
+impl Demo for GenericsDemo {
+    fn name(&self) -> &'static str { "generics" }
+
+    fn description(&self) -> &'static str { "Generics with trait bounds" }
+
+    fn technique(&self) -> &'static str { "trait bounds and where clauses in place of generic constraints" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
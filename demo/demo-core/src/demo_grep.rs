@@ -0,0 +1,109 @@
+use regex::{ Regex, RegexBuilder };
+
+use crate::read_lines_from;
+use crate::progress;
+use crate::registry::{ Demo, DemoError };
+
+/// grep's flags
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flags {
+    /// `-v`: print the lines which don't match instead
+    pub invert: bool,
+    /// `-i`: ignore case
+    pub ignore_case: bool
+}
+
+/// Reads the flags, given separately or together as `-vi`, and returns them with
+/// the arguments which follow: the pattern, and then the file, if there is one.
+pub fn parse_args(args: &[String]) -> Result<(Flags, &[String]), DemoError> {
+    let mut flags = Flags::default();
+    let mut rest = args;
+    while let Some((arg, after)) = rest.split_first() {
+        if arg.len() < 2 || !arg.starts_with('-') {
+            break;
+        }
+        for flag in arg.chars().skip(1) {
+            match flag {
+                'v' => flags.invert = true,
+                'i' => flags.ignore_case = true,
+                _   => return Err(DemoError::Usage(format!("Unknown flag -{}; expected -v or -i", flag)))
+            }
+        }
+        rest = after;
+    }
+    Ok((flags, rest))
+}
+
+/// The parts of `line` which `regex` matches.
+///
+/// C#'s `Match.Value` is a new string, copied out of the input. A `regex::Match<'h>`
+/// is a position in the haystack, and `as_str` borrows the text from it - so the
+/// `&'h str`s here point into `line`, and can't outlive it.
+pub fn matches<'h>(regex: &Regex, line: &'h str) -> Vec<&'h str> {
+    regex.find_iter(line).map(|m| m.as_str()).collect()
+}
+
+/// `line` with each match in square brackets.
+///
+/// `replace_all` returns a `Cow<str>`, which only allocates when something matched;
+/// a line with no match is handed back borrowed.
+pub fn highlight(regex: &Regex, line: &str) -> String {
+    regex.replace_all(line, "[$0]").into_owned()
+}
+
+// grep: `grep [-vi] [--progress] <pattern> [file]`, reading stdin when there's no
+// file, with a progress bar while it reads given --progress.
+//
+// The pattern is compiled once, like `new Regex(pattern)`, and checked then - a bad
+// pattern is a usage error before any of the input is read. The regex crate never
+// backtracks, so it runs in time linear in the input however the pattern is
+// written. The price is that it has no lookaround or backreferences, which .NET's
+// engine does
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let (with_progress, args) = progress::take_flag(&args[1..]);
+    let (flags, rest) = parse_args(&args)?;
+    let pattern = rest.first()
+        .ok_or_else(|| DemoError::Usage("Expected a pattern to search for".to_string()))?;
+    // RegexBuilder is the RegexOptions argument
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(flags.ignore_case)
+        .build()
+        .map_err(|e| DemoError::Usage(format!("Invalid pattern: {}", e)))?;
+
+    let file_name = rest.get(1).map(|s| &s[..]);
+    let lines = progress::read_input(file_name, with_progress, read_lines_from).map_err(|e| e.in_input(file_name))?;
+
+    let mut found = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if regex.is_match(line) == flags.invert {
+            continue;
+        }
+        if flags.invert {
+            println!("{:>4}: {}", i + 1, line);
+        } else {
+            println!("{:>4}: {}", i + 1, highlight(&regex, line));
+            found.extend(matches(&regex, line));
+        }
+    }
+
+    // found is full of &strs borrowed from lines, so lines has to outlive it - drop
+    // lines before this and it won't compile
+    if !flags.invert {
+        println!("{} matches: {:?}", found.len(), found);
+    }
+    Ok(())
+}
+
+pub struct GrepDemo;
+
+impl Demo for GrepDemo {
+    fn name(&self) -> &'static str { "grep" }
+
+    fn description(&self) -> &'static str { "Filtering lines with a regex" }
+
+    fn technique(&self) -> &'static str { "the regex crate in place of System.Text.RegularExpressions" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+/// Groups the items of another iterator into chunks of (at most) `size` items.
+///
+/// In C# this would be a `yield return` method, and the compiler would write the
+/// state machine:
+///
+/// ```text
+/// static IEnumerable<List<T>> Chunked<T>(IEnumerable<T> items, int size) {
+///     var chunk = new List<T>(size);
+///     foreach (var item in items) {
+///         chunk.Add(item);
+///         if (chunk.Count == size) { yield return chunk; chunk = new List<T>(size); }
+///     }
+///     if (chunk.Count > 0) yield return chunk;
+/// }
+/// ```
+///
+/// Rust has no `yield` (yet), so this is that state machine written by hand, like
+/// implementing `IEnumerator<T>` ourselves. The state is only the iterator being
+/// chunked, as nothing needs remembering between calls to `next`.
+pub struct Chunked<I> {
+    items: I,
+    size: usize
+}
+
+impl<I: Iterator> Chunked<I> {
+    pub fn new(items: I, size: usize) -> Chunked<I> {
+        assert!(size > 0, "chunks must hold at least one item");
+        Chunked { items, size }
+    }
+}
+
+// Iterator has one required method, next, which is MoveNext and Current rolled into
+// one: None means there's nothing more. Every adaptor - map, filter, sum and the rest -
+// comes for free, where C# gets LINQ from extension methods on IEnumerable<T>
+impl<I: Iterator> Iterator for Chunked<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk: Vec<I::Item> = self.items.by_ref().take(self.size).collect();
+        if chunk.is_empty() { None } else { Some(chunk) }
+    }
+
+    // Optional, but it lets collect allocate the right amount of space up front. It's
+    // the bounds on how many items are left: at least the first number, and at most
+    // the second if there is one. We can work ours out from the ones we're chunking
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.items.size_hint();
+        (lower.div_ceil(self.size), upper.map(|upper| upper.div_ceil(self.size)))
+    }
+}
+
+// When the size of what we're chunking is known exactly, so is ours, and saying so
+// gives Chunked a len() method - C#'s ICollection<T>.Count, without being a collection
+impl<I: ExactSizeIterator> ExactSizeIterator for Chunked<I> {}
+
+// Adds up the numbers in the input a chunk at a time
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let size = args.get(1).and_then(|size| size.parse().ok()).filter(|&size| size > 0)
+        .ok_or_else(|| DemoError::Usage("Expected a chunk size of at least 1".to_string()))?;
+    let file_name = args.get(2).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+
+    let chunks = Chunked::new(lines.iter().enumerate(), size);
+    println!("{} lines in {} chunks of up to {}", lines.len(), chunks.len(), size);
+    for chunk in chunks {
+        let (first, _) = chunk[0];
+        let (last, _) = chunk[chunk.len() - 1];
+        // Summing into a u64 would panic on overflow, so the fold checks each addition,
+        // and stops at a line which doesn't parse or a total which doesn't fit
+        let sum = chunk.iter().try_fold(0u64, |sum, &(i, line)| {
+            let n: u64 = parse_line(i + 1, line).map_err(|e| ReadError::from(e).in_input(file_name))?;
+            sum.checked_add(n).ok_or_else(|| DemoError::Overflow(format!("Lines {} to {} add up to more than a u64 can hold", first + 1, last + 1)))
+        })?;
+        println!("Lines {} to {}: {}", first + 1, last + 1, sum);
+    }
+    Ok(())
+}
+
+pub struct IteratorDemo;
+
+impl Demo for IteratorDemo {
+    fn name(&self) -> &'static str { "iterator" }
+
+    fn description(&self) -> &'static str { "Writing an iterator" }
+
+    fn technique(&self) -> &'static str { "impl Iterator in place of IEnumerator<T>" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
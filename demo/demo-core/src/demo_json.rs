@@ -0,0 +1,154 @@
+use std::cmp::Reverse;
+use std::io::{ self, Read };
+use std::path::Path;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::{ read_lines, read_lines_from, AnyParseError, ReadError };
+use crate::demo6;
+use crate::numbers::LineError;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+/// A job to run over a file of numbers, as jobs.json describes it.
+///
+/// `rename_all = "camelCase"` maps `source_file` to `"sourceFile"`, like a
+/// `JsonNamingPolicy.CamelCase`, but on the type rather than the serializer's
+/// options - so every caller gets the same names.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    pub name: String,
+    pub source_file: String,
+    /// Missing from the JSON is `None`, as `null` would be. Leaving `None` out when
+    /// writing is `[JsonIgnore(Condition = WhenWritingNull)]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lines: Option<usize>,
+    pub output: Output,
+    /// `default` makes a missing list empty, where System.Text.Json would leave it null
+    #[serde(default)]
+    pub tags: Vec<String>
+}
+
+/// What a job works out.
+///
+/// Each variant is a shape of JSON object, and `tag = "kind"` says which it is:
+/// `{ "kind": "top", "count": 3 }`. That's `[JsonPolymorphic]` with a
+/// `[JsonDerivedType]` for each subclass - except that here the set of cases is
+/// closed, so a `match` on it has to handle them all.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Output {
+    Summary,
+    Top { count: usize }
+}
+
+/// What came of running a job, to be written back out as JSON.
+///
+/// It borrows the job's name rather than copying it - serializing only needs to
+/// read it.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobResult<'a> {
+    pub job: &'a str,
+    pub lines_read: usize,
+    /// `flatten` writes the outcome's fields into this object, rather than nesting them
+    #[serde(flatten)]
+    pub outcome: Outcome
+}
+
+/// The figures a job produced, or why it couldn't
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Outcome {
+    Summary { count: usize, sum: u128, mean: Option<f64> },
+    Top { values: Vec<u64> },
+    Failed { error: String }
+}
+
+/// Parses a list of jobs, failing on JSON which doesn't match `Job` exactly -
+/// a missing field, a field of the wrong type, or an unknown `kind`.
+pub fn parse_jobs<R: Read>(read: R) -> serde_json::Result<Vec<Job>> {
+    serde_json::from_reader(read)
+}
+
+/// Runs a job over the numbers in its source file. A file which can't be read
+/// fails that job, but not the others.
+pub fn run_job(job: &Job) -> JobResult<'_> {
+    let path = Path::new(&job.source_file);
+    let numbers = match demo6::read_values::<u64>(path, None) {
+        Ok(numbers) => numbers,
+        Err(e) => {
+            let outcome = Outcome::Failed { error: e.in_file(path).to_string() };
+            return JobResult { job: &job.name, lines_read: 0, outcome };
+        }
+    };
+    let numbers = &numbers[..job.max_lines.unwrap_or(numbers.len()).min(numbers.len())];
+    let outcome = match job.output {
+        Output::Summary => {
+            let stats = Stats::of(numbers);
+            Outcome::Summary { count: stats.count, sum: stats.sum, mean: stats.mean() }
+        },
+        Output::Top { count } => {
+            let mut values = numbers.to_vec();
+            values.sort_unstable_by_key(|&n| Reverse(n));
+            values.truncate(count);
+            Outcome::Top { values }
+        }
+    };
+    JobResult { job: &job.name, lines_read: numbers.len(), outcome }
+}
+
+// Reads a list of jobs from JSON, runs them, and writes the results as JSON
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let jobs = match args.get(1) {
+        Some(file_name) => {
+            let lines = read_lines(file_name).map_err(|e| e.in_file(file_name))?;
+            parse_lines(&lines).map_err(|e| DemoError::Parse(e.in_file(file_name)))?
+        },
+        None => parse_lines(&read_lines_from(io::stdin().lock())?).map_err(DemoError::Parse)?
+    };
+
+    // Debug shows what the JSON became: Option and enum values, not strings and nulls
+    for job in &jobs {
+        println!("{:?}", job);
+    }
+    let results: Vec<JobResult> = jobs.iter().map(run_job).collect();
+    // to_string_pretty is WriteIndented = true
+    let json = serde_json::to_string_pretty(&results).expect("results always serialize");
+    println!("{}", json);
+    Ok(())
+}
+
+// The shared reader deals with the encoding, then serde_json parses the text. Its
+// errors say where in the document they are, so each becomes a LineError like any
+// other input which doesn't parse, and the runner reports the line and column
+fn parse_lines(lines: &[String]) -> Result<Vec<Job>, ReadError<AnyParseError>> {
+    parse_jobs(lines.join("\n").as_bytes()).map_err(|e| {
+        // serde_json puts the position at the end of its message; the LineError says
+        // where it was, so it's taken off rather than said twice
+        let message = e.to_string();
+        let position = format!(" at line {} column {}", e.line(), e.column());
+        let problem = message.strip_suffix(&position).unwrap_or(&message).to_string();
+        ReadError::BadLine(LineError {
+            line: e.line(),
+            column: Some(e.column()),
+            text: lines.get(e.line().saturating_sub(1)).cloned().unwrap_or_default(),
+            error: AnyParseError(problem.into())
+        })
+    })
+}
+
+pub struct JsonDemo;
+
+impl Demo for JsonDemo {
+    fn name(&self) -> &'static str { "json" }
+
+    fn description(&self) -> &'static str { "Typed JSON in and out" }
+
+    fn technique(&self) -> &'static str { "serde_json and #[derive] in place of System.Text.Json attributes" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// Runs the same chain of iterator adaptors several ways, with every step of it
+// printing what it's doing, to show when - and whether - each step runs
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+
+    // Building the chain runs none of it, just as defining a LINQ query doesn't.
+    // Each adaptor only wraps the iterator before it, and waits to be asked for an item
+    println!("Building the chain:");
+    let numbers = chain(&lines);
+    println!("    nothing yet");
+
+    // collect is ToList: it asks for every item, so every step runs. Note the order -
+    // each line goes all the way through before the next is read, rather than each
+    // step running over the whole input in turn. LINQ to Objects streams the same way
+    println!("collect():");
+    let all: Vec<u64> = numbers.collect::<Result<_, ReadError>>()?;
+    println!("    = {:?}", all);
+
+    // collect took the chain by value, so `numbers` is gone. Enumerating an IEnumerable
+    // twice quietly runs the query twice; here the compiler makes us ask for that, by
+    // building it again (or cloning it, if every adaptor's state can be cloned)
+    println!("take(2):");
+    // take stops asking once it has two, so the rest of the input is never read
+    let first_two: Vec<u64> = chain(&lines).take(2).collect::<Result<_, ReadError>>()?;
+    println!("    = {:?}", first_two);
+
+    // find stops at the first match, like FirstOrDefault(predicate)
+    println!("find(over 100):");
+    let found = chain(&lines).find(|n| n.as_ref().map_or(true, |&n| n > 100)).transpose()?;
+    println!("    = {:?}", found);
+    Ok(())
+}
+
+// Parses the lines and keeps the even numbers, times ten. Each step says when it runs
+fn chain(lines: &[String]) -> impl Iterator<Item = Result<u64, ReadError>> + '_ {
+    lines.iter()
+        .enumerate()
+        .inspect(|(_, line)| println!("    read   {}", line))
+        .map(|(i, line)| parse_line::<u64>(i + 1, line).map_err(ReadError::from))
+        .filter(|n| {
+            // Errors are kept, so collect can stop on them
+            let keep = n.as_ref().map_or(true, |n| n % 2 == 0);
+            if let Ok(n) = n {
+                println!("    filter {} -> {}", n, if keep { "keep" } else { "skip" });
+            }
+            keep
+        })
+        .map(|n| n.map(|n| {
+            println!("    map    {} -> {}", n, n * 10);
+            n * 10
+        }))
+}
+
+pub struct LazyDemo;
+
+impl Demo for LazyDemo {
+    fn name(&self) -> &'static str { "lazy" }
+
+    fn description(&self) -> &'static str { "Lazy evaluation" }
+
+    fn technique(&self) -> &'static str { "Iterator adaptors in place of deferred execution" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,44 @@
+use std::io;
+
+use crate::read_lines_from;
+use crate::diagnostics;
+use crate::numbers::{ parse_numbers_lenient, read_numbers_lenient };
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    // Failing to read the input is still an error - only bad lines are skipped
+    let (numbers, errors) = match args.get(1) {
+        Some(file_name) => read_numbers_lenient(file_name).map_err(|e| e.in_file(file_name))?,
+        None => parse_numbers_lenient(&read_lines_from(io::stdin().lock())?)
+    };
+
+    for n in numbers.iter() {
+        println!("{}", n);
+    }
+    println!("Read {}", Stats::of(&numbers));
+
+    // The bad lines come back as values too, so we can report all of them
+    // rather than stopping at the first
+    if !errors.is_empty() {
+        diagnostics::warning(format!("Skipped {} lines:", errors.len()));
+        for e in errors.iter() {
+            diagnostics::detail(e);
+        }
+    }
+    Ok(())
+}
+
+pub struct LenientDemo;
+
+impl Demo for LenientDemo {
+    fn name(&self) -> &'static str { "lenient" }
+
+    fn description(&self) -> &'static str { "Skipping bad lines" }
+
+    fn technique(&self) -> &'static str { "collecting errors instead of returning the first" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
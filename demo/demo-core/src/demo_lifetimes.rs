@@ -0,0 +1,122 @@
+use std::io::Read;
+
+use crate::{ open_input, ReadError };
+use crate::encoding::decode;
+use crate::registry::{ Demo, DemoError };
+
+/// One line of input, split into its value and any comment after it.
+///
+/// Neither part is a copy: both are slices of the text the entry was read from.
+/// The `'a` says so - an `Entry<'a>` can't outlive the `&'a str` it borrows from.
+#[derive(Debug)]
+pub struct Entry<'a> {
+    pub line: usize,
+    pub value: &'a str,
+    pub comment: Option<&'a str>
+}
+
+// Reads the whole input into one String, then picks it apart without copying any of it.
+//
+// C# strings are immutable objects on the heap, so Substring has to copy - there's no
+// way for a string to point into the middle of another. ReadOnlySpan<char> can, but
+// it can only live on the stack, so it can't be stored in a list or a field of a class.
+// A Rust &str can go anywhere, because the compiler tracks what it points into and
+// refuses any code where it could outlive that
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let text = read_text(file_name).map_err(|e| e.in_input(file_name))?;
+
+    let entries = entries(&text);
+    for entry in &entries {
+        match entry.comment {
+            Some(comment) => println!("{:>4}: {:<12} # {}", entry.line, entry.value, comment),
+            None          => println!("{:>4}: {}", entry.line, entry.value)
+        }
+    }
+    if let Some(longest) = entries.iter().map(|entry| entry.value).reduce(longest) {
+        println!("Longest value: {}", longest);
+        // Proof there was no copying: the slice's address is inside the text's buffer
+        let range = text.as_bytes().as_ptr_range();
+        println!("Inside the buffer: {}", range.contains(&longest.as_ptr()));
+    }
+
+    // Dropping `text` while `entries` still borrows from it doesn't compile:
+    //
+    //   drop(text);
+    //        ^^^^ error[E0505]: cannot move out of `text` because it is borrowed
+    //   println!("{:?}", entries);
+    Ok(())
+}
+
+// The whole input, decoded the same way read_lines decodes it
+fn read_text(file_name: Option<&str>) -> Result<String, ReadError> {
+    let mut bytes = Vec::new();
+    open_input(file_name)?.read_to_end(&mut bytes)?;
+    decode(&bytes)
+}
+
+/// Splits `text` into entries, skipping blank lines and lines which are only a comment.
+///
+/// With one reference in and references out, the compiler assumes the output borrows
+/// from the input - one of the *elision* rules which mean most functions never need a
+/// lifetime written. Written out in full, this is
+/// `fn entries<'a>(text: &'a str) -> Vec<Entry<'a>>`.
+pub fn entries(text: &str) -> Vec<Entry<'_>> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(i, line)| match split_comment(line) {
+            ("", _)          => None,
+            (value, comment) => Some(Entry { line: i + 1, value, comment })
+        })
+        .collect()
+}
+
+// Elided again: both halves come from `line`. A comment starts with # or // at the
+// start of the line or after a space, so the # in C# isn't one
+fn split_comment(line: &str) -> (&str, Option<&str>) {
+    for (at, _) in line.char_indices() {
+        if at > 0 && !line[..at].ends_with(char::is_whitespace) {
+            continue;
+        }
+        for marker in &["#", "//"] {
+            if line[at..].starts_with(marker) {
+                return (line[..at].trim(), Some(line[at + marker.len()..].trim()));
+            }
+        }
+    }
+    (line.trim(), None)
+}
+
+// With two references in, the compiler can't guess which one the result borrows
+// from, so we have to say. Giving both the same lifetime means the result is valid
+// for as long as both of them are
+fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if b.len() > a.len() { b } else { a }
+}
+
+// And a function can never return a reference to something it owns, because what it
+// owns is dropped as it returns. In C# the garbage collector would keep the string
+// alive; here there's no garbage collector, so the compiler refuses:
+//
+//   fn first_line(file_name: &str) -> &str {
+//       let text = read_text(Some(file_name)).unwrap();
+//       text.lines().next().unwrap()
+//       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ error[E0515]: cannot return value referencing
+//                                    local variable `text`
+//   }
+//
+// The fix is to return something owned - a String - and let the caller own it.
+
+pub struct LifetimesDemo;
+
+impl Demo for LifetimesDemo {
+    fn name(&self) -> &'static str { "lifetimes" }
+
+    fn description(&self) -> &'static str { "Slices of a buffer" }
+
+    fn technique(&self) -> &'static str { "&str with lifetimes in place of Substring" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,85 @@
+use itertools::Itertools;
+
+use crate::read_input_numbers;
+use crate::registry::{ Demo, DemoError };
+
+// Reads some numbers, then puts them through the iterator version of each of the
+// LINQ operators C# developers reach for most. Each one has its C# in a comment
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let numbers = read_input_numbers(args.get(1).map(|s| &s[..]))?;
+
+    // Iterator adaptors live on the Iterator trait, where LINQ's are extension methods
+    // on IEnumerable<T>. itertools adds more of them the same way, through a trait of
+    // its own - which is why `use itertools::Itertools` is needed, like a C# `using`
+    // to bring extension methods into scope.
+
+    // numbers.Select(n => checked(n * 2)). Collecting Options into an Option<Vec<_>>
+    // gives None if any of them was None, as an OverflowException would stop the query
+    let doubled: Vec<u64> = numbers.iter().map(|n| n.checked_mul(2)).collect::<Option<_>>()
+        .ok_or_else(|| DemoError::Overflow("a number is too big to double".to_string()))?;
+    println!("Select (doubled):     {:?}", doubled);
+
+    // numbers.Where(n => n % 2 == 0)
+    let even: Vec<u64> = numbers.iter().filter(|&&n| n % 2 == 0).cloned().collect();
+    println!("Where (even):         {:?}", even);
+
+    // numbers.OrderByDescending(n => n). std has sort and sort_by too, but they sort a
+    // Vec in place rather than being part of a chain - sorted_by is from itertools
+    let descending: Vec<u64> = numbers.iter().cloned().sorted_by(|a, b| b.cmp(a)).collect();
+    println!("OrderByDescending:    {:?}", descending);
+
+    // numbers.GroupBy(n => n.ToString().Length). Careful: itertools' chunk_by (which
+    // used to be called group_by) only groups neighbours, like a run of equal keys, so
+    // the numbers are sorted by key first. into_group_map is the closer match to LINQ,
+    // gathering every group into a HashMap - though without LINQ's order
+    println!("GroupBy (digits):");
+    for (digits, group) in &numbers.iter().sorted_by_key(|n| digits(**n)).chunk_by(|n| digits(**n)) {
+        println!("    {}: {:?}", digits, group.collect::<Vec<_>>());
+    }
+
+    // numbers.Aggregate((Min: ulong.MaxValue, Max: 0UL),
+    //     (acc, n) => (Math.Min(acc.Min, n), Math.Max(acc.Max, n)))
+    // Sum, Min and Max are iterator methods too, but fold is the general case - here
+    // finding both ends of the range in one pass. Seeded with (MaxValue, 0), an empty
+    // sequence would give a range from MaxValue down to 0, so the fold starts from
+    // None instead, and there's only a range once there's a number
+    let range = numbers.iter().fold(None, |range, &n| match range {
+        Some((min, max)) => Some((u64::min(min, n), u64::max(max, n))),
+        None             => Some((n, n))
+    });
+    match range {
+        Some((min, max)) => println!("Aggregate (range):    {}..={}", min, max),
+        None             => println!("Aggregate (range):    none")
+    }
+
+    // numbers.Where(n => n > 10).Select(n => n.ToString()).First() - and as with
+    // LINQ, nothing past the first match is looked at. First would throw on an empty
+    // sequence, so Rust's version, find, returns an Option instead
+    match numbers.iter().find(|&&n| n > 10).map(|n| n.to_string()) {
+        Some(first) => println!("First over 10:        {}", first),
+        None        => println!("First over 10:        none")
+    }
+
+    // string.Join(", ", numbers.Skip(1).Take(3))
+    println!("Skip(1).Take(3):      {}", numbers.iter().skip(1).take(3).join(", "));
+    Ok(())
+}
+
+// How many decimal digits `n` is written with
+fn digits(n: u64) -> usize {
+    n.to_string().len()
+}
+
+pub struct LinqDemo;
+
+impl Demo for LinqDemo {
+    fn name(&self) -> &'static str { "linq" }
+
+    fn description(&self) -> &'static str { "LINQ with iterators" }
+
+    fn technique(&self) -> &'static str { "Iterator adaptors and itertools in place of LINQ" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs::File;
+use std::io::{ self, BufRead, BufReader };
+
+use log::{ debug, info, log_enabled, trace, warn, Level };
+
+use crate::{ LineStream, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+/// Adds up the numbers in `read`, skipping any line which isn't one, and logs what
+/// it's doing. Returns the total, as a `u128` which no number of `u64`s can
+/// overflow, and how many lines were skipped.
+///
+/// This is library code, so it only says what happened, through the `log` macros -
+/// an `ILogger<T>` it's been handed, rather than a `LoggerFactory` it set up. It
+/// doesn't decide whether the messages are shown, or where they go: the binary does,
+/// once, in `main`, by installing a logger. A library which did that itself would
+/// fight the binary for the output. Without a logger installed, every macro here is
+/// a cheap no-op.
+pub fn total<R: BufRead>(read: R) -> Result<(u128, usize), ReadError> {
+    let mut total = 0;
+    let mut count = 0;
+    let mut skipped = 0;
+    for (i, line) in LineStream::new(read).enumerate() {
+        let line = line?;
+        // The arguments aren't formatted unless the level is enabled
+        trace!("line {}: {:?}", i + 1, line);
+        match parse_line::<u64>(i + 1, &line) {
+            Ok(n) => {
+                total += u128::from(n);
+                count += 1;
+                debug!("added {}, total now {}", n, total);
+            },
+            Err(e) => {
+                skipped += 1;
+                warn!("skipping {}", e);
+            }
+        }
+    }
+    // Work which is only done for a log message can be skipped too, the way
+    // `ILogger.IsEnabled` would be checked
+    if log_enabled!(Level::Debug) && count > 0 {
+        debug!("mean {:.2}", total as f64 / count as f64);
+    }
+    info!("totalled {} numbers, skipping {}", count, skipped);
+    Ok((total, skipped))
+}
+
+// logging: `logging [file]`. Nothing is logged unless RUST_LOG says so:
+// `RUST_LOG=debug demo run logging numbers.txt`
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let result = match args.get(1) {
+        Some(file_name) => File::open(file_name)
+            .map_err(ReadError::from)
+            .and_then(|file| total(BufReader::new(file)))
+            .map_err(|e| e.in_file(file_name)),
+        None => total(io::stdin().lock())
+    };
+    let (total, skipped) = result?;
+    println!("Total {}, {} lines skipped", total, skipped);
+    Ok(())
+}
+
+pub struct LoggingDemo;
+
+impl Demo for LoggingDemo {
+    fn name(&self) -> &'static str { "logging" }
+
+    fn description(&self) -> &'static str { "Logging from a library" }
+
+    fn technique(&self) -> &'static str { "the log facade and env_logger in place of ILogger and Serilog" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
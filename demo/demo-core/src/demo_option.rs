@@ -0,0 +1,68 @@
+use crate::read_input_numbers;
+use crate::registry::{ Demo, DemoError };
+
+// Finds the first even number in the input, then does what C# would do with `?.`,
+// `??` and a null check - with Option, where a value which might be missing says so
+// in its type. Rust has no null: a u64 is always a number, and Option<u64> is the
+// only way to write "a number, or nothing". That's Nullable<T> for every type, where
+// C#'s nullable reference types are annotations the compiler only warns about
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let numbers = read_input_numbers(args.get(1).map(|s| &s[..]))?;
+
+    // numbers.FirstOrDefault(n => n % 2 == 0) returns 0 when there isn't one, which
+    // is also an even number. find can't be misread that way
+    let first_even: Option<u64> = numbers.iter().cloned().find(|n| n % 2 == 0);
+    println!("find:      {:?}", first_even);
+
+    // firstEven?.Half() - map runs the closure on the value if there is one, and
+    // passes None straight through
+    println!("map:       {:?}", first_even.map(|n| n / 2));
+
+    // and_then is for a closure which might not have an answer itself, so it returns
+    // an Option too. map would give us an Option<Option<u64>>; and_then flattens it.
+    // checked_div is None for division by zero, rather than a DivideByZeroException
+    println!("and_then:  {:?}", first_even.and_then(|n| 1000u64.checked_div(n)));
+
+    // firstEven ?? 0. For a default which takes work to make, unwrap_or_else takes
+    // a closure, and only runs it when it's needed
+    println!("unwrap_or: {}", first_even.unwrap_or(0));
+
+    // ok_or turns a missing value into an error, ready for `?` - the same as
+    // `firstEven ?? throw new InvalidOperationException(...)`
+    let found: Result<u64, &str> = first_even.ok_or("there are no even numbers");
+    println!("ok_or:     {:?}", found);
+
+    // `?` works on Options too, in a function which returns one
+    println!("?:         {:?}", first_two_evens(&numbers));
+
+    // Or match on it, which must cover both cases. `if let` handles just the one
+    match first_even {
+        Some(n) => println!("match:     the first even number is {}", n),
+        None    => println!("match:     there are no even numbers")
+    }
+    Ok(())
+}
+
+// The first two even numbers, if there are two. Each `?` returns None from the whole
+// function if its Option is None - like `?.`, except that it stops the function
+// rather than just the expression
+fn first_two_evens(numbers: &[u64]) -> Option<(u64, u64)> {
+    let mut evens = numbers.iter().filter(|&&n| n % 2 == 0);
+    let first = evens.next()?;
+    let second = evens.next()?;
+    Some((*first, *second))
+}
+
+pub struct OptionDemo;
+
+impl Demo for OptionDemo {
+    fn name(&self) -> &'static str { "option" }
+
+    fn description(&self) -> &'static str { "Values which might be missing" }
+
+    fn technique(&self) -> &'static str { "Option<T> in place of null, ?. and ??" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+// Passes the input's lines from function to function the way C# code passes a
+// List<string> around - first the ways which don't compile, then the ways which do.
+//
+// In C# a List<string> variable holds a reference, and passing it copies the
+// reference: caller and callee share one list, and the garbage collector frees it
+// once nobody refers to it. A Vec<String> is owned by exactly one variable at a time.
+// Passing it by value *moves* it - the callee owns it now, and frees it when it's
+// done - so the caller can't use it any more. There's no garbage collector to need.
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    // Mistake 1: use after move. Perfectly good C#:
+    //
+    //   var count = CountNonBlank(lines);
+    //   var total = Sum(lines);
+    //
+    // let count = count_non_blank(lines);
+    // let total = sum(&lines);
+    //                 ^^^^^^ error[E0382]: borrow of moved value: `lines`
+    //
+    // count_non_blank took `lines` by value, so it was freed when count_non_blank
+    // returned. If this compiled, sum would read freed memory.
+
+    // Fix 1: clone. Both functions get a Vec of their own - a deep copy of every
+    // string, which is honest about the cost C#'s shared reference hides
+    let count = count_non_blank(lines.clone());
+    println!("clone:  {} non-blank lines", count);
+
+    // Fix 2: borrow. Most functions only need to look, so they should take a
+    // reference - `&[String]` - and the caller keeps ownership. This is the fix
+    // nearly every time
+    let count = count_non_blank_borrowed(&lines);
+    let total = sum(&lines)?;
+    println!("borrow: {} non-blank lines, totalling {}", count, total);
+
+    // Mistake 2: a double free, as it might be written in C# with two owners:
+    //
+    //   var first = lines;
+    //   var second = lines;       // two references to one list - fine in C#
+    //
+    // let first = lines;
+    // let second = lines;
+    //             ^^^^^ error[E0382]: use of moved value: `lines`
+    //
+    // Two owners would both free the Vec when they went out of scope - a double free
+    // in C or C++. Rust lets a value have one owner, so the second assignment is the
+    // same use after move as before. (Sharing ownership is what Rc and Arc are for.)
+
+    // Mistake 3: hanging on to a borrow of something that's been given away:
+    //
+    //   let first = &lines[0];
+    //   let trimmed = trim_all(lines);
+    //                          ^^^^^ error[E0505]: cannot move out of `lines` because it is borrowed
+    //   println!("{}", first);
+    //
+    // trim_all might free or change the strings `first` points into. In C# `first`
+    // would keep its string alive; here the borrow checker stops the move instead.
+
+    // Fix 3: copy what's needed before giving the Vec away - and have the function
+    // which takes it give it back. A function which changes what it's given can take
+    // it by value and return it, or take `&mut` and leave ownership where it is, as
+    // drop_blank does
+    let first = lines.first().cloned();
+    let mut lines = trim_all(lines);
+    drop_blank(&mut lines);
+    println!("return: {} lines, the first was {:?}", lines.len(), first);
+    Ok(())
+}
+
+// Takes ownership of the lines. They're dropped - freed - at the end of this function
+fn count_non_blank(lines: Vec<String>) -> usize {
+    lines.iter().filter(|line| !line.trim().is_empty()).count()
+}
+
+// Only borrows them, so the caller still has them afterwards
+fn count_non_blank_borrowed(lines: &[String]) -> usize {
+    lines.iter().filter(|line| !line.trim().is_empty()).count()
+}
+
+fn sum(lines: &[String]) -> Result<u128, DemoError> {
+    let mut total = 0;
+    for (i, line) in lines.iter().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let n: u64 = parse_line(i + 1, line).map_err(ReadError::from)?;
+        total += u128::from(n);
+    }
+    Ok(total)
+}
+
+// Takes ownership of the lines, and hands them back trimmed
+fn trim_all(lines: Vec<String>) -> Vec<String> {
+    lines.into_iter().map(|line| line.trim().to_string()).collect()
+}
+
+// Borrows the lines mutably instead: only one `&mut` can exist at a time, and
+// nothing else can read them while it does
+fn drop_blank(lines: &mut Vec<String>) {
+    lines.retain(|line| !line.is_empty());
+}
+
+pub struct OwnershipDemo;
+
+impl Demo for OwnershipDemo {
+    fn name(&self) -> &'static str { "ownership" }
+
+    fn description(&self) -> &'static str { "Ownership and moves" }
+
+    fn technique(&self) -> &'static str { "moves, clones and borrows in place of shared references" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
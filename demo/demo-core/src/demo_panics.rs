@@ -0,0 +1,136 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::panic::{ self, UnwindSafe };
+use std::thread;
+
+use crate::diagnostics;
+use crate::registry::{ Demo, DemoError };
+
+/// A payload for `panic_any`, which can be any value at all - here, the bad line
+/// a parser gave up on
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadInput {
+    pub line: usize,
+    pub text: String
+}
+
+/// What a panic said, from its payload.
+///
+/// `panic!("...")` panics with a `&'static str`, or a `String` when the message is
+/// formatted, and `panic_any` with whatever it's given - so the payload is a
+/// `dyn Any`, and has to be downcast to find out which. It's the `object` in a
+/// `catch (Exception)` with nothing known about it.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else if let Some(bad) = payload.downcast_ref::<BadInput>() {
+        format!("bad input on line {}: '{}'", bad.line, bad.text)
+    } else {
+        "a panic with a payload of an unknown type".to_string()
+    }
+}
+
+/// Runs `f`, catching a panic and returning its message.
+///
+/// This looks like `try`/`catch`, but isn't meant to be used like one. A panic
+/// says there's a bug - an index past the end, an `unwrap` of something which
+/// should never have been `None` - and the usual thing is to let it end the
+/// thread. `catch_unwind` is for the edges, where one crash shouldn't take the
+/// rest down with it: a request handler in a server, a plugin, a call from C.
+/// It can't catch anything if the program's built with `panic = "abort"`.
+///
+/// `UnwindSafe` stops `f` capturing anything a panic could leave half changed,
+/// unless it's wrapped in `AssertUnwindSafe` to say it's been thought about.
+pub fn catch<T, F: FnOnce() -> T + UnwindSafe>(f: F) -> Result<T, String> {
+    panic::catch_unwind(f).map_err(|payload| panic_message(&*payload))
+}
+
+/// Runs `f` on a thread of its own, returning its message if it panics.
+///
+/// A panic only unwinds the thread it happened on, and `join` hands the payload
+/// over as an `Err` - which is where the other threaded demos call
+/// `resume_unwind`, passing it on as awaiting a faulted Task rethrows.
+pub fn on_thread<T: Send + 'static, F: FnOnce() -> T + Send + 'static>(f: F) -> Result<T, String> {
+    thread::spawn(f).join().map_err(|payload| panic_message(&*payload))
+}
+
+/// Replaces the message printed when a thread panics with a friendlier one.
+///
+/// The hook runs when the panic happens, before any unwinding, wherever the
+/// panic is going to be caught - much like `AppDomain.FirstChanceException`. The
+/// default hook prints the message and where it came from; this one prints the
+/// same as one of our own errors, and says what to do about it.
+pub fn install_hook() {
+    panic::set_hook(Box::new(|info| {
+        let thread = thread::current();
+        let name = thread.name().unwrap_or("unnamed");
+        diagnostics::error(format_args!("the '{}' thread crashed: {}", name, panic_message(info.payload())));
+        if let Some(location) = info.location() {
+            diagnostics::detail(format_args!("at {}:{}", location.file(), location.line()));
+        }
+        diagnostics::detail("This is a bug in the demos - please report it");
+    }));
+}
+
+fn show<T: Debug>(what: &str, result: Result<T, String>) {
+    match result {
+        Ok(value)   => println!("{:<28} returned {:?}", what, value),
+        Err(message) => println!("{:<28} panicked: {}", what, message)
+    }
+}
+
+// panics: `panics [uncaught]`. Each panic is caught and its message printed, after
+// the hook has reported it on stderr. `uncaught` panics without catching, to show
+// the hook and the exit code
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let uncaught = match args.get(1).map(|a| &a[..]) {
+        None             => false,
+        Some("uncaught") => true,
+        Some(other)      => return Err(DemoError::Usage(format!("Unknown argument {}; expected uncaught", other)))
+    };
+
+    // Something which can fail in the normal run of things is a Result, and the
+    // caller decides what to do - no unwinding, no hook
+    println!("{:<28} returned {:?}", "\"two\".parse::<u64>()", "two".parse::<u64>());
+    let numbers: Vec<u64> = (1..=3).collect();
+    println!("{:<28} returned {:?}", "numbers.get(10)", numbers.get(10));
+
+    let previous = panic::take_hook();
+    install_hook();
+    if uncaught {
+        // Nothing catches this, so it unwinds out of main and the process exits
+        // with 101, as an unhandled exception ends a .NET process
+        panic!("nothing catches this");
+    }
+
+    // Indexing past the end is a bug rather than bad input, so it panics, as
+    // IndexOutOfRangeException would be thrown
+    show("numbers[10]", catch(move || numbers[10]));
+    // unwrap says "this can't fail" - when it does, it panics with the error
+    show("\"two\".parse().unwrap()", catch(|| "two".parse::<u64>().unwrap()));
+    // A panic can carry any value, like throwing a custom exception
+    show("panic_any(BadInput)", catch(|| -> u64 {
+        panic::panic_any(BadInput { line: 2, text: "two".to_string() })
+    }));
+    show("panic! on a thread", on_thread(|| -> u64 { panic!("gave up on thread") }));
+
+    // The hook is global to the process, so the one the runner had is put back
+    panic::set_hook(previous);
+    Ok(())
+}
+
+pub struct PanicsDemo;
+
+impl Demo for PanicsDemo {
+    fn name(&self) -> &'static str { "panics" }
+
+    fn description(&self) -> &'static str { "Panics and where they stop" }
+
+    fn technique(&self) -> &'static str { "panic, catch_unwind and a panic hook in place of exceptions" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
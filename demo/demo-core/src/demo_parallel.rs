@@ -0,0 +1,63 @@
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// Parses the input twice, once on one thread and once on all of them, and times both
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    let start = Instant::now();
+    let sequential = parse_sequential(&lines)?;
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel = parse_parallel(&lines)?;
+    let parallel_time = start.elapsed();
+
+    // Collecting a parallel iterator still keeps the values in their original order
+    assert_eq!(sequential, parallel);
+    println!("Parsed {}", Stats::of(&parallel));
+    println!("Sequential: {:?}", sequential_time);
+    println!("Parallel:   {:?} on {} threads", parallel_time, rayon::current_num_threads());
+    Ok(())
+}
+
+/// Parses every line on the current thread
+pub fn parse_sequential(lines: &[String]) -> Result<Vec<u64>, ReadError> {
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map_err(ReadError::from))
+        .collect()
+}
+
+/// Parses the lines spread across rayon's thread pool.
+///
+/// The only change from `parse_sequential` is `par_iter` for `iter` - the Rust version
+/// of adding `.AsParallel()` to a LINQ query. Collecting into a `Result` works just
+/// the same, stopping at an error, but if several lines are bad, which one is
+/// reported depends on which thread got there first.
+pub fn parse_parallel(lines: &[String]) -> Result<Vec<u64>, ReadError> {
+    lines.par_iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map_err(ReadError::from))
+        .collect()
+}
+
+pub struct ParallelDemo;
+
+impl Demo for ParallelDemo {
+    fn name(&self) -> &'static str { "parallel" }
+
+    fn description(&self) -> &'static str { "Data parallelism with rayon" }
+
+    fn technique(&self) -> &'static str { "par_iter, collecting into a Result" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+
+/// The value of a setting, by the shape of its text
+#[derive(Debug, PartialEq, Eq)]
+pub enum Value<'a> {
+    Number(u64),
+    Flag(bool),
+    /// Several values separated by commas
+    List(Vec<&'a str>),
+    Text(&'a str)
+}
+
+/// One `key=value` line
+#[derive(Debug, PartialEq, Eq)]
+pub struct Setting<'a> {
+    pub line: usize,
+    pub key: &'a str,
+    pub value: Value<'a>
+}
+
+/// Splits a `key=value` line into a setting, or `None` if it isn't one
+pub fn parse_setting(line: usize, text: &str) -> Option<Setting<'_>> {
+    // split_once gives back a tuple, which the pattern takes apart - in C# terms
+    // `if (text.SplitOnce('=') is (var key, var value))`. A guard then rules out `=5`
+    match text.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Some(Setting { line, key: key.trim(), value: parse_value(value.trim()) }),
+        _ => None
+    }
+}
+
+// Matching a string against literals. `|` is an or-pattern, which C# writes `or`,
+// and `_ if ...` is a guard on the catch-all arm
+fn parse_value(text: &str) -> Value<'_> {
+    match text {
+        "true" | "yes" | "on"   => Value::Flag(true),
+        "false" | "no" | "off"  => Value::Flag(false),
+        _ if text.contains(',') => Value::List(text.split(',').map(str::trim).collect()),
+        _ => match text.parse() {
+            Ok(n)  => Value::Number(n),
+            Err(_) => Value::Text(text)
+        }
+    }
+}
+
+// Reads key=value settings, skipping blank lines and comments, and describes each one
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    // A tuple pattern in the for loop, and another in each arm of the match
+    for (i, text) in lines.iter().enumerate() {
+        match (i + 1, text.trim()) {
+            (_, "") => {},
+            (_, text) if text.starts_with('#') => {},
+            (line, text) => match parse_setting(line, text) {
+                Some(setting) => println!("{}", describe(&setting)),
+                None          => println!("line {}: not a key=value setting: {}", line, text)
+            }
+        }
+    }
+    Ok(())
+}
+
+// One match, with the patterns nested to say exactly which settings each arm is for.
+// Arms are tried in order, so the specific ones come first - the same as a C# switch
+// expression with property patterns, `{ Key: "port", Value: Number { N: var port } }`
+pub fn describe(setting: &Setting) -> String {
+    // A struct pattern in a let takes out the fields we want; `..` skips the rest
+    let Setting { line, key, .. } = setting;
+    let description = match setting {
+        // A particular key, with a particular kind of value inside. `port @ 1..=65535`
+        // binds the number to `port`, but only matches if it's in the range - C#'s
+        // `Number { N: >= 1 and <= 65535 } port`
+        Setting { key: "port", value: Value::Number(port @ 1..=65535), .. } => format!("port {}", port),
+        Setting { key: "port", value, .. } => format!("not a valid port: {:?}", value),
+        // An or-pattern inside a struct pattern: either key will do
+        Setting { key: "host" | "hostname", value: Value::Text(host), .. } => format!("host {}", host),
+        Setting { value: Value::Number(n @ 0..=9), .. } => format!("{} is the digit {}", key, n),
+        Setting { value: Value::Number(n), .. } => format!("{} is the number {}", key, n),
+        Setting { value: Value::Flag(true), .. } => format!("{} is on", key),
+        Setting { value: Value::Flag(false), .. } => format!("{} is off", key),
+        // Slice patterns pick apart a list by its length. `..` is any number of items
+        // in between, and `rest @ ..` binds them - there's no C# list pattern for that
+        Setting { value: Value::List(items), .. } => match items[..] {
+            [only] => format!("{} is a list of one: {}", key, only),
+            [first, second] => format!("{} is a pair: {} and {}", key, first, second),
+            [first, ref rest @ .., last] => format!("{} is a list: {} to {}, with {} in between", key, first, last, rest.len()),
+            [] => unreachable!("splitting on commas always gives at least one item")
+        },
+        // A guard can look at anything, not just the value being matched
+        Setting { value: Value::Text(_), .. } if key.starts_with('_') => format!("{} is private", key),
+        Setting { value: Value::Text(text), .. } => format!("{} is \"{}\"", key, text)
+    };
+    format!("line {}: {}", line, description)
+}
+
+pub struct PatternsDemo;
+
+impl Demo for PatternsDemo {
+    fn name(&self) -> &'static str { "patterns" }
+
+    fn description(&self) -> &'static str { "Pattern matching on settings" }
+
+    fn technique(&self) -> &'static str { "destructuring, @ bindings, or-patterns and guards" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
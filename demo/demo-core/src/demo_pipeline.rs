@@ -0,0 +1,116 @@
+use std::panic;
+use std::sync::mpsc::{ self, Receiver, Sender, SyncSender };
+use std::thread::{ self, JoinHandle };
+
+use crate::{ open_input, LineStream, ReadError };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// How many threads parse lines at once
+const WORKERS: usize = 4;
+// How many lines each worker can have waiting before the reader has to wait for it
+const QUEUE_LENGTH: usize = 16;
+
+// A reader thread streams lines to a pool of parser threads, which send the numbers
+// on to us. Each stage talks to the next over a channel - a BlockingCollection in C#,
+// or a Channel<T> - and nothing is shared between threads except what's sent
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).cloned();
+
+    // Every worker sends its results down the same channel: mpsc stands for multiple
+    // producer, single consumer, and each producer has its own clone of the Sender
+    let (results, received) = mpsc::channel();
+    let mut queues = Vec::new();
+    let mut workers = Vec::new();
+    for _ in 0..WORKERS {
+        // sync_channel is bounded, like BlockingCollection with a boundedCapacity,
+        // so a slow worker holds the reader up rather than letting lines pile up
+        let (queue, lines) = mpsc::sync_channel(QUEUE_LENGTH);
+        queues.push(queue);
+        workers.push(spawn_worker(lines, results.clone()));
+    }
+    // Our own Sender has to go, or the loop below would wait forever for it
+    drop(results);
+
+    let reader = spawn_reader(file_name.clone(), queues);
+
+    // Receivers are iterators, which end once every Sender has been dropped: the
+    // reader drops the queues when it's read everything, which ends each worker's
+    // loop, which drops their Senders, which ends ours. No CompleteAdding needed
+    let mut stats = Stats::default();
+    let mut failed = None;
+    for result in received.iter() {
+        match result {
+            Ok(n) => stats = stats.with(n),
+            Err(e) => {
+                failed = Some(e);
+                break;
+            }
+        }
+    }
+    // Shutdown works backwards too. Once our Receiver is dropped the workers' sends
+    // fail, so they stop, and then so does the reader
+    drop(received);
+
+    let read = join(reader);
+    for worker in workers {
+        join(worker);
+    }
+    // A line which didn't parse is what stopped the reader, if anything did
+    let outcome = match failed {
+        Some(e) => Err(ReadError::from(e)),
+        None    => read
+    };
+    if let Err(e) = outcome {
+        return Err(e.in_input(file_name).into());
+    }
+    println!("Read on {} workers: {}", WORKERS, stats);
+    Ok(())
+}
+
+// Reads the input a line at a time, dealing the lines out to each worker in turn
+fn spawn_reader(file_name: Option<String>, queues: Vec<SyncSender<(usize, String)>>) -> JoinHandle<Result<(), ReadError>> {
+    thread::spawn(move || {
+        let input = open_input(file_name.as_deref())?;
+        for (i, line) in LineStream::new(input).enumerate() {
+            // A send only fails once the other end has gone, so there's no one left to read for
+            if queues[i % queues.len()].send((i + 1, line?)).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn spawn_worker(lines: Receiver<(usize, String)>, results: Sender<Result<u64, LineError>>) -> JoinHandle<()> {
+    thread::spawn(move || {
+        for (line, text) in lines.iter() {
+            if results.send(parse_line(line, &text)).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+// Waits for a thread to finish, passing on its panic if it had one
+fn join<T>(handle: JoinHandle<T>) -> T {
+    match handle.join() {
+        Ok(result) => result,
+        Err(panic) => panic::resume_unwind(panic)
+    }
+}
+
+pub struct PipelineDemo;
+
+impl Demo for PipelineDemo {
+    fn name(&self) -> &'static str { "pipeline" }
+
+    fn description(&self) -> &'static str { "A producer/consumer pipeline" }
+
+    fn technique(&self) -> &'static str { "mpsc channels between threads" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
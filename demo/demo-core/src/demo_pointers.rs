@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+/// An arithmetic expression, as a tree.
+///
+/// An enum is stored inline, at the size of its biggest variant - so a variant can't
+/// hold another `Expr` directly, or the size would be infinite. `Box` puts the inner
+/// expressions on the heap and holds a pointer to each, which has a fixed size. In C#
+/// every class is already behind a reference like this, so the problem never comes up.
+#[derive(Debug)]
+pub enum Expr {
+    Num(u64),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>)
+}
+
+impl Expr {
+    /// The expression's value, or None if it's too big for a u64
+    pub fn eval(&self) -> Option<u64> {
+        // `a` is a &Box<Expr>, but calling eval on it works because Box derefs to Expr
+        match self {
+            Expr::Num(n)    => Some(*n),
+            Expr::Add(a, b) => a.eval()?.checked_add(b.eval()?),
+            Expr::Mul(a, b) => a.eval()?.checked_mul(b.eval()?)
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Num(n)    => write!(f, "{}", n),
+            Expr::Add(a, b) => write!(f, "{} + {}", a, b),
+            // Only a sum needs brackets inside a product
+            Expr::Mul(a, b) => write!(f, "{} * {}", Bracketed(a), Bracketed(b))
+        }
+    }
+}
+
+struct Bracketed<'a>(&'a Expr);
+
+impl fmt::Display for Bracketed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Expr::Add(..) => write!(f, "({})", self.0),
+            expr          => write!(f, "{}", expr)
+        }
+    }
+}
+
+/// Multiplies the numbers in pairs and adds up the products: 1 * 2 + 3 * 4 + 5
+pub fn sum_of_products(numbers: &[u64]) -> Option<Expr> {
+    numbers.chunks(2)
+        .map(|pair| match *pair {
+            [a, b] => Expr::Mul(Box::new(Expr::Num(a)), Box::new(Expr::Num(b))),
+            [a]    => Expr::Num(a),
+            _      => unreachable!("chunks(2) gives one or two at a time")
+        })
+        .reduce(|sum, product| Expr::Add(Box::new(sum), Box::new(product)))
+}
+
+/// Tidies a line for parsing: trims it, and takes out any `_` digit separators.
+///
+/// Trimming only needs a slice of the line, but taking out separators needs a new
+/// String. `Cow` - clone on write - holds either: `Borrowed` when the line could be
+/// used as it was, `Owned` when it had to be copied. C#'s `Trim` does something
+/// similar, returning the same string instance when there's nothing to trim; Cow lets
+/// any function do that, and the caller can tell which happened.
+pub fn tidy(line: &str) -> Cow<'_, str> {
+    let line = line.trim();
+    if line.contains('_') {
+        Cow::Owned(line.replace('_', ""))
+    } else {
+        Cow::Borrowed(line)
+    }
+}
+
+/// A line of input which knows where it came from. Implementing `Deref` makes it
+/// usable wherever a `&str` is expected, as if it were one.
+pub struct Line {
+    pub number: usize,
+    text: String
+}
+
+impl Deref for Line {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+// Builds an expression tree from the input, tidying each line on the way in
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    let lines: Vec<Line> = lines.into_iter().enumerate().map(|(i, text)| Line { number: i + 1, text }).collect();
+
+    let mut numbers = Vec::new();
+    let mut copied = 0;
+    for line in &lines {
+        // Deref coercion: &Line isn't a &str, but the compiler inserts the deref call
+        // to make it one. It's how &String becomes &str and &Vec<u64> becomes &[u64]
+        // without anyone asking - the nearest C# comes is an implicit conversion
+        // operator, and those create a new value rather than looking inside
+        let tidied = tidy(line);
+        if let Cow::Owned(_) = tidied {
+            copied += 1;
+        }
+        // And a Cow<str> derefs to &str, whichever kind it is
+        let n: u64 = parse_line(line.number, &tidied).map_err(|e| ReadError::from(e).in_input(file_name))?;
+        numbers.push(n);
+    }
+    println!("Tidied {} lines, copying {} of them", lines.len(), copied);
+
+    match sum_of_products(&numbers) {
+        Some(expr) => {
+            let value = expr.eval().ok_or_else(|| DemoError::Overflow(format!("{} is too big for a u64", expr)))?;
+            println!("{} = {}", expr, value);
+        },
+        None => println!("No numbers, so no expression")
+    }
+    Ok(())
+}
+
+pub struct PointersDemo;
+
+impl Demo for PointersDemo {
+    fn name(&self) -> &'static str { "pointers" }
+
+    fn description(&self) -> &'static str { "Box, Cow and Deref" }
+
+    fn technique(&self) -> &'static str { "smart pointers in place of references to objects" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
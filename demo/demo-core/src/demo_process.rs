@@ -0,0 +1,78 @@
+use crate::{ process_file, ReadError };
+use crate::literal::Literal;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+use crate::str_ext::StrExt;
+
+/// Accepts lines with something on them.
+///
+/// `impl Fn` in return position means "some closure type, which I'm not naming".
+/// Closure types have no names, so without it the only option would be a
+/// `Box<dyn Fn>` - a heap allocation and a virtual call, like a C# `Func<>`. The
+/// caller can't see the concrete type, but the compiler can, and still inlines it.
+pub fn not_blank() -> impl Fn(&str) -> bool {
+    |line| !line.is_blank()
+}
+
+/// Accepts lines which aren't comments, which start with `#` or `//`
+pub fn not_comment() -> impl Fn(&str) -> bool {
+    |line| !line.is_comment()
+}
+
+/// Accepts lines which both `a` and `b` accept.
+///
+/// `impl Fn` in argument position is a generic parameter without a name, so this
+/// takes any two closures, and returns a third which owns them both.
+pub fn both(a: impl Fn(&str) -> bool, b: impl Fn(&str) -> bool) -> impl Fn(&str) -> bool {
+    move |line| a(line) && b(line)
+}
+
+// The filter to use, chosen at run time. The two branches are different closure
+// types, and `impl Fn` has to stand for exactly one type, so this is where a Box<dyn
+// Fn> is needed after all
+fn line_filter(keep_comments: bool) -> Box<dyn Fn(&str) -> bool> {
+    if keep_comments {
+        Box::new(not_blank())
+    } else {
+        Box::new(both(not_blank(), not_comment()))
+    }
+}
+
+// Runs the file through three pipelines, each put together from small closures
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let keep_comments = args.get(1).map(|a| &a[..]) == Some("--keep-comments");
+    let file_name = match args.get(if keep_comments { 2 } else { 1 }) {
+        Some(file_name) => file_name,
+        None => return Err(DemoError::Usage("Expected a file to process".to_string()))
+    };
+    let in_file = |e: ReadError| e.in_file(file_name);
+
+    // A Box<dyn Fn> is an Fn too, so it can go wherever an impl Fn can
+    let lines: Vec<String> = process_file(file_name, line_filter(keep_comments), |line| line.trim().to_string()).map_err(in_file)?;
+    println!("lines:   {:?}", lines);
+
+    // Functions and methods can stand in for closures, when they have the right shape
+    let lengths: Vec<usize> = process_file(file_name, not_blank(), str::len).map_err(in_file)?;
+    println!("lengths: {:?}", lengths);
+
+    // The map can produce anything, including a Result for each line
+    let parsed = process_file(file_name, both(not_blank(), not_comment()), |line| line.trim().parse::<Literal>()).map_err(in_file)?;
+    let numbers: Vec<u64> = parsed.iter().flatten().map(|literal| literal.0).collect();
+    let failed = parsed.iter().filter(|result| result.is_err()).count();
+    println!("numbers: {}, and {} which didn't parse", Stats::of(&numbers), failed);
+    Ok(())
+}
+
+pub struct ProcessDemo;
+
+impl Demo for ProcessDemo {
+    fn name(&self) -> &'static str { "process" }
+
+    fn description(&self) -> &'static str { "Pipelines built from closures" }
+
+    fn technique(&self) -> &'static str { "impl Fn arguments and return types in place of Func" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
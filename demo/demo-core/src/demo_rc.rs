@@ -0,0 +1,127 @@
+use std::rc::{ Rc, Weak };
+use std::sync::Arc;
+use std::thread;
+
+use crate::read_input_numbers;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+/// Every number in the input - the big thing which everything else shares
+#[derive(Debug)]
+pub struct Dataset {
+    pub numbers: Vec<u64>
+}
+
+/// One of the components sharing the dataset.
+///
+/// Holding an `Rc` makes it one of the dataset's owners: the dataset lives until the
+/// last owner is dropped, whichever that turns out to be. That's how every C# object
+/// reference behaves, but here it's opted into, and counted.
+struct Summary {
+    data: Rc<Dataset>
+}
+
+impl Summary {
+    fn describe(&self) -> String {
+        Stats::of(&self.data.numbers).to_string()
+    }
+}
+
+struct Extremes {
+    data: Rc<Dataset>
+}
+
+impl Extremes {
+    fn describe(&self) -> String {
+        let stats = Stats::of(&self.data.numbers);
+        format!("from {:?} to {:?}", stats.min, stats.max)
+    }
+}
+
+/// A component which uses the dataset if it's still there, but doesn't keep it alive.
+///
+/// A `Weak` doesn't count as an owner, so it has to be upgraded to an `Rc` - which
+/// fails once the owners are gone - every time it's used. C#'s `WeakReference<T>`
+/// and `TryGetTarget` are the same idea.
+struct Watcher {
+    data: Weak<Dataset>
+}
+
+impl Watcher {
+    fn describe(&self) -> String {
+        match self.data.upgrade() {
+            Some(data) => format!("still there, with {} numbers", data.numbers.len()),
+            None       => "gone".to_string()
+        }
+    }
+}
+
+// Shares one parsed dataset between components, then between threads, counting owners
+// as they come and go
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let numbers = read_input_numbers(args.get(1).map(|s| &s[..]))?;
+
+    let data = Rc::new(Dataset { numbers });
+    println!("Rc: created, strong count {}", Rc::strong_count(&data));
+    let watcher = Watcher { data: Rc::downgrade(&data) };
+    {
+        // Rc::clone copies a pointer and adds one to the count - the numbers stay put
+        let summary = Summary { data: Rc::clone(&data) };
+        println!("Rc: shared with Summary, strong count {}", Rc::strong_count(&data));
+        let extremes = Extremes { data: Rc::clone(&data) };
+        println!("Rc: shared with Extremes, strong count {}", Rc::strong_count(&data));
+        println!("  Summary:  {}", summary.describe());
+        println!("  Extremes: {}", extremes.describe());
+        // Dropping each component takes one off the count
+    }
+    println!("Rc: components dropped, strong count {}, weak count {}", Rc::strong_count(&data), Rc::weak_count(&data));
+    println!("  Watcher:  {}", watcher.describe());
+
+    // Rc's count isn't updated atomically, so Rc can't be sent to another thread - the
+    // compiler refuses. Arc is the same thing with an atomic count. Moving the numbers
+    // out of the Rc only works because nothing else owns them any more - a Weak
+    // doesn't count, so the Watcher is left with nothing to upgrade to
+    let data = Arc::new(Rc::try_unwrap(data).expect("the components were dropped"));
+    println!("  Watcher:  {}", watcher.describe());
+    share_between_threads(data);
+
+    // Reference counting's blind spot is the cycle. If the dataset held an Rc to a
+    // component holding an Rc back to it, each would keep the other's count above zero
+    // and neither would ever be freed - a leak. A tracing garbage collector like .NET's
+    // finds everything reachable from the roots, so it frees unreachable cycles; Rust
+    // has no collector, so one side of a cycle has to be a Weak, like the Watcher's.
+    // It's the price of freeing everything at a predictable moment, with no GC pauses
+    Ok(())
+}
+
+fn share_between_threads(data: Arc<Dataset>) {
+    println!("Arc: created, strong count {}", Arc::strong_count(&data));
+    // Each thread needs its own Arc to own. They're all cloned before any thread starts,
+    // so the count can be printed before threads start dropping theirs
+    let halves: Vec<(usize, Arc<Dataset>)> = (0..2).map(|half| (half, Arc::clone(&data))).collect();
+    println!("Arc: one for each of {} threads, strong count {}", halves.len(), Arc::strong_count(&data));
+    let handles: Vec<_> = halves.into_iter()
+        .map(|(half, data)| thread::spawn(move || {
+            let middle = data.numbers.len() / 2;
+            let numbers = if half == 0 { &data.numbers[..middle] } else { &data.numbers[middle..] };
+            Stats::of(numbers).sum
+        }))
+        .collect();
+    let sums: Vec<u128> = handles.into_iter().map(|handle| handle.join().expect("summing can't panic")).collect();
+    println!("  Threads:  the halves add up to {:?}", sums);
+    println!("Arc: threads finished, strong count {}", Arc::strong_count(&data));
+}
+
+pub struct RcDemo;
+
+impl Demo for RcDemo {
+    fn name(&self) -> &'static str { "rc" }
+
+    fn description(&self) -> &'static str { "Sharing by reference counting" }
+
+    fn technique(&self) -> &'static str { "Rc, Arc and Weak in place of the garbage collector" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,114 @@
+use std::cell::{ Cell, RefCell };
+use std::collections::HashMap;
+use std::path::{ Path, PathBuf };
+use std::rc::Rc;
+
+use crate::{ read_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+/// Parsed files, by path, shared between everything which reads them.
+///
+/// `Rc` lets several readers own the cache, but only gives them `&` access - and `&`
+/// means read-only. `RefCell` moves the borrowing rules from compile time to run
+/// time: `borrow()` and `borrow_mut()` hand out `&` and `&mut` through a shared
+/// reference, counting as they go, and panic if the rules would be broken.
+pub type SharedCache = Rc<RefCell<HashMap<PathBuf, Rc<Vec<u64>>>>>;
+
+/// Reads files of numbers through a shared cache, counting how often the cache helped
+pub struct Reader {
+    pub name: &'static str,
+    cache: SharedCache,
+    // Cell is RefCell's simpler sibling, for Copy values like counts: get and set,
+    // never a reference to the inside, so nothing to check and nothing to panic
+    hits: Cell<usize>,
+    misses: Cell<usize>
+}
+
+impl Reader {
+    pub fn new(name: &'static str, cache: SharedCache) -> Reader {
+        Reader { name, cache, hits: Cell::new(0), misses: Cell::new(0) }
+    }
+
+    /// The numbers in the file at `path`, from the cache if anyone has read it before.
+    ///
+    /// Only `&self` - the caching and counting happen behind its back, which is what
+    /// *interior mutability* means. To a C# developer this is just a method updating
+    /// a field; it's Rust which needs telling that it's allowed.
+    pub fn load(&self, path: &Path) -> Result<Rc<Vec<u64>>, ReadError> {
+        // The borrow() only lasts for this statement, so it's over before borrow_mut()
+        if let Some(numbers) = self.cache.borrow().get(path) {
+            self.hits.set(self.hits.get() + 1);
+            return Ok(Rc::clone(numbers));
+        }
+        self.misses.set(self.misses.get() + 1);
+        let numbers = Rc::new(read_numbers(path).map_err(|e| e.in_file(path))?);
+        self.cache.borrow_mut().insert(path.to_path_buf(), Rc::clone(&numbers));
+        Ok(numbers)
+    }
+
+    /// How many loads came from the cache, and how many from the file
+    pub fn stats(&self) -> (usize, usize) {
+        (self.hits.get(), self.misses.get())
+    }
+}
+
+fn read_numbers(path: &Path) -> Result<Vec<u64>, ReadError> {
+    let mut numbers = Vec::new();
+    for (i, line) in read_lines(path)?.iter().enumerate() {
+        numbers.push(parse_line(i + 1, line)?);
+    }
+    Ok(numbers)
+}
+
+// Two readers share one cache, so whatever the first reads, the second gets for free
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_names = &args[1..];
+    if file_names.is_empty() {
+        return Err(DemoError::Usage("Expected one or more files to read".to_string()));
+    }
+
+    let cache = SharedCache::default();
+    let readers = [Reader::new("first", Rc::clone(&cache)), Reader::new("second", Rc::clone(&cache))];
+    for reader in &readers {
+        for file_name in file_names {
+            let numbers = reader.load(Path::new(file_name))?;
+            println!("{:<6} read {} numbers from {}", reader.name, numbers.len(), file_name);
+        }
+    }
+    for reader in &readers {
+        let (hits, misses) = reader.stats();
+        println!("{:<6} {} from the cache, {} from the file", reader.name, hits, misses);
+    }
+    println!("{} files cached", cache.borrow().len());
+
+    // The rules haven't gone away, they're just checked later. While one borrow() is
+    // alive, borrow_mut() panics - the C# equivalent of changing a Dictionary while
+    // enumerating it, except that it's caught even when nothing is enumerating. The
+    // try_ versions return an error instead, so this can be shown without the panic
+    let entries = cache.borrow();
+    match cache.try_borrow_mut() {
+        Ok(_)  => println!("borrow_mut while borrowed: allowed"),
+        Err(e) => println!("borrow_mut while borrowed: {}", e)
+    }
+    drop(entries);
+    match cache.try_borrow_mut() {
+        Ok(_)  => println!("borrow_mut once that's dropped: allowed"),
+        Err(e) => println!("borrow_mut once that's dropped: {}", e)
+    }
+    Ok(())
+}
+
+pub struct RefCellDemo;
+
+impl Demo for RefCellDemo {
+    fn name(&self) -> &'static str { "refcell" }
+
+    fn description(&self) -> &'static str { "A shared, changeable cache" }
+
+    fn technique(&self) -> &'static str { "RefCell and Cell for mutation through shared references" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
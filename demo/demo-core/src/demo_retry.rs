@@ -0,0 +1,80 @@
+use std::cell::Cell;
+use std::fs::File;
+use std::io::{ self, BufReader, ErrorKind, Read };
+use std::time::Duration;
+
+use crate::{ read_lines_from, ReadError };
+use crate::diagnostics;
+use crate::registry::{ Demo, DemoError };
+use crate::retry::{ retry, RetryPolicy };
+
+/// A reader which fails while `failures` is above 0, taking one off each time -
+/// a file server which drops the first few connections, then recovers. The count
+/// is shared, so it carries on going down across the readers opened for each try.
+pub struct FlakyReader<'a, R> {
+    inner: R,
+    failures: &'a Cell<u32>
+}
+
+impl<'a, R> FlakyReader<'a, R> {
+    pub fn new(inner: R, failures: &'a Cell<u32>) -> FlakyReader<'a, R> {
+        FlakyReader { inner, failures }
+    }
+}
+
+impl<'a, R: Read> Read for FlakyReader<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.failures.get() {
+            0 => self.inner.read(buf),
+            n => {
+                self.failures.set(n - 1);
+                Err(io::Error::new(ErrorKind::ConnectionReset, "connection reset by the file server"))
+            }
+        }
+    }
+}
+
+// retry: `retry <file> [failures]`. Reads the file through a reader which fails
+// the first `failures` times (2 by default), trying up to 3 times in all
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).ok_or_else(|| DemoError::Usage("Expected a file to read".to_string()))?;
+    let failures = match args.get(2) {
+        Some(n) => n.parse().map_err(|e| DemoError::Usage(format!("Could not parse failures: {}", e)))?,
+        None => 2
+    };
+    let failures = Cell::new(failures);
+    let policy = RetryPolicy::new().attempts(3).delay(Duration::from_millis(50));
+
+    let mut attempt = 0;
+    // Each try opens the file again: whatever was read before the failure is gone
+    let result = retry(|| {
+        attempt += 1;
+        let file = File::open(file_name)?;
+        let result = read_lines_from(BufReader::new(FlakyReader::new(file, &failures)));
+        match result {
+            Ok(ref lines)             => println!("Attempt {}: read {} lines", attempt, lines.len()),
+            // The io::Error says more than "could not read file"
+            Err(ReadError::Io(ref e)) => diagnostics::warning(format!("Attempt {}: {}", attempt, e)),
+            Err(ref e)                => diagnostics::warning(format!("Attempt {}: {}", attempt, e))
+        }
+        result
+    }, &policy);
+
+    let lines = result.map_err(|e| e.in_file(file_name))?;
+    println!("Read {} lines in {} attempts", lines.len(), attempt);
+    Ok(())
+}
+
+pub struct RetryDemo;
+
+impl Demo for RetryDemo {
+    fn name(&self) -> &'static str { "retry" }
+
+    fn description(&self) -> &'static str { "Retrying a flaky read" }
+
+    fn technique(&self) -> &'static str { "a retry function with exponential backoff in place of Polly" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,82 @@
+use std::panic;
+use std::thread;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// How many threads share the lines
+const WORKERS: usize = 4;
+
+// Sums up the numbers in the input, with the lines split between several threads
+// which borrow them straight from our stack
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    let stats = stats_scoped(&lines).map_err(|e| e.in_input(file_name))?;
+    println!("Read on {} threads: {}", WORKERS, stats);
+    Ok(())
+}
+
+/// The statistics of the numbers in `lines`, giving each of several threads a slice
+/// of them and merging what they find.
+///
+/// Unlike the threads in `demo_atomics`, these borrow the lines rather than sharing
+/// a copy of them in an `Arc`.
+pub fn stats_scoped(lines: &[String]) -> Result<Stats, ReadError> {
+    // Rounding up, so no line is left over. chunks panics on 0, hence the max
+    let chunk_len = lines.len().div_ceil(WORKERS).max(1);
+
+    // thread::spawn insists on 'static because nothing stops the function which
+    // spawned a thread returning first, taking anything the thread borrowed with it.
+    // thread::scope is the something: every thread spawned on `scope` is joined
+    // before scope returns. That's written into its lifetimes - a borrow the threads
+    // use only has to outlive the scope, and `lines` does.
+    //
+    // C# can't promise that. A lambda handed to Task.Run may run for as long as it
+    // likes, so it can't capture a Span<T>, a ref local or anything else on the
+    // stack; it captures objects on the heap, which the GC keeps alive. Waiting for
+    // every task before returning is good practice, but nothing checks it was done
+    thread::scope(|scope| {
+        let handles: Vec<_> = lines.chunks(chunk_len)
+            .enumerate()
+            .map(|(chunk, slice)| scope.spawn(move || stats_of_chunk(slice, chunk * chunk_len)))
+            .collect();
+
+        // A scope would join the threads itself, but joining them here gets us their
+        // results. A thread which panicked is passed on the same way as ever
+        let mut stats = Stats::default();
+        for handle in handles {
+            match handle.join() {
+                Ok(chunk)  => stats = stats.merge(chunk?),
+                Err(panic) => panic::resume_unwind(panic)
+            }
+        }
+        Ok(stats)
+    })
+}
+
+// Sums up one slice of the lines. `first` is the index of the slice's first line
+// in the whole input, so errors report the right line number
+fn stats_of_chunk(lines: &[String], first: usize) -> Result<Stats, ReadError> {
+    let mut stats = Stats::default();
+    for (i, line) in lines.iter().enumerate() {
+        stats = stats.with(parse_line(first + i + 1, line)?);
+    }
+    Ok(stats)
+}
+
+pub struct ScopedDemo;
+
+impl Demo for ScopedDemo {
+    fn name(&self) -> &'static str { "scoped" }
+
+    fn description(&self) -> &'static str { "Threads which borrow" }
+
+    fn technique(&self) -> &'static str { "thread::scope in place of Arc" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
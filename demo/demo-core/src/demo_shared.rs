@@ -0,0 +1,104 @@
+use std::sync::{ Arc, Mutex };
+use std::thread::{ self, JoinHandle };
+
+use crate::{ read_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// Reads every file on its own thread, like the threads demo, but rather than each
+// thread handing back its own result they all update the same Stats as they go
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    // --panic makes the first thread panic in the middle of an update, to show
+    // what happens to a lock when that happens
+    let panic = args.get(1).map(|a| &a[..]) == Some("--panic");
+    let file_names = &args[if panic { 2 } else { 1 }..];
+    if file_names.is_empty() {
+        return Err(DemoError::Usage("Expected one or more files to read".to_string()));
+    }
+
+    // In C# we'd lock(stats) and trust every thread to remember to. A Mutex<T> owns
+    // the data it protects instead, so there's no way to get at it without the lock.
+    //
+    // And the Arc? thread::spawn needs everything it's given to be 'static, so the
+    // threads can't borrow the Mutex from us - they need to own it. Shared ownership
+    // is what reference counting is for, but Rc's count isn't thread safe, so it's
+    // not Send and the compiler won't let it near a thread. Arc is the atomic version.
+    // In C# the garbage collector quietly does the same job for every object.
+    let stats = Arc::new(Mutex::new(Stats::default()));
+
+    let handles: Vec<JoinHandle<Result<(), ReadError>>> = file_names.iter()
+        .enumerate()
+        .map(|(i, file_name)| {
+            // Cloning an Arc copies the pointer and bumps the count - the Mutex isn't copied
+            let stats = Arc::clone(&stats);
+            let file_name = file_name.clone();
+            let should_panic = panic && i == 0;
+            thread::spawn(move || add_file(&file_name, &stats, should_panic).map_err(|e| e.in_file(&file_name)))
+        })
+        .collect();
+
+    // A thread which panicked is reported here rather than passed on, so we can
+    // see what it did to the stats
+    let mut results = Vec::new();
+    for handle in handles {
+        match handle.join() {
+            Ok(result) => results.push(result),
+            Err(_)     => println!("A thread panicked")
+        }
+    }
+    for result in results {
+        result?;
+    }
+
+    // A thread which panics while holding the lock poisons the Mutex: it might have
+    // been half way through an update, so every lock after that returns Err. C#
+    // releases the lock as the exception leaves the lock block, and carries on
+    // regardless. Here we get to decide - into_inner takes the data anyway
+    match stats.lock() {
+        Ok(stats) => println!("Read {} files: {}", file_names.len(), stats),
+        // Debug, as it shows what's there - a count and a sum with no range to go with them
+        Err(poisoned) => {
+            println!("The stats were poisoned by a panic, and may be half updated:");
+            println!("{:?}", poisoned.into_inner());
+        }
+    }
+    Ok(())
+}
+
+fn add_file(file_name: &str, stats: &Mutex<Stats>, should_panic: bool) -> Result<(), ReadError> {
+    for (i, line) in read_lines(file_name)?.iter().enumerate() {
+        let n = parse_line(i + 1, line)?;
+
+        // lock() waits for the lock like Monitor.Enter, and the guard it returns
+        // unlocks it again when it's dropped at the end of the loop body - our
+        // `lock` block. Locking once per line is slow, but it makes the point
+        let mut stats = match stats.lock() {
+            Ok(stats) => stats,
+            // Someone else panicked mid-update, so these stats can't be trusted - give up
+            Err(_) => return Ok(())
+        };
+        stats.count += 1;
+        stats.sum += u128::from(n);
+        if should_panic {
+            panic!("giving up half way through an update");
+        }
+        stats.min = Some(stats.min.map_or(n, |min| min.min(n)));
+        stats.max = Some(stats.max.map_or(n, |max| max.max(n)));
+    }
+    Ok(())
+}
+
+pub struct SharedDemo;
+
+impl Demo for SharedDemo {
+    fn name(&self) -> &'static str { "shared" }
+
+    fn description(&self) -> &'static str { "Shared state between threads" }
+
+    fn technique(&self) -> &'static str { "Arc<Mutex<T>> in place of lock(obj)" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::read_input_numbers;
+use crate::registry::{ Demo, DemoError };
+
+/// Says what's in a slice, by its shape.
+///
+/// Slice patterns match on the length and the ends at once; `..` stands for
+/// however many are in the middle, including none.
+pub fn describe(numbers: &[u64]) -> String {
+    match numbers {
+        []                => "nothing".to_string(),
+        [only]            => format!("just {}", only),
+        [first, second]   => format!("{} and {}", first, second),
+        [first, .., last] => format!("{} numbers, from {} to {}", numbers.len(), first, last)
+    }
+}
+
+/// The longest run of numbers which only go up, as a slice of `numbers`.
+///
+/// `windows(2)` sees each overlapping pair. The run found is a borrowed sub-slice,
+/// so nothing is copied to return it.
+pub fn longest_rise(numbers: &[u64]) -> &[u64] {
+    let mut best = 0..numbers.len().min(1);
+    let mut start = 0;
+    for (i, pair) in numbers.windows(2).enumerate() {
+        if pair[1] <= pair[0] {
+            start = i + 1;
+        }
+        if i + 2 - start > best.len() {
+            best = start..i + 2;
+        }
+    }
+    &numbers[best]
+}
+
+// Looks at the numbers through slices of them - views which borrow part of the Vec
+// rather than copying it.
+//
+// A &[u64] is a pointer and a length, which is what C#'s Span<T> is too - and like
+// ArraySegment<T>, it can point into a Vec, an array, or part of either. Unlike
+// Span<T>, it isn't restricted to the stack: the borrow checker, not a ref struct
+// rule, keeps it from outliving what it points into
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let numbers = read_input_numbers(args.get(1).map(|s| &s[..]))?;
+
+    // &numbers turns the &Vec<u64> into a &[u64] - a Vec derefs to a slice
+    println!("describe:     {}", describe(&numbers));
+    // Indexing past the end panics, where get returns None - numbers[1..] would, for no numbers
+    println!("tail:         {}", describe(numbers.get(1..).unwrap_or(&[])));
+
+    // windows overlap; chunks don't, and the last chunk is whatever's left over
+    let steps: Vec<i128> = numbers.windows(2).map(|pair| i128::from(pair[1]) - i128::from(pair[0])).collect();
+    println!("windows(2):   steps {:?}", steps);
+    println!("              longest rise {:?}", longest_rise(&numbers));
+    // Summed as u128s, like the steps above are i128s, so three big numbers can't overflow
+    let sums: Vec<u128> = numbers.chunks(3).map(|chunk| chunk.iter().map(|&n| u128::from(n)).sum()).collect();
+    println!("chunks(3):    sums {:?}", sums);
+
+    // split_at gives two slices which don't overlap, at no cost
+    let (front, back) = numbers.split_at(numbers.len() / 2);
+    println!("split_at:     {:?} and {:?}", front, back);
+    if let Some((first, rest)) = numbers.split_first() {
+        println!("split_first:  {} and {} more", first, rest.len());
+    }
+
+    // An array's length is part of its type, and it lives on the stack, like a C#
+    // fixed-size buffer. It borrows as a slice like a Vec does, so describe takes it too
+    let mut sizes = [0u64; 3];
+    for &n in &numbers {
+        sizes[match n { 0..=9 => 0, 10..=99 => 1, _ => 2 }] += 1;
+    }
+    println!("[u64; 3]:     {} units, {} tens, {} bigger - {}", sizes[0], sizes[1], sizes[2], describe(&sizes));
+
+    // A &mut slice can change what it points into, but not how long it is
+    let mut sorted = numbers.clone();
+    let (low, high) = sorted.split_at_mut(numbers.len() / 2);
+    low.sort_unstable();
+    high.sort_unstable();
+    println!("split_at_mut: each half sorted {:?}", sorted);
+    Ok(())
+}
+
+pub struct SlicesDemo;
+
+impl Demo for SlicesDemo {
+    fn name(&self) -> &'static str { "slices" }
+
+    fn description(&self) -> &'static str { "Views into the numbers" }
+
+    fn technique(&self) -> &'static str { "slices in place of Span<T> and ArraySegment<T>" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,90 @@
+use std::cmp::Reverse;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+/// The median of `numbers`, found without sorting them all.
+///
+/// `select_nth_unstable` moves the element which belongs in the middle there, with
+/// nothing bigger before it and nothing smaller after - quickselect, O(n) where a
+/// full sort is O(n log n). It reorders the slice, so it needs a `&mut` one.
+/// For an even count this is the upper of the two middle numbers.
+pub fn median(numbers: &mut [u64]) -> Option<u64> {
+    if numbers.is_empty() {
+        return None;
+    }
+    let middle = numbers.len() / 2;
+    let (_, median, _) = numbers.select_nth_unstable(middle);
+    Some(*median)
+}
+
+// Sorts the numbers several ways, then searches them.
+//
+// List<T>.Sort and Array.Sort are introsorts: fast, in place, and *unstable* - equal
+// elements can come out in any order. Rust's sort is the other kind: stable, so
+// equal elements keep the order they came in, at the cost of a buffer half the
+// length of the slice. sort_unstable is the one which matches C#, and allocates
+// nothing. Both are on slices, so they work on a Vec, an array, or part of either
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    // Each number with the line it was on, to show which of the equal ones is which
+    let records = lines.iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map(|n| (i + 1, n)).map_err(ReadError::from))
+        .collect::<Result<Vec<(usize, u64)>, ReadError>>()
+        .map_err(|e| e.in_input(file_name))?;
+    let show = |records: &[(usize, u64)]| records.iter().map(|(line, n)| format!("{}@{}", n, line)).collect::<Vec<String>>().join(" ");
+    println!("as read:             {}", show(&records));
+
+    // sort uses the element type's Ord, like List<T>.Sort() uses IComparable<T>
+    let mut numbers: Vec<u64> = records.iter().map(|&(_, n)| n).collect();
+    numbers.sort();
+    println!("sort:                {:?}", numbers);
+
+    // Stable: equal numbers stay in line order
+    let mut by_value = records.clone();
+    by_value.sort_by_key(|&(_, n)| n);
+    println!("sort_by_key:         {}", show(&by_value));
+
+    // Reverse flips the comparison, for largest first - C#'s OrderByDescending
+    let mut largest_first = records.clone();
+    largest_first.sort_unstable_by_key(|&(_, n)| Reverse(n));
+    println!("sort_unstable:       {}", show(&largest_first));
+
+    // binary_search needs sorted input, like Array.BinarySearch. Where that returns
+    // the bitwise complement of the insertion point when the value isn't found, this
+    // returns Err(insertion point) - a value which can't be mistaken for an index.
+    // When several elements are equal, it could find any of them
+    let targets = [numbers.first().copied(), numbers.last().and_then(|n| n.checked_add(1))];
+    for target in targets.iter().flatten() {
+        match by_value.binary_search_by(|&(_, n)| n.cmp(target)) {
+            Ok(i)  => println!("binary_search_by:    {} found, on line {}", target, by_value[i].0),
+            Err(i) => println!("binary_search_by:    {} not found, but it would go at {}", target, i)
+        }
+    }
+
+    // partition_point is the binary search for a condition, rather than a value
+    let small = numbers.partition_point(|&n| n < 10);
+    println!("partition_point:     {} numbers are under 10", small);
+
+    let mut unsorted: Vec<u64> = records.iter().map(|&(_, n)| n).collect();
+    let middle = median(&mut unsorted);
+    println!("select_nth_unstable: median {:?}, leaving {:?}", middle, unsorted);
+    Ok(())
+}
+
+pub struct SortingDemo;
+
+impl Demo for SortingDemo {
+    fn name(&self) -> &'static str { "sorting" }
+
+    fn description(&self) -> &'static str { "Sorting and searching" }
+
+    fn technique(&self) -> &'static str { "stable and unstable sorts, and binary_search in place of Array.BinarySearch" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
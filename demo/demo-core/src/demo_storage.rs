@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use crate::{ read_lines_from_storage, ReadError };
+use crate::registry::{ Demo, DemoError };
+use crate::storage::{ FileStorage, MemoryStorage, Storage };
+
+// Reads the input through each kind of Storage, then asks each for something it hasn't
+// got, to show the two fail with two different error types
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = match args.get(1) {
+        Some(file_name) => file_name,
+        None => return Err(DemoError::Usage("Expected a file to read".to_string()))
+    };
+    let path = Path::new(file_name);
+    let key = match path.file_name().and_then(|name| name.to_str()) {
+        Some(key) => key,
+        None => return Err(DemoError::Usage(format!("Not a file name: {}", file_name)))
+    };
+    let files = FileStorage::new(path.parent().unwrap_or(Path::new("")));
+
+    // The same function reads from both: ReadError can be made from an io::Error and
+    // from a MissingKey, which is all its where clause asks
+    let from_file: Vec<String> = read_lines_from_storage(&files, key).map_err(|e| e.in_file(file_name))?;
+    let mut memory = MemoryStorage::new();
+    memory.store(key, files.load(key).map_err(|e| ReadError::from(e).in_file(file_name))?);
+    let from_memory: Vec<String> = read_lines_from_storage(&memory, key)?;
+    if from_file != from_memory {
+        return Err(DemoError::Mismatch("storage in memory read different lines from the file".to_string()));
+    }
+    println!("file:   {} lines", from_file.len());
+    println!("memory: {} lines", from_memory.len());
+
+    describe("file:", &files, "missing.txt");
+    describe("memory:", &memory, "missing.txt");
+    Ok(())
+}
+
+// One type parameter is enough: S::Error comes with S. With a C#-style
+// IStorage<TError> this would be describe<S, TError>(...) where S : IStorage<TError>,
+// and the caller, or the compiler, would have to work out TError as well
+fn describe<S: Storage>(name: &str, storage: &S, key: &str) {
+    let result: Result<Vec<u8>, S::Error> = storage.load(key);
+    match result {
+        Ok(bytes) => println!("{:<7} {} has {} bytes", name, key, bytes.len()),
+        Err(e)    => println!("{:<7} {}", name, e)
+    }
+}
+
+pub struct StorageDemo;
+
+impl Demo for StorageDemo {
+    fn name(&self) -> &'static str { "storage" }
+
+    fn description(&self) -> &'static str { "Storage with its own error type" }
+
+    fn technique(&self) -> &'static str { "associated types in place of a second generic parameter" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
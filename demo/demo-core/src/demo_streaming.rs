@@ -0,0 +1,78 @@
+use indicatif::ProgressBar;
+
+use crate::{ open_input, LineStream, ReadError };
+use crate::progress;
+use crate::registry::{ Demo, DemoError };
+
+// streaming: `streaming [--progress] [file] [count]`
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let (with_progress, args) = progress::take_flag(&args[1..]);
+    let mut argv = args.into_iter();
+    let file_name = argv.next();
+    let count: usize = match argv.next() {
+        Some(s) => match s.parse() {
+            Ok(n) => n,
+            Err(e) => return Err(DemoError::Usage(format!("Could not parse line count: {}", e)))
+        },
+        None => 5
+    };
+
+    // Opening the stream reads nothing yet - like File.ReadLines in C#.
+    // With no file name we stream whatever is piped in on stdin
+    // With --progress the reader is wrapped to move a bar on stderr as it goes.
+    // Without it the bar is a hidden one, which draws nothing
+    let file_name = file_name.as_ref().map(|s| &s[..]);
+    let opened = if with_progress {
+        progress::open_input(file_name)
+    } else {
+        open_input(file_name).map(|read| (read, ProgressBar::hidden()))
+    };
+    let (mut lines, bar) = match opened {
+        Ok((read, bar)) => (LineStream::new(read), bar),
+        Err(e) => return Err(abandon(ReadError::from(e), &ProgressBar::hidden()))
+    };
+
+    // Only the first `count` lines are read here. Each item is a Result, since
+    // any individual read can fail. by_ref lets us keep using the stream afterwards
+    for (i, line) in lines.by_ref().take(count).enumerate() {
+        match line {
+            // The bar is taken down while the line is printed, so they don't overlap
+            Ok(line) => bar.suspend(|| println!("{:>4}: {}", i + 1, line)),
+            Err(e)   => return Err(abandon(e, &bar))
+        }
+    }
+
+    // Then we fold over the rest of the input without ever holding more than
+    // one line in memory, however large it is. try_fold stops at the first Err
+    let totals = lines.try_fold((0, 0), |(lines, chars), line| {
+        line.map(|line| (lines + 1, chars + line.chars().count()))
+    });
+    match totals {
+        Ok((lines, chars)) => {
+            bar.finish_and_clear();
+            println!("...and {} more lines, {} characters", lines, chars);
+            Ok(())
+        },
+        Err(e) => Err(abandon(e, &bar))
+    }
+}
+
+// The bar is left where it stopped, above the error the runner reports
+fn abandon(e: ReadError, bar: &ProgressBar) -> DemoError {
+    bar.abandon();
+    DemoError::Read(e)
+}
+
+pub struct StreamingDemo;
+
+impl Demo for StreamingDemo {
+    fn name(&self) -> &'static str { "streaming" }
+
+    fn description(&self) -> &'static str { "Lazy iteration over a file" }
+
+    fn technique(&self) -> &'static str { "an Iterator of Results, one per line" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,84 @@
+use std::mem;
+
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+
+/// The first `n` characters of `text`, or all of it if it's shorter.
+///
+/// A `&str` can only be sliced by byte offsets, and only at the start of a
+/// character, so the offset has to be found first - `char_indices` gives the byte
+/// offset each character starts at.
+pub fn first_chars(text: &str, n: usize) -> &str {
+    match text.char_indices().nth(n) {
+        Some((offset, _)) => &text[..offset],
+        None              => text
+    }
+}
+
+// The first byte offset which falls in the middle of a character, if there is one
+fn first_bad_offset(text: &str) -> Option<usize> {
+    (0..text.len()).find(|&i| !text.is_char_boundary(i))
+}
+
+// Takes each line apart, then puts them back together.
+//
+// A C# string is a sequence of UTF-16 code units: `s.Length` counts them, and `s[0]`
+// is one, even when it's half of an emoji. A Rust String is UTF-8 bytes, which are
+// always valid, and there are three ways to count it - bytes, chars, or the UTF-16
+// units C# would see. None of them is *the* length, so `line[0]` doesn't compile:
+//
+//   error[E0277]: the type `str` cannot be indexed by `{integer}`
+//
+// A char is a whole Unicode scalar value, four bytes, not C#'s two
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    for line in &lines {
+        println!("{:?}: {} bytes, {} chars, {} UTF-16 units", line, line.len(), line.chars().count(), line.encode_utf16().count());
+        // bytes() is the UTF-8 encoding; chars() decodes it
+        let bytes: Vec<String> = line.bytes().take(6).map(|b| format!("{:02x}", b)).collect();
+        let chars: Vec<char> = line.chars().take(6).collect();
+        println!("  bytes {}, chars {:?}", bytes.join(" "), chars);
+        println!("  first 3 chars {:?}, last char {:?}", first_chars(line, 3), line.chars().last());
+        // Slicing by a byte offset in the middle of a character would panic, with
+        // "byte index 4 is not a char boundary; it is inside 'é' (bytes 3..5)" - get
+        // checks first, and returns None instead
+        if let Some(offset) = first_bad_offset(line) {
+            println!("  &line[..{}] would panic: get(..{}) is {:?}", offset, offset, line.get(..offset));
+        }
+    }
+
+    // Putting strings together. `+` takes the String on the left by value and appends
+    // to it, so it only goes String + &str; format! builds a new one from anything
+    let mut joined = String::new();
+    for line in &lines {
+        if !joined.is_empty() {
+            joined.push_str(", ");
+        }
+        joined += first_chars(line, 2);
+    }
+    println!("push_str and +=: {}", joined);
+    println!("join:            {}", lines.join(" | "));
+    if let [first, second, ..] = &lines[..] {
+        let added = first.clone() + " & " + second;
+        let formatted = format!("{} & {}", first, second);
+        println!("+:               {}", added);
+        println!("format!:         {}", formatted);
+    }
+    println!("A char is {} bytes, and a C# char is 2", mem::size_of::<char>());
+    Ok(())
+}
+
+pub struct StringsDemo;
+
+impl Demo for StringsDemo {
+    fn name(&self) -> &'static str { "strings" }
+
+    fn description(&self) -> &'static str { "Taking strings apart" }
+
+    fn technique(&self) -> &'static str { "UTF-8 String and &str in place of UTF-16 string" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use crate::{ read_lines_from_with, read_lines_with, ReadError, ReadOptions };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+
+/// The numbers read from a file, and where they came from.
+///
+/// As a C# class this would be
+///
+/// ```text
+/// public class NumberFile
+/// {
+///     private readonly string? path;
+///     private readonly List<ulong> numbers;
+///     public NumberFile(List<ulong> numbers) { ... }
+///     public static NumberFile Open(string path) { ... }
+///     public override string ToString() { ... }
+/// }
+/// ```
+///
+/// In Rust the data goes in the struct and the code in `impl` blocks, which can be
+/// split up and even live in different files of the same crate. `derive(Debug)` has
+/// the compiler write a `{:?}` format listing every field, which C# records do
+/// for `ToString` but classes don't. Fields are private to this module unless
+/// marked `pub`, so these can only be reached through the methods.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NumberFile {
+    path: Option<PathBuf>,
+    numbers: Vec<u64>
+}
+
+impl NumberFile {
+    /// Numbers which didn't come from a file.
+    ///
+    /// Rust has no constructors: any associated function which returns `Self` will do,
+    /// and `new` is only a naming convention. A struct literal - `NumberFile { path,
+    /// numbers }` - is the one real way to make a value, and only this module can write
+    /// one, because only it can see the private fields.
+    pub fn new(numbers: Vec<u64>) -> NumberFile {
+        NumberFile { path: None, numbers }
+    }
+
+    /// Reads the numbers in the file at `path`, one to a line, skipping blank lines
+    /// and comments as demo 6 does. The runner's `hist` command reads its file this way.
+    ///
+    /// A C# constructor can only fail by throwing. This is a C# static factory
+    /// method instead - `NumberFile.Open(path)` - which fails by returning an error.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<NumberFile, ReadError> {
+        let path = path.as_ref();
+        let lines = read_lines_with(path, &NumberFile::options()).map_err(|e| e.in_file(path))?;
+        let numbers = NumberFile::parse(&lines).map_err(|e| e.in_file(path))?;
+        Ok(NumberFile { path: Some(path.to_path_buf()), numbers })
+    }
+
+    /// Reads the numbers piped in on stdin
+    pub fn from_stdin() -> Result<NumberFile, ReadError> {
+        let lines = read_lines_from_with(io::stdin().lock(), &NumberFile::options())?;
+        Ok(NumberFile::new(NumberFile::parse(&lines)?))
+    }
+
+    // Associated functions needn't return Self: with no `self` parameter, this is just
+    // a function which lives in NumberFile's namespace, like a private static method
+    fn options() -> ReadOptions {
+        ReadOptions::new().skip_blank(true).skip_comments(true)
+    }
+
+    // The lines come numbered, so a bad one is reported where it is in the file,
+    // counting the blank lines and comments which were skipped
+    fn parse(lines: &[(usize, String)]) -> Result<Vec<u64>, ReadError> {
+        lines.iter()
+            .map(|&(line, ref text)| parse_line(line, text).map_err(ReadError::from))
+            .collect()
+    }
+
+    // Methods take `self` explicitly, as their first parameter. `&self` borrows the
+    // NumberFile to read it, like any C# instance method
+
+    /// Where the numbers came from, if they came from a file
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn numbers(&self) -> &[u64] {
+        &self.numbers
+    }
+
+    pub fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    /// The numbers added up, or `None` if that's too big for a `u64` - the answer
+    /// C#'s `checked` arithmetic gives as an `OverflowException`
+    pub fn sum(&self) -> Option<u64> {
+        self.numbers.iter().try_fold(0u64, |sum, &n| sum.checked_add(n))
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.numbers.iter().copied().max()
+    }
+
+    /// Adds a number to the end. `&mut self` borrows the NumberFile to change it, so
+    /// this can only be called through a `mut` variable - C# has no way to say which
+    /// methods change an object, short of `readonly` members on a struct.
+    pub fn push(&mut self, n: u64) {
+        self.numbers.push(n);
+    }
+
+    /// Hands over the numbers, using up the NumberFile. `self` by value moves it into
+    /// the method, so the caller can't touch it afterwards.
+    pub fn into_numbers(self) -> Vec<u64> {
+        self.numbers
+    }
+}
+
+// C#'s ToString. Implementing Display is what `{}` in format! uses, and gives
+// NumberFile a to_string method as well
+impl fmt::Display for NumberFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.path {
+            Some(ref path) => write!(f, "{} numbers from {}", self.len(), path.display()),
+            None           => write!(f, "{} numbers", self.len())
+        }
+    }
+}
+
+// Builds NumberFiles each way there is, and uses their methods
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    // Associated functions are called on the type, with ::, like static methods
+    let mut file = match args.get(1) {
+        Some(file_name) => NumberFile::open(file_name)?,
+        None            => NumberFile::from_stdin()?
+    };
+    println!("Display: {}", file);
+    println!("Debug:   {:?}", file);
+    let sum = file.sum().ok_or_else(|| DemoError::Overflow("The numbers add up to more than a u64 can hold".to_string()))?;
+    println!("sum {}, max {:?}", sum, file.max());
+
+    // Methods are called on a value, with a dot
+    file.push(sum);
+    println!("pushed:  {}", file);
+
+    let copy = NumberFile::new(file.numbers().to_vec());
+    println!("new:     {}, same numbers: {}", copy, copy.numbers() == file.numbers());
+    println!("default: {:?}", NumberFile::default());
+
+    let numbers = file.into_numbers();
+    // println!("{}", file);
+    //                ^^^^ error[E0382]: borrow of moved value: `file`
+    println!("into_numbers: {:?}", numbers);
+    Ok(())
+}
+
+pub struct StructsDemo;
+
+impl Demo for StructsDemo {
+    fn name(&self) -> &'static str { "structs" }
+
+    fn description(&self) -> &'static str { "Structs and impl blocks" }
+
+    fn technique(&self) -> &'static str { "structs with associated functions in place of classes" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
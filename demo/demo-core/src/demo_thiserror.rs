@@ -0,0 +1,128 @@
+use std::fs::File;
+use std::io::{ self, BufRead, BufReader };
+use std::num::ParseIntError;
+use std::path::{ Path, PathBuf };
+use thiserror::Error;
+
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+
+/// Demo 6's `ReadError` again, with thiserror writing the impls which read.rs
+/// writes by hand.
+///
+/// `#[error("...")]` is the `Display` impl, one message to a variant, with the
+/// fields in braces. `#[from]` writes the `From` impl which lets `?` convert, and
+/// marks the field as the `source` too. `#[source]` marks the cause without the
+/// `From`, and `#[error(transparent)]` hands both the message and the cause on to
+/// the error inside. The result is an ordinary enum: thiserror adds nothing at run
+/// time, and callers see no sign of it.
+///
+/// This is the C# custom exception hierarchy - `ReadException`, with a subclass for
+/// each way reading can fail - as one type. A library should return something like
+/// this: its callers can `match` on what went wrong and do something different for
+/// each, as the `From` impl for `DemoError` does, which is `catch (FileNotFoundException)` without the
+/// hierarchy. The variants are part of the library's API, like the exception types
+/// a .NET method documents it throws. An application which only reports errors can
+/// take these with `?` into an `anyhow::Result` (see demo_anyhow.rs), since
+/// anyhow accepts any `std::error::Error`; going the other way, a library returning
+/// `anyhow::Error` would leave its callers downcasting to find out what happened.
+#[derive(Debug, Error)]
+pub enum ReadError {
+    #[error("could not read file")]
+    Io(#[from] io::Error),
+    #[error("could not parse number")]
+    Parse(#[from] ParseIntError),
+    /// A line which wasn't a number, with where it was
+    #[error(transparent)]
+    BadLine(#[from] LineError),
+    /// One of the above, attributed to the file it came from
+    #[error("in {}", .path.display())]
+    InFile {
+        path: PathBuf,
+        #[source]
+        error: Box<ReadError>
+    }
+}
+
+impl ReadError {
+    /// Attributes this error to the file at `path`
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> ReadError {
+        ReadError::InFile { path: path.as_ref().to_path_buf(), error: Box::new(self) }
+    }
+
+    /// This error as the shared library's `ReadError`, found by matching on it - the
+    /// type says every kind of error there can be, so the compiler checks none is
+    /// missed
+    pub fn shared(self) -> crate::ReadError {
+        match self {
+            ReadError::Io(e)      => crate::ReadError::Io(e),
+            ReadError::Parse(e)   => crate::ReadError::Parse(e),
+            ReadError::BadLine(e) => crate::ReadError::BadLine(e),
+            ReadError::InFile { path, error } => error.shared().in_file(path)
+        }
+    }
+}
+
+/// Reads the numbers in `read`, one to a line, skipping blank lines and comments.
+/// Each `?` converts the error it's given with one of the `#[from]` impls.
+pub fn read_values<R: BufRead>(read: R) -> Result<Vec<u64>, ReadError> {
+    let mut values = Vec::new();
+    for (i, line) in read.lines().enumerate() {
+        let line = line?;
+        let text = line.trim();
+        if text.is_empty() || text.starts_with('#') || text.starts_with("//") {
+            continue;
+        }
+        values.push(parse_line(i + 1, text)?);
+    }
+    Ok(values)
+}
+
+/// Reads each file in turn, stopping at the first error
+pub fn read_files(file_names: &[String]) -> Result<Vec<u64>, ReadError> {
+    let mut values = Vec::new();
+    for file_name in file_names {
+        let file_values = File::open(file_name)
+            .map_err(ReadError::from)
+            .and_then(|file| read_values(BufReader::new(file)))
+            .map_err(|e| e.in_file(file_name))?;
+        values.extend(file_values);
+    }
+    Ok(values)
+}
+
+/// So `?` hands this demo's errors to the runner, which reports them as it does
+/// the shared library's
+impl From<ReadError> for DemoError {
+    fn from(e: ReadError) -> DemoError {
+        DemoError::Read(e.shared())
+    }
+}
+
+// thiserror: `thiserror [file]...`
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_names = &args[1..];
+    let values = if file_names.is_empty() {
+        read_values(io::stdin().lock())?
+    } else {
+        read_files(file_names)?
+    };
+    for v in values {
+        println!("{}", v);
+    }
+    Ok(())
+}
+
+pub struct ThiserrorDemo;
+
+impl Demo for ThiserrorDemo {
+    fn name(&self) -> &'static str { "thiserror" }
+
+    fn description(&self) -> &'static str { "Library errors with thiserror" }
+
+    fn technique(&self) -> &'static str { "derive(Error) with #[from] and #[error] in place of an exception hierarchy" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,106 @@
+use std::cell::RefCell;
+use std::panic;
+use std::thread;
+
+use crate::{ read_input_lines, ReadError };
+use crate::numbers::LineError;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// How many threads share the lines
+const WORKERS: usize = 4;
+
+// Each thread's reusable buffer, and how much use it got
+struct Scratch {
+    buffer: String,
+    uses: usize
+}
+
+// A thread's locals are dropped as the thread exits, so this runs once per thread.
+// The main thread is the exception: its locals may never be dropped at all, as the
+// process can end before anyone gets round to it - so nothing important belongs here
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        println!("{}: dropping its scratch buffer (lines parsed: {}, capacity: {} bytes)",
+            thread::current().name().unwrap_or("unnamed"), self.uses, self.buffer.capacity());
+    }
+}
+
+// Every thread which touches SCRATCH gets its own, created the first time it's used -
+// like C#'s ThreadLocal<T> with a factory, or a [ThreadStatic] field which initialises
+// itself. There's no way to reach another thread's, so no locking is needed. The
+// RefCell is because `with` only hands out a shared reference, and we want to change it.
+// The initial value can be worked out at compile time, and saying so with `const`
+// saves checking whether it's been initialised yet on every use
+thread_local! {
+    static SCRATCH: RefCell<Scratch> = const { RefCell::new(Scratch { buffer: String::new(), uses: 0 }) };
+}
+
+// Sums up the numbers in the input, which may be written with _ or , between the
+// digits, on several threads
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_name = args.get(1).map(|s| &s[..]);
+    let lines = read_input_lines(file_name)?;
+    let stats = stats_parallel(&lines).map_err(|e| e.in_input(file_name))?;
+    println!("Read on {} threads: {}", WORKERS, stats);
+    Ok(())
+}
+
+/// The statistics of the numbers in `lines`, which may have `_` or `,` between
+/// their digits, shared between named threads the same way as the scoped demo
+pub fn stats_parallel(lines: &[String]) -> Result<Stats, ReadError> {
+    let chunk_len = lines.len().div_ceil(WORKERS).max(1);
+    thread::scope(|scope| {
+        let handles: Vec<_> = lines.chunks(chunk_len)
+            .enumerate()
+            .map(|(chunk, slice)| thread::Builder::new()
+                .name(format!("worker {}", chunk + 1))
+                .spawn_scoped(scope, move || stats_of_chunk(slice, chunk * chunk_len))
+                .expect("could not start a thread"))
+            .collect();
+
+        let mut stats = Stats::default();
+        for handle in handles {
+            match handle.join() {
+                Ok(chunk)  => stats = stats.merge(chunk?),
+                Err(panic) => panic::resume_unwind(panic)
+            }
+        }
+        Ok(stats)
+    })
+}
+
+fn stats_of_chunk(lines: &[String], first: usize) -> Result<Stats, ReadError> {
+    let mut stats = Stats::default();
+    for (i, line) in lines.iter().enumerate() {
+        stats = stats.with(parse_separated(first + i + 1, line)?);
+    }
+    Ok(stats)
+}
+
+// Parses a number with separators by copying its digits into this thread's scratch
+// buffer first. The buffer keeps its capacity from line to line, so once it has
+// grown to fit the longest line, no more allocations are needed
+fn parse_separated(line: usize, text: &str) -> Result<u64, LineError> {
+    SCRATCH.with(|scratch| {
+        let mut scratch = scratch.borrow_mut();
+        scratch.uses += 1;
+        scratch.buffer.clear();
+        scratch.buffer.extend(text.trim().chars().filter(|&c| c != '_' && c != ','));
+        scratch.buffer.parse().map_err(|error| LineError { line, column: None, text: text.to_string(), error })
+    })
+}
+
+pub struct ThreadLocalDemo;
+
+impl Demo for ThreadLocalDemo {
+    fn name(&self) -> &'static str { "thread-local" }
+
+    fn description(&self) -> &'static str { "Per-thread scratch space" }
+
+    fn technique(&self) -> &'static str { "thread_local! in place of ThreadLocal<T>" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,80 @@
+use std::panic;
+use std::thread::{ self, JoinHandle };
+
+use crate::{ read_lines, ReadError };
+use crate::numbers::parse_line;
+use crate::registry::{ Demo, DemoError };
+use crate::stats::Stats;
+
+// What each thread hands back when it's done
+type FileResult = Result<Vec<u64>, ReadError>;
+
+// Reads every file named on the command line at once, one thread per file
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let file_names = &args[1..];
+    if file_names.is_empty() {
+        return Err(DemoError::Usage("Expected one or more files to read".to_string()));
+    }
+
+    // thread::spawn is our Task.Run: the thread starts straight away, and the
+    // JoinHandle is how we get its result later, like a Task<T>. Its signature
+    // spells out what the compiler checks for us:
+    //
+    //   fn spawn<F, T>(f: F) -> JoinHandle<T>
+    //       where F: FnOnce() -> T + Send + 'static, T: Send + 'static
+    //
+    // 'static means the closure can't borrow anything from this function, which
+    // might return before the thread finishes - so it's a `move` closure, and
+    // takes its own copy of the file name. Send means the closure and its result
+    // are safe to hand to another thread. String, Vec<u64> and ReadError all are;
+    // try capturing an Rc instead and the program won't compile.
+    let handles: Vec<(String, JoinHandle<FileResult>)> = file_names.iter()
+        .map(|file_name| {
+            let owned = file_name.clone();
+            let handle = thread::spawn(move || read_file(&owned));
+            (file_name.clone(), handle)
+        })
+        .collect();
+
+    // Task.WhenAll: wait for every thread before looking at any of the results.
+    // join returns Err only if the thread panicked, and resume_unwind carries on
+    // panicking here - the same way awaiting a faulted Task rethrows its exception
+    let results: Vec<(String, FileResult)> = handles.into_iter()
+        .map(|(file_name, handle)| match handle.join() {
+            Ok(result) => (file_name, result),
+            Err(panic) => panic::resume_unwind(panic)
+        })
+        .collect();
+
+    let mut total = Stats::default();
+    for (file_name, result) in results {
+        let numbers = result.map_err(|e| e.in_file(&file_name))?;
+        let stats = Stats::of(&numbers);
+        println!("{}: {}", file_name, stats);
+        total = total.merge(stats);
+    }
+    println!("Read {} files on {} threads: {}", file_names.len(), file_names.len(), total);
+    Ok(())
+}
+
+fn read_file(file_name: &str) -> FileResult {
+    let mut numbers = Vec::new();
+    for (i, line) in read_lines(file_name)?.iter().enumerate() {
+        numbers.push(parse_line(i + 1, line)?);
+    }
+    Ok(numbers)
+}
+
+pub struct ThreadsDemo;
+
+impl Demo for ThreadsDemo {
+    fn name(&self) -> &'static str { "threads" }
+
+    fn description(&self) -> &'static str { "Reading files in parallel" }
+
+    fn technique(&self) -> &'static str { "thread::spawn, move closures and JoinHandle" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
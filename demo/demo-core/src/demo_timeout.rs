@@ -0,0 +1,55 @@
+use std::time::{ Duration, Instant };
+
+use crate::{ read_with_timeout, ReadError };
+use crate::registry::{ Demo, DemoError };
+
+// Reads a file, giving up if it takes longer than the given number of milliseconds.
+// --async does the same with tokio, where the async feature is turned on
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let mut argv = args.iter().skip(1).peekable();
+    let use_async = argv.next_if(|a| *a == "--async").is_some();
+    let timeout = argv.next().and_then(|ms| ms.parse().ok()).map(Duration::from_millis);
+    let (timeout, file_name) = match (timeout, argv.next()) {
+        (Some(timeout), Some(file_name)) => (timeout, file_name),
+        _ => return Err(DemoError::Usage("Expected a timeout in milliseconds and a file to read".to_string()))
+    };
+
+    let started = Instant::now();
+    let lines = if use_async { read_async(file_name, timeout)? } else { read_with_timeout(file_name, timeout) };
+    // A timeout is just another ReadError, so it's reported like any other - in C#
+    // WaitAsync throws a TimeoutException, which is easy to miss in a catch block
+    let lines = lines.map_err(|e| e.in_file(file_name))?;
+    println!("Read {} lines in {}ms", lines.len(), started.elapsed().as_millis());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+fn read_async(file_name: &str, timeout: Duration) -> Result<Result<Vec<String>, ReadError>, DemoError> {
+    use crate::async_read::read_with_timeout_async;
+
+    let runtime = tokio::runtime::Runtime::new().map_err(ReadError::from)?;
+    let lines = runtime.block_on(read_with_timeout_async(file_name, timeout));
+    // Dropping a runtime waits for its blocking threads, one of which may still be
+    // stuck in a read which timed out. We've stopped caring about it, so don't wait
+    runtime.shutdown_background();
+    Ok(lines)
+}
+
+#[cfg(not(feature = "async"))]
+fn read_async(_: &str, _: Duration) -> Result<Result<Vec<String>, ReadError>, DemoError> {
+    Err(DemoError::Usage("--async needs the async feature: cargo run --features async".to_string()))
+}
+
+pub struct TimeoutDemo;
+
+impl Demo for TimeoutDemo {
+    fn name(&self) -> &'static str { "timeout" }
+
+    fn description(&self) -> &'static str { "Giving up on a slow read" }
+
+    fn technique(&self) -> &'static str { "recv_timeout in place of Task.WaitAsync" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,89 @@
+use std::time::Instant;
+
+use tracing::field::Empty;
+use tracing::{ debug, info, info_span, instrument, warn, Span };
+
+use crate::{ read_lines, ReadError };
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+
+/// Reads a file of numbers and adds them up, each step in a span of its own.
+///
+/// A span is a stretch of work with a beginning and an end - an `Activity`, in
+/// .NET's System.Diagnostics. Everything logged inside one, including in the spans
+/// it opens, belongs to it, so a subscriber can say which file a skipped line came
+/// from without every message repeating it. `#[instrument]` opens a span for each
+/// call, named after the function, with its arguments as fields.
+///
+/// The total is a `u128`, which no file of `u64`s can overflow.
+#[instrument(fields(line_count = Empty, duration_ms = Empty))]
+pub fn total_file(file_name: &str) -> Result<u128, ReadError> {
+    let started = Instant::now();
+    let lines: Vec<String> = read(file_name)?;
+    let (numbers, errors) = parse(&lines);
+    let total: u128 = numbers.iter().map(|&n| u128::from(n)).sum();
+
+    // Fields declared `Empty` are filled in once they're known, as
+    // `Activity.SetTag` would
+    let span = Span::current();
+    span.record("line_count", lines.len());
+    span.record("duration_ms", started.elapsed().as_secs_f64() * 1000.0);
+    info!(total, skipped = errors.len(), "totalled");
+    Ok(total)
+}
+
+// The arguments are already the parent span's fields, so they're skipped here
+#[instrument(skip_all)]
+fn read(file_name: &str) -> Result<Vec<String>, ReadError> {
+    let lines = read_lines(file_name).map_err(|e| e.in_file(file_name))?;
+    debug!(line_count = lines.len(), "read");
+    Ok(lines)
+}
+
+#[instrument(skip_all, fields(line_count = lines.len()))]
+fn parse(lines: &[String]) -> (Vec<u64>, Vec<LineError>) {
+    let mut numbers = Vec::new();
+    let mut errors = Vec::new();
+    for (i, text) in lines.iter().enumerate() {
+        match parse_line(i + 1, text) {
+            Ok(n) => numbers.push(n),
+            Err(e) => {
+                // `%` records a field with Display, and `?` with Debug. A JSON
+                // subscriber writes each as a property, not as part of the message
+                warn!(line = e.line, text = %e.text, error = %e.error, "skipping");
+                errors.push(e);
+            }
+        }
+    }
+    (numbers, errors)
+}
+
+// tracing: `tracing <file>...`. Nothing is printed but the totals unless RUST_LOG
+// says so: `RUST_LOG=demo=debug demo run tracing numbers.txt`, with
+// DEMO_LOG_FORMAT=json for one JSON object per line
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    if args.len() < 2 {
+        return Err(DemoError::Usage("Expected one or more files to total".to_string()));
+    }
+    // A span entered by hand, around all of the files. `_entered` leaves it when
+    // it's dropped, at the end of main
+    let _entered = info_span!("files", count = args.len() - 1).entered();
+    for file_name in &args[1..] {
+        println!("{}: {}", file_name, total_file(file_name)?);
+    }
+    Ok(())
+}
+
+pub struct TracingDemo;
+
+impl Demo for TracingDemo {
+    fn name(&self) -> &'static str { "tracing" }
+
+    fn description(&self) -> &'static str { "Spans and structured logging" }
+
+    fn technique(&self) -> &'static str { "tracing spans and fields in place of Activity and OpenTelemetry" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
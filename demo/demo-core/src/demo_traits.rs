@@ -0,0 +1,128 @@
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+
+/// Somewhere to send lines - the interface this demo is built around.
+///
+/// A trait is a set of methods a type can implement, like a C# interface, and it can
+/// be used the same two ways: as a constraint on a generic, or as a type of its own
+/// behind a pointer (`Box<dyn LineSink>`, much like an `ILineSink` variable).
+pub trait LineSink {
+    /// Takes one line
+    fn accept(&mut self, line: &str);
+
+    /// Takes every line in `lines`, in order.
+    ///
+    /// A default method, like C# 8's default interface methods: implementers get it
+    /// for free, and can override it. Unlike C#, it can be called on the implementing
+    /// type directly, without casting to the interface first.
+    fn accept_all(&mut self, lines: &[String]) {
+        for line in lines {
+            self.accept(line);
+        }
+    }
+
+    /// What the sink has to show for the lines it's been sent, if anything
+    fn summary(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Prints each line, with a prefix
+pub struct ConsoleSink {
+    pub prefix: &'static str
+}
+
+impl LineSink for ConsoleSink {
+    fn accept(&mut self, line: &str) {
+        println!("{}{}", self.prefix, line);
+    }
+}
+
+/// Counts lines and characters, and throws the lines away
+#[derive(Debug, Default)]
+pub struct CountingSink {
+    pub lines: usize,
+    pub chars: usize
+}
+
+impl LineSink for CountingSink {
+    fn accept(&mut self, line: &str) {
+        self.lines += 1;
+        self.chars += line.chars().count();
+    }
+
+    // Overrides the default: there's no need to look at lines one at a time to count them
+    fn accept_all(&mut self, lines: &[String]) {
+        self.lines += lines.len();
+        self.chars += lines.iter().map(|line| line.chars().count()).sum::<usize>();
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!("counted {} lines, {} characters", self.lines, self.chars))
+    }
+}
+
+// Collects the lines. Vec isn't our type, but LineSink is our trait, so we can
+// implement one for the other - C# has no way to make List<string> implement a new
+// interface. What we can't do is implement someone else's trait for someone else's
+// type, such as Display for Vec: that's the *orphan rule*, which stops two crates
+// from each supplying a different implementation of the same thing
+impl LineSink for Vec<String> {
+    fn accept(&mut self, line: &str) {
+        self.push(line.to_string());
+    }
+
+    fn summary(&self) -> Option<String> {
+        Some(format!("collected {:?}", self))
+    }
+}
+
+// Sends the input to each kind of sink, first through generics, then through trait objects
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    // Generics: `S: LineSink` is `where S : ILineSink`. A separate copy of send_to is
+    // compiled for each sink type, so every call is direct
+    send_to(&lines, &mut ConsoleSink { prefix: "generic: " });
+    let mut counter = CountingSink::default();
+    send_to(&lines, &mut counter);
+    println!("generic: counted {} lines, {} characters", counter.lines, counter.chars);
+
+    // Trait objects: one list holding different types, like a List<ILineSink>. Each
+    // call goes through a table of function pointers - a virtual call
+    let mut sinks: Vec<Box<dyn LineSink>> = vec![
+        Box::new(ConsoleSink { prefix: "dyn:     " }),
+        Box::new(CountingSink::default()),
+        Box::new(Vec::new())
+    ];
+    for sink in sinks.iter_mut() {
+        sink.accept_all(&lines);
+    }
+    for summary in sinks.iter().filter_map(|sink| sink.summary()) {
+        println!("dyn:     {}", summary);
+    }
+
+    // There's no inheritance to reuse code with - no abstract base class for the sinks
+    // to share. Default methods and generic functions like send_to do that job, and a
+    // type which wants another's behaviour holds one as a field
+    Ok(())
+}
+
+/// Sends every line to `sink`. Works with any sink at all, chosen at compile time.
+pub fn send_to<S: LineSink>(lines: &[String], sink: &mut S) {
+    sink.accept_all(lines);
+}
+
+pub struct TraitsDemo;
+
+impl Demo for TraitsDemo {
+    fn name(&self) -> &'static str { "traits" }
+
+    fn description(&self) -> &'static str { "Traits and interfaces" }
+
+    fn technique(&self) -> &'static str { "traits, generics and dyn in place of interfaces" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
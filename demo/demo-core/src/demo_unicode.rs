@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::read_input_lines;
+use crate::registry::{ Demo, DemoError };
+
+/// Characters which take up no space and mean nothing on their own: the byte order
+/// mark (which turns up mid-file when files are joined together), the zero-width
+/// space and the word joiner. The zero-width *joiner*, U+200D, isn't one of them - it
+/// holds emoji sequences together.
+pub const INVISIBLE: [char; 3] = ['\u{FEFF}', '\u{200B}', '\u{2060}'];
+
+/// `text` without any invisible characters - borrowed as it is if there aren't any
+pub fn strip_invisible(text: &str) -> Cow<'_, str> {
+    if text.contains(&INVISIBLE[..]) {
+        Cow::Owned(text.chars().filter(|c| !INVISIBLE.contains(c)).collect())
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// What to compare lines by, so that text which looks the same is the same.
+///
+/// `é` can be one char, or an `e` followed by a combining accent. NFC normalization
+/// composes the second into the first, as C#'s `string.Normalize()` does - but it
+/// isn't in the standard library, it's the unicode-normalization crate's `nfc()`.
+pub fn comparison_key(text: &str) -> String {
+    strip_invisible(text).nfc().collect::<String>().to_lowercase()
+}
+
+// Counts what a person would call the characters on each line, and finds the lines
+// which only differ in ways a person can't see.
+//
+// A char is a code point, which isn't what a reader thinks of as a character: an
+// accent can be a code point of its own, and a family emoji is five of them. The
+// unicode-segmentation crate splits text into *grapheme clusters*, which are what
+// C#'s StringInfo.LengthInTextElements counts
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    // The shared reader already dropped any byte order mark at the start of the input
+    let lines = read_input_lines(args.get(1).map(|s| &s[..]))?;
+
+    for (i, line) in lines.iter().enumerate() {
+        let text = strip_invisible(line);
+        if let Cow::Owned(_) = text {
+            let found: Vec<char> = line.chars().filter(|c| INVISIBLE.contains(c)).collect();
+            println!("line {}: stripped {:?}", i + 1, found);
+        }
+        println!("line {}: {:?} has {} chars and {} graphemes", i + 1, text, text.chars().count(), text.graphemes(true).count());
+        // Case works on the whole string, not char by char, because one char can
+        // become several: ß uppercases to SS, and İ lowercases to i and a combining
+        // dot. It's always invariant - there's no CultureInfo, so no ToLower(culture)
+        println!("  lower {:?}, upper {:?}", text.to_lowercase(), text.to_uppercase());
+    }
+
+    let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        groups.entry(comparison_key(line)).or_default().push(i + 1);
+    }
+    for (key, numbers) in &groups {
+        if numbers.len() > 1 {
+            let identical = numbers.windows(2).all(|pair| lines[pair[0] - 1] == lines[pair[1] - 1]);
+            println!("lines {:?} are all {:?} - and == says they're {}", numbers, key, if identical { "equal" } else { "different" });
+        }
+    }
+    Ok(())
+}
+
+pub struct UnicodeDemo;
+
+impl Demo for UnicodeDemo {
+    fn name(&self) -> &'static str { "unicode" }
+
+    fn description(&self) -> &'static str { "Text as people read it" }
+
+    fn technique(&self) -> &'static str { "grapheme clusters and normalization in place of StringInfo and Normalize" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt;
+use std::io;
+use std::iter::Sum;
+use std::ops::{ Add, Div, Mul };
+
+use crate::{ read_lines_from_with, read_lines_with, ReadOptions };
+use crate::registry::{ Demo, DemoError };
+
+/// A distance. A struct with one field, wrapping an f64 to give it a meaning - a
+/// *newtype*. In memory it's exactly an f64, so the safety costs nothing at run time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Meters(pub f64);
+
+/// A length of time
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Seconds(pub f64);
+
+/// A speed - what you get by dividing Meters by Seconds
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct MetersPerSecond(pub f64);
+
+// Operators are traits: implementing Add is C#'s `public static Meters operator +`.
+// Only the combinations which make sense are implemented, so Meters + Seconds is a
+// compile error rather than a meaningless number
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0 + other.0)
+    }
+}
+
+impl Add for Seconds {
+    type Output = Seconds;
+
+    fn add(self, other: Seconds) -> Seconds {
+        Seconds(self.0 + other.0)
+    }
+}
+
+// The two sides of an operator needn't be the same type, and neither need the result
+impl Div<Seconds> for Meters {
+    type Output = MetersPerSecond;
+
+    fn div(self, time: Seconds) -> MetersPerSecond {
+        MetersPerSecond(self.0 / time.0)
+    }
+}
+
+impl Mul<Seconds> for MetersPerSecond {
+    type Output = Meters;
+
+    fn mul(self, time: Seconds) -> Meters {
+        Meters(self.0 * time.0)
+    }
+}
+
+// Scaling by a plain number is fine, whatever the unit
+impl Mul<f64> for Meters {
+    type Output = Meters;
+
+    fn mul(self, factor: f64) -> Meters {
+        Meters(self.0 * factor)
+    }
+}
+
+// Sum is what Iterator::sum needs - C# would need a generic math interface for Sum()
+impl Sum for Meters {
+    fn sum<I: Iterator<Item = Meters>>(iter: I) -> Meters {
+        iter.fold(Meters::default(), |total, m| total + m)
+    }
+}
+
+impl Sum for Seconds {
+    fn sum<I: Iterator<Item = Seconds>>(iter: I) -> Seconds {
+        iter.fold(Seconds::default(), |total, s| total + s)
+    }
+}
+
+impl fmt::Display for Meters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.1} m", self.0)
+    }
+}
+
+impl fmt::Display for Seconds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.2} s", self.0)
+    }
+}
+
+impl fmt::Display for MetersPerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.2} m/s", self.0)
+    }
+}
+
+/// One line of input: how far, and how long it took
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Run {
+    pub distance: Meters,
+    pub time: Seconds
+}
+
+/// Reads a `meters seconds` line, or says what's wrong with it
+pub fn parse_run(text: &str) -> Result<Run, String> {
+    let fields: Vec<&str> = text.split_whitespace().collect();
+    match fields[..] {
+        [meters, seconds] => {
+            let meters = meters.parse().map_err(|e| format!("bad distance '{}': {}", meters, e))?;
+            let seconds = seconds.parse().map_err(|e| format!("bad time '{}': {}", seconds, e))?;
+            Ok(Run { distance: Meters(meters), time: Seconds(seconds) })
+        },
+        _ => Err(format!("expected a distance and a time, got '{}'", text.trim()))
+    }
+}
+
+// Reads runs, one `meters seconds` pair to a line, and works out the totals - in units
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let options = ReadOptions::new().skip_blank(true).skip_comments(true);
+    let lines = match args.get(1) {
+        Some(file_name) => read_lines_with(file_name, &options).map_err(|e| e.in_file(file_name))?,
+        None            => read_lines_from_with(io::stdin().lock(), &options)?
+    };
+    let mut runs = Vec::new();
+    for (line, text) in &lines {
+        match parse_run(text) {
+            Ok(run) => runs.push(run),
+            Err(e)  => println!("line {}: {}", line, e)
+        }
+    }
+
+    for run in &runs {
+        println!("{} in {}: {}", run.distance, run.time, run.distance / run.time);
+    }
+    let distance: Meters = runs.iter().map(|run| run.distance).sum();
+    let time: Seconds = runs.iter().map(|run| run.time).sum();
+    println!("Total: {} in {}", distance, time);
+    if time.0 > 0.0 {
+        let speed = distance / time;
+        println!("Average speed: {}, or {} in an hour", speed, speed * Seconds(3600.0));
+        println!("Twice the distance: {}", distance * 2.0);
+    }
+
+    // Mixing up the units doesn't compile:
+    //
+    //   let nonsense = distance + time;
+    //                             ^^^^ error[E0308]: mismatched types: expected `Meters`,
+    //                                  found `Seconds`
+    //
+    // In C# the same safety needs a struct for each unit and a page of operator
+    // declarations; with plain doubles, `distance + time` compiles and is wrong
+    Ok(())
+}
+
+pub struct UnitsDemo;
+
+impl Demo for UnitsDemo {
+    fn name(&self) -> &'static str { "units" }
+
+    fn description(&self) -> &'static str { "Units which can't be mixed up" }
+
+    fn technique(&self) -> &'static str { "newtypes with operator traits in place of operator overloads" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use crate::read_input_lines;
+use crate::numbers::{ parse_line, LineError };
+use crate::registry::{ Demo, DemoError };
+use crate::validated::{ InvalidValue, Port, Score };
+
+// Reads every line as a Port or a Score, reporting each one which isn't.
+//
+// The C# habit is to parse an int and then validate it - `if (port < 1 || port >
+// 65535) throw ...` - and every method which is handed the int has to trust that
+// someone did. Here the check lives in TryFrom, the only way to make a Port, so a
+// Port which exists has passed it. Parse, don't validate: turn the text into a type
+// which can't hold a bad value, and the rest of the program never needs to ask
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let type_name = match args.get(1) {
+        Some(type_name) => &type_name[..],
+        None => return Err(DemoError::Usage("Expected a type to read: port or score".to_string()))
+    };
+    let lines = read_input_lines(args.get(2).map(|s| &s[..]))?;
+
+    match type_name {
+        "port" => {
+            let ports: Vec<Port> = parse_all(&lines);
+            println!("{} valid ports, the lowest {:?}", ports.len(), ports.iter().min().map(|port| port.get()));
+        },
+        "score" => {
+            let scores: Vec<Score> = parse_all(&lines);
+            println!("{} valid scores, averaging {:.1}%", scores.len(), average(&scores));
+        },
+        other => return Err(DemoError::Usage(format!("Unknown type: {} (expected port or score)", other)))
+    }
+    Ok(())
+}
+
+// Keeps the values which parse, and prints why each of the others didn't. The error is
+// a LineError<InvalidValue>, so it says which rule was broken, not just that one was
+fn parse_all<T: FromStr<Err = InvalidValue>>(lines: &[String]) -> Vec<T> {
+    let mut values = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let parsed: Result<T, LineError<InvalidValue>> = parse_line(i + 1, line);
+        match parsed {
+            Ok(value) => values.push(value),
+            Err(e)    => println!("{}", e)
+        }
+    }
+    values
+}
+
+// No range check needed: every Score is between 0 and 100, or it wouldn't be a Score
+fn average(scores: &[Score]) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    scores.iter().map(|score| f64::from(score.get())).sum::<f64>() / scores.len() as f64
+}
+
+pub struct ValidateDemo;
+
+impl Demo for ValidateDemo {
+    fn name(&self) -> &'static str { "validate" }
+
+    fn description(&self) -> &'static str { "Types which can't hold bad values" }
+
+    fn technique(&self) -> &'static str { "newtypes with TryFrom in place of validation checks" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
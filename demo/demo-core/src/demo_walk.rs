@@ -0,0 +1,95 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use crate::read_lines;
+use crate::diagnostics;
+use crate::registry::{ Demo, DemoError };
+
+// Like Directory.EnumerateFiles(path, "*.txt", SearchOption.AllDirectories) in C#,
+// but reading each file we find as we go
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let root = Path::new(args.get(1).map(|s| &s[..]).unwrap_or("."));
+
+    let mut files = Vec::new();
+    walk_recursive(root, &mut files);
+    for file in files.iter() {
+        // One bad file shouldn't stop us processing the rest
+        match read_lines(file) {
+            Ok(lines) => println!("{}: {} lines", file.display(), lines.len()),
+            Err(e)    => diagnostics::warning(format!("{}: {}", file.display(), e))
+        }
+    }
+
+    let stacked = walk_with_stack(root);
+    println!("Found {} files recursively, and {} with an explicit stack", files.len(), stacked.len());
+    Ok(())
+}
+
+// The recursive version: each directory calls back into walk_recursive for its subdirectories.
+// An unreadable directory or entry is reported and skipped, rather than aborting the whole walk
+fn walk_recursive(dir: &Path, files: &mut Vec<PathBuf>) {
+    for (path, is_dir) in read_entries(dir) {
+        if is_dir {
+            walk_recursive(&path, files);
+        } else if is_txt(&path) {
+            files.push(path);
+        }
+    }
+}
+
+// The same walk using an explicit stack of directories still to visit, so a very
+// deep tree can't overflow the call stack. Entries are pushed in reverse so they
+// come back off the stack in order, and we find files in the same order as above
+fn walk_with_stack(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut stack = vec![(root.to_path_buf(), true)];
+    while let Some((path, is_dir)) = stack.pop() {
+        if is_dir {
+            stack.extend(read_entries(&path).into_iter().rev());
+        } else if is_txt(&path) {
+            files.push(path);
+        }
+    }
+    files
+}
+
+// Lists a directory's entries in name order (read_dir makes no promises about order),
+// along with whether each is itself a directory
+fn read_entries(dir: &Path) -> Vec<(PathBuf, bool)> {
+    let read_dir = match fs::read_dir(dir) {
+        Ok(read_dir) => read_dir,
+        Err(e) => {
+            diagnostics::warning(format!("Skipping {}: {}", dir.display(), e));
+            return Vec::new();
+        }
+    };
+    let mut entries = Vec::new();
+    for entry in read_dir {
+        // file_type doesn't follow symlinks, so a link back up the tree can't loop forever
+        let entry = entry.and_then(|entry| entry.file_type().map(|t| (entry.path(), t.is_dir())));
+        match entry {
+            Ok(entry) => entries.push(entry),
+            Err(e)    => diagnostics::warning(format!("Skipping an entry in {}: {}", dir.display(), e))
+        }
+    }
+    entries.sort();
+    entries
+}
+
+fn is_txt(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "txt")
+}
+
+pub struct WalkDemo;
+
+impl Demo for WalkDemo {
+    fn name(&self) -> &'static str { "walk" }
+
+    fn description(&self) -> &'static str { "Walking a directory tree" }
+
+    fn technique(&self) -> &'static str { "reporting and skipping per-entry errors" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
@@ -0,0 +1,59 @@
+//! `wc`, split into modules the way a program too big for one file would be.
+//!
+//! A module is a namespace and an access boundary at once. Everything in one is
+//! private unless it's marked otherwise - the opposite of C#, where a class
+//! member without a modifier is private but the class itself is `internal` - and
+//! it can see everything private in the modules it's inside. `mod parser;` means
+//! the file demo_wc/parser.rs, so the folders follow the modules rather than being
+//! chosen freely as a C# project's are.
+//!
+//! * `parser` reads the flags, `reader` counts a stream and `report` formats the
+//!   counts. None of them is `pub`, so the rest of the crate can't reach into them
+//! * `prelude` re-exports what callers need, and this module re-exports the
+//!   prelude, so `demo_wc::count` and `demo_wc::prelude::count` are the same
+//!   function. The runner only sees that, and `WcDemo`
+
+mod parser;
+mod reader;
+mod report;
+pub mod prelude;
+
+pub use self::prelude::*;
+
+use crate::progress;
+use crate::registry::{ Demo, DemoError };
+
+// wc: `wc [-lwmc] [--progress] [file...]`, reading stdin when there are no files, or
+// for `-`. With more than one file, a total follows. --progress shows a bar while
+// each file is read
+pub fn main(args: &[String]) -> Result<(), DemoError> {
+    let (with_progress, args) = progress::take_flag(&args[1..]);
+    let (show, files) = parse_args(&args)?;
+    let files = if files.is_empty() { vec!["-"] } else { files };
+
+    let mut total = Counts::default();
+    for &file_name in &files {
+        let path = if file_name == "-" { None } else { Some(file_name) };
+        let counts = progress::read_input(path, with_progress, count).map_err(|e| e.in_input(path))?;
+        println!("{}", report::row(&show, &counts, path));
+        total = total + counts;
+    }
+    if files.len() > 1 {
+        println!("{}", report::row(&show, &total, Some("total")));
+    }
+    Ok(())
+}
+
+pub struct WcDemo;
+
+impl Demo for WcDemo {
+    fn name(&self) -> &'static str { "wc" }
+
+    fn description(&self) -> &'static str { "Counting lines, words and bytes" }
+
+    fn technique(&self) -> &'static str { "a small tool, counting raw lines from read_until in place of a LINQ one-liner" }
+
+    fn run(&self, args: &[String]) -> Result<(), DemoError> {
+        main(args)
+    }
+}
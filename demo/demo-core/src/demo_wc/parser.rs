@@ -0,0 +1,52 @@
+//! Reading `wc`'s command line
+
+use crate::registry::DemoError;
+
+/// Which counts to show, in the order `wc` shows them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Show {
+    pub lines: bool,
+    pub words: bool,
+    pub chars: bool,
+    pub bytes: bool
+}
+
+impl Show {
+    /// Whether any of the counts is to be shown
+    fn any(&self) -> bool {
+        self.lines || self.words || self.chars || self.bytes
+    }
+}
+
+/// Reads `wc`'s flags, which can be given separately or together: `-l -w`, or `-lw`.
+/// With none, it shows lines, words and bytes. Returns the flags and the file names.
+pub fn parse_args(args: &[String]) -> Result<(Show, Vec<&str>), DemoError> {
+    let mut show = Show { lines: false, words: false, chars: false, bytes: false };
+    let mut files = Vec::new();
+    for arg in args {
+        if arg.len() < 2 || !arg.starts_with('-') {
+            files.push(&arg[..]);
+            continue;
+        }
+        for flag in arg.chars().skip(1) {
+            set_flag(&mut show, flag)?;
+        }
+    }
+    if !show.any() {
+        show = Show { lines: true, words: true, chars: false, bytes: true };
+    }
+    Ok((show, files))
+}
+
+// Nothing is marked `pub`, so this is private to the parser module - not even the
+// rest of the wc demo can call it
+fn set_flag(show: &mut Show, flag: char) -> Result<(), DemoError> {
+    match flag {
+        'l' => show.lines = true,
+        'w' => show.words = true,
+        'm' => show.chars = true,
+        'c' => show.bytes = true,
+        _   => return Err(DemoError::Usage(format!("Unknown flag -{}; expected -l, -w, -m or -c", flag)))
+    }
+    Ok(())
+}
@@ -0,0 +1,8 @@
+//! Everything a user of the wc demo needs, for `use demo_core::demo_wc::prelude::*`.
+//!
+//! The modules these come from are private, so these re-exports are the only way
+//! to name them from outside - the public path of an item is chosen separately
+//! from where it's written, as a C# type can be forwarded to another namespace.
+
+pub use super::parser::{ parse_args, Show };
+pub use super::reader::{ count, Counts };
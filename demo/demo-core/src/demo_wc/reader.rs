@@ -0,0 +1,72 @@
+//! Counting what's in a stream
+
+use std::io::BufRead;
+use std::ops::Add;
+
+use crate::ReadError;
+
+/// What `wc` counts, for one file or all of them
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub lines: usize,
+    pub words: usize,
+    pub chars: usize,
+    pub bytes: usize
+}
+
+impl Counts {
+    /// The counts for a single line's bytes, with its line break if it has one.
+    ///
+    /// As in `wc`, a line is only counted if it ends in `\n`, so a last line without
+    /// one adds its words and bytes but not a line, and the `\r` of a `\r\n` is a
+    /// byte, a character and whitespace like any other. Bytes which aren't UTF-8 are
+    /// counted as bytes but not characters, and don't fail the count.
+    ///
+    /// `pub(crate)` is C#'s `internal`: any module in this crate can call it, but
+    /// nothing outside it can, and it isn't part of the prelude.
+    pub(crate) fn of_line(line: &[u8]) -> Counts {
+        let text: String = line.utf8_chunks().map(|chunk| chunk.valid()).collect();
+        Counts {
+            lines: if line.ends_with(b"\n") { 1 } else { 0 },
+            words: text.split_whitespace().count(),
+            chars: text.chars().count(),
+            bytes: line.len()
+        }
+    }
+}
+
+// Adding counts up is what lets a fold total them, and what totals several files
+impl Add for Counts {
+    type Output = Counts;
+
+    fn add(self, other: Counts) -> Counts {
+        Counts {
+            lines: self.lines + other.lines,
+            words: self.words + other.words,
+            chars: self.chars + other.chars,
+            bytes: self.bytes + other.bytes
+        }
+    }
+}
+
+/// Counts everything in a stream in one pass, holding one line at a time.
+///
+/// The LINQ one-liner reads the file once for each figure:
+///
+/// ```text
+/// var lines = File.ReadLines(path);
+/// var counts = (lines.Count(), lines.Sum(l => l.Split(' ', RemoveEmptyEntries).Length), ...);
+/// ```
+///
+/// `read_until` reads the raw bytes of each line, its `\n` included, into a buffer
+/// which is reused for the next, where a `LineStream` would decode the line and drop
+/// the line break that `wc` needs to see.
+pub fn count<R: BufRead>(mut read: R) -> Result<Counts, ReadError> {
+    let mut counts = Counts::default();
+    let mut line = Vec::new();
+    while read.read_until(b'\n', &mut line)? > 0 {
+        counts = counts + Counts::of_line(&line);
+        line.clear();
+    }
+    Ok(counts)
+}
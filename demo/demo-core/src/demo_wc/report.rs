@@ -0,0 +1,17 @@
+//! Printing the counts as `wc` does
+
+use super::parser::Show;
+use super::reader::Counts;
+
+/// A row of the output: each count `show` asks for, right-aligned, then the file's
+/// name if there is one.
+///
+/// `pub(super)` makes this visible to the `demo_wc` module, which prints the rows,
+/// and nowhere else. C# has nothing quite like it - the nearest is `internal`
+/// within an assembly of its own.
+pub(super) fn row(show: &Show, counts: &Counts, name: Option<&str>) -> String {
+    let columns = [(show.lines, counts.lines), (show.words, counts.words), (show.chars, counts.chars), (show.bytes, counts.bytes)];
+    let mut row: Vec<String> = columns.iter().filter(|(show, _)| *show).map(|(_, n)| format!("{:>7}", n)).collect();
+    row.extend(name.map(String::from));
+    row.join(" ")
+}
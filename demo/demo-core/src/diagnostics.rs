@@ -0,0 +1,145 @@
+//! Errors and warnings, printed to stderr in colour where the terminal shows it.
+//!
+//! What a demo works out goes to stdout, plain, with `println!`. What went wrong
+//! goes to stderr, so `demo run 6 numbers.txt > out.txt` still shows the errors
+//! and leaves them out of the file - `Console.Error` rather than `Console.Out`.
+//!
+//! anstream's `eprintln!` writes the colour codes when stderr is a terminal, and
+//! strips them when it's a file or a pipe, or when `NO_COLOR` is set - so nothing
+//! here has to check. `CLICOLOR_FORCE` turns them back on for a pipe.
+
+use std::backtrace::{ Backtrace, BacktraceStatus };
+#[cfg(feature = "tracing")]
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+#[cfg(feature = "tracing")]
+use std::io;
+
+#[cfg(feature = "tracing")]
+use anstream::AutoStream;
+use anstream::{ eprintln, ColorChoice };
+use anstyle::{ AnsiColor, Style };
+#[cfg(feature = "tracing")]
+use tracing_subscriber::EnvFilter;
+#[cfg(feature = "tracing")]
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// How bad a diagnostic is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// Something which stopped a demo
+    Error,
+    /// Something a demo skipped over and carried on
+    Warning
+}
+
+impl Severity {
+    /// What the diagnostic starts with
+    pub fn label(self) -> &'static str {
+        match self {
+            Severity::Error   => "Error",
+            Severity::Warning => "Warning"
+        }
+    }
+
+    /// How the label is shown: errors in red and warnings in yellow
+    pub fn style(self) -> Style {
+        let color = match self {
+            Severity::Error   => AnsiColor::Red,
+            Severity::Warning => AnsiColor::Yellow
+        };
+        color.on_default().bold()
+    }
+}
+
+/// Prints `message` to stderr, with a label saying how bad it is: `Error: ...`
+pub fn report(severity: Severity, message: impl Display) {
+    let style = severity.style();
+    // `{:#}` on a Style is the code which resets it
+    eprintln!("{}{}:{:#} {}", style, severity.label(), style, message);
+}
+
+/// Prints `message` as an error
+pub fn error(message: impl Display) {
+    report(Severity::Error, message);
+}
+
+/// Prints `message` as a warning
+pub fn warning(message: impl Display) {
+    report(Severity::Warning, message);
+}
+
+/// Prints a line which belongs to the diagnostic before it, indented under it
+pub fn detail(message: impl Display) {
+    eprintln!("  {}", message);
+}
+
+/// Prints `e` as an error, then each error which caused it, each indented under
+/// the one before.
+///
+/// This works for any `std::error::Error`. Each can point at the error which
+/// caused it, like `InnerException` in C#, and `source` follows the pointer -
+/// the nesting is the `--->` in an `Exception.ToString()`.
+pub fn error_chain(e: &dyn Error) {
+    error(e);
+    let mut depth = 1;
+    let mut cause = e.source();
+    while let Some(e) = cause {
+        eprintln!("{:indent$}Caused by: {}", "", e, indent = depth * 2);
+        depth += 1;
+        cause = e.source();
+    }
+}
+
+/// Prints `e` and its causes, as `error_chain` does, followed by a backtrace when
+/// `RUST_BACKTRACE` (or `RUST_LIB_BACKTRACE`) asks for one. This is what a demo
+/// does with an error it can't carry on from.
+///
+/// A `dyn Error` doesn't carry a backtrace of where it was created the way an
+/// exception's `StackTrace` does, so this is the stack where it was reported -
+/// which says which demo gave up, if not which line of it went wrong. anyhow takes
+/// one when the error is created (see demo_anyhow.rs).
+pub fn report_error(e: &dyn Error) {
+    error_chain(e);
+    let backtrace = Backtrace::capture();
+    if backtrace.status() == BacktraceStatus::Captured {
+        detail("Backtrace:");
+        eprintln!("{}", backtrace);
+    }
+}
+
+/// Prints everything after this without colour, whatever the terminal can show -
+/// for `--no-color`
+pub fn disable_color() {
+    ColorChoice::Never.write_global();
+}
+
+/// Sends the library's log messages to stderr, at the levels `RUST_LOG` asks for
+/// (see demo_logging.rs).
+///
+/// The library only logs; it's a binary which decides where the messages go, by
+/// calling this once before anything is logged. The runner and the examples both
+/// do, so a demo logs the same whichever runs it.
+#[cfg(not(feature = "tracing"))]
+pub fn init_logging() {
+    env_logger::init();
+}
+
+/// With the tracing feature, a tracing subscriber takes the log messages as well as
+/// the spans (see demo_tracing.rs). `RUST_LOG` picks the levels as before, and
+/// `DEMO_LOG_FORMAT=json` prints one JSON object a line, with each message's fields
+/// and the spans it's in. It's in colour when the diagnostics are.
+#[cfg(feature = "tracing")]
+pub fn init_logging() {
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(AutoStream::choice(&io::stderr()) != ColorChoice::Never)
+        .with_writer(io::stderr);
+    if env::var_os("DEMO_LOG_FORMAT").is_some_and(|format| format == "json") {
+        subscriber.json().init();
+    } else {
+        subscriber.init();
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+
+use crate::ReadError;
+
+/// The text encodings the shared reader understands
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be
+}
+
+impl Encoding {
+    /// Sniffs the byte order mark at the start of `bytes`, returning the encoding
+    /// it identifies and the length of the mark. Like C#'s `StreamReader`, text
+    /// without a byte order mark is assumed to be UTF-8.
+    pub fn detect(bytes: &[u8]) -> (Encoding, usize) {
+        if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            (Encoding::Utf8, 3)
+        } else if bytes.starts_with(&[0xFF, 0xFE]) {
+            (Encoding::Utf16Le, 2)
+        } else if bytes.starts_with(&[0xFE, 0xFF]) {
+            (Encoding::Utf16Be, 2)
+        } else {
+            (Encoding::Utf8, 0)
+        }
+    }
+
+    /// Decodes `bytes` (without a byte order mark) as text in this encoding,
+    /// or None if they aren't valid
+    pub fn decode(self, bytes: &[u8]) -> Option<String> {
+        match self {
+            Encoding::Utf8 => String::from_utf8(bytes.to_vec()).ok(),
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                if !bytes.len().is_multiple_of(2) {
+                    None
+                } else {
+                    String::from_utf16(&utf16_units(bytes, self)).ok()
+                }
+            }
+        }
+    }
+
+    /// Decodes `bytes` (without a byte order mark) as text in this encoding,
+    /// replacing anything invalid with U+FFFD
+    pub fn decode_lossy(self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                let mut text = String::from_utf16_lossy(&utf16_units(bytes, self));
+                // A trailing odd byte is half a code unit
+                if !bytes.len().is_multiple_of(2) {
+                    text.push('\u{FFFD}');
+                }
+                text
+            }
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Encoding::Utf8    => write!(f, "UTF-8"),
+            Encoding::Utf16Le => write!(f, "UTF-16LE"),
+            Encoding::Utf16Be => write!(f, "UTF-16BE")
+        }
+    }
+}
+
+/// Decodes `bytes` as text, using the byte order mark (if any) to pick the encoding.
+///
+/// A Rust `String` is always valid UTF-8, so UTF-16 text is converted as it's decoded.
+/// Invalid data is an error - see `decode_lossy` for the forgiving version.
+pub fn decode(bytes: &[u8]) -> Result<String, ReadError> {
+    let (encoding, bom_len) = Encoding::detect(bytes);
+    encoding.decode(&bytes[bom_len..]).ok_or(ReadError::Decode(encoding))
+}
+
+/// Decodes `bytes` as text like `decode`, but replaces anything invalid with
+/// U+FFFD (the replacement character) rather than failing - which is what C#'s
+/// `StreamReader` does without telling you.
+pub fn decode_lossy(bytes: &[u8]) -> String {
+    let (encoding, bom_len) = Encoding::detect(bytes);
+    encoding.decode_lossy(&bytes[bom_len..])
+}
+
+// Pairs up bytes into 16 bit code units, in the given byte order
+fn utf16_units(bytes: &[u8], encoding: Encoding) -> Vec<u16> {
+    bytes.chunks(2)
+        .filter(|pair| pair.len() == 2)
+        .map(|pair| match encoding {
+            Encoding::Utf16Be => u16::from_be_bytes([pair[0], pair[1]]),
+            _                 => u16::from_le_bytes([pair[0], pair[1]])
+        })
+        .collect()
+}
@@ -0,0 +1,72 @@
+//! Process exit codes for each category of failure, so scripts can tell them apart.
+//!
+//! The values follow the BSD `sysexits.h` convention.
+
+use std::process::{ ExitCode, Termination };
+
+use crate::ReadError;
+use crate::registry::DemoError;
+
+/// Everything worked
+pub const SUCCESS: i32 = 0;
+/// The program was invoked with arguments it didn't understand
+pub const USAGE: i32 = 64;
+/// The input couldn't be parsed or decoded, or held numbers too big to work with
+/// or values it shouldn't
+pub const PARSE: i32 = 65;
+/// Something went wrong which is a bug in the demos themselves, or in how they
+/// set themselves up
+pub const SOFTWARE: i32 = 70;
+/// The input couldn't be read, or the output couldn't be written
+pub const IO: i32 = 74;
+/// The input took too long to read. This might not happen next time, so it's
+/// sysexits' temporary failure, which invites the caller to try again
+pub const TIMED_OUT: i32 = 75;
+/// The runner's settings, from demo.toml or the environment, are wrong
+pub const CONFIG: i32 = 78;
+/// The program was interrupted with Ctrl-C. Shells report a process killed by
+/// a signal as 128 plus the signal's number, and SIGINT is 2
+pub const INTERRUPTED: i32 = 130;
+
+/// The exit code for a `ReadError`
+pub fn for_read_error<E>(e: &ReadError<E>) -> i32 {
+    match *e {
+        ReadError::Io(_) => IO,
+        ReadError::Parse(_) | ReadError::BadLine(_) | ReadError::Decode(_) | ReadError::LineTooLong(..) => PARSE,
+        ReadError::TimedOut(_) => TIMED_OUT,
+        ReadError::InFile(_, ref e) => for_read_error(e)
+    }
+}
+
+/// The exit code for a `DemoError`
+pub fn for_demo_error(e: &DemoError) -> i32 {
+    match *e {
+        DemoError::Usage(_) => USAGE,
+        DemoError::Read(ref e) => for_read_error(e),
+        DemoError::Write(_) => IO,
+        DemoError::Parse(ref e) => for_read_error(e),
+        DemoError::Mismatch(_) => SOFTWARE,
+        DemoError::Cancelled   => INTERRUPTED,
+        DemoError::Handler(_)  => SOFTWARE,
+        DemoError::Config(_)   => CONFIG,
+        DemoError::Overflow(_) => PARSE,
+        DemoError::Invalid(_)  => PARSE
+    }
+}
+
+/// The result of running a demo, which `main` can return directly.
+///
+/// `main` may return any type which implements `std::process::Termination`. The
+/// standard library implements it for `Result`, but any error exits with 1.
+/// Implementing it ourselves gives each kind of failure its own code.
+pub struct Exit(pub Result<(), DemoError>);
+
+impl Termination for Exit {
+    fn report(self) -> ExitCode {
+        let code = match self.0 {
+            Ok(()) => SUCCESS,
+            Err(ref e) => for_demo_error(e)
+        };
+        ExitCode::from(code as u8)
+    }
+}
@@ -0,0 +1,21 @@
+//! The `head` command, which prints the first few lines of a file.
+
+use crate::{ open_input, LineStream, ReadError };
+use crate::registry::DemoError;
+
+/// How many lines to print unless `-n` says otherwise
+pub const DEFAULT_LINES: usize = 10;
+
+/// Runs the `head` command: prints the first `count` lines of `file_name`, or of
+/// stdin if it's `None`.
+///
+/// This is the easy end. A `LineStream` only reads as far as it's asked to, so
+/// `take` stops the reading as well as the printing - `File.ReadLines(path).Take(n)`.
+pub fn run(count: usize, file_name: Option<&str>) -> Result<(), DemoError> {
+    let in_file = |e: ReadError| e.in_input(file_name);
+    let read = open_input(file_name).map_err(|e| in_file(ReadError::from(e)))?;
+    for line in LineStream::new(read).take(count) {
+        println!("{}", line.map_err(in_file)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! The `hist` command: sorts a file's numbers into buckets of equal width, and draws
+//! a bar for each.
+//!
+//! Everything is done in integers. The buckets have to cover every number from the
+//! smallest to the largest, so their width is rounded up, and the bars are scaled
+//! so that the fullest bucket's is exactly as wide as asked for.
+
+use crate::demo_structs::NumberFile;
+use crate::registry::DemoError;
+
+/// How many buckets there are unless `--buckets` says otherwise
+pub const DEFAULT_BUCKETS: u64 = 10;
+
+/// How wide the longest bar is unless `--width` says otherwise
+pub const DEFAULT_WIDTH: usize = 40;
+
+/// A range of numbers, and how many of the input fell into it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64,
+    /// The last number in the bucket, not one past it - so that a bucket can end
+    /// at u64::MAX
+    pub end: u64,
+    pub count: usize
+}
+
+/// Splits the range from the smallest number to the largest into `count` buckets,
+/// and counts the numbers in each.
+///
+/// The width is worked out in u128, since every u64 from 0 to u64::MAX is one more
+/// than a u64 can hold. There may be fewer than `count` buckets, when there are
+/// fewer different numbers which could go in them.
+pub fn buckets(numbers: &[u64], count: u64) -> Vec<Bucket> {
+    let (min, max) = match (numbers.iter().min(), numbers.iter().max()) {
+        (Some(&min), Some(&max)) => (min, max),
+        _ => return Vec::new()
+    };
+    let span = u128::from(max - min) + 1;
+    let width = span.div_ceil(u128::from(count.max(1)));
+    let used = span.div_ceil(width) as usize;
+
+    // Each number's bucket is how many widths it is past the smallest
+    let counts = numbers.iter().fold(vec![0; used], |mut counts, &n| {
+        counts[(u128::from(n - min) / width) as usize] += 1;
+        counts
+    });
+    counts.into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let start = u128::from(min) + i as u128 * width;
+            let end = (start + width - 1).min(u128::from(max));
+            Bucket { start: start as u64, end: end as u64, count }
+        })
+        .collect()
+}
+
+/// Draws each bucket as a line of `#`s, the fullest `width` long.
+///
+/// Rounding down would leave a bucket with only a few numbers in it looking empty,
+/// so any bucket with something in it gets at least one `#`.
+pub fn render(buckets: &[Bucket], width: usize) -> Vec<String> {
+    let fullest = buckets.iter().map(|bucket| bucket.count).max().unwrap_or(0).max(1);
+    let digits = buckets.iter().map(|bucket| bucket.end.to_string().len()).max().unwrap_or(1);
+    buckets.iter()
+        .map(|bucket| {
+            let bar = match bucket.count * width / fullest {
+                0 if bucket.count > 0 => 1,
+                len => len
+            };
+            format!("{:>digits$} - {:>digits$} | {:<width$} {}", bucket.start, bucket.end, "#".repeat(bar), bucket.count, digits = digits, width = width)
+        })
+        .collect()
+}
+
+/// Runs the `hist` command: `count` buckets for the numbers in `file_name`, with
+/// bars at most `width` wide. Both must be above 0, which the runner's parser checks.
+pub fn run(file_name: &str, count: u64, width: usize) -> Result<(), DemoError> {
+    let file = NumberFile::open(file_name)?;
+
+    for line in render(&buckets(file.numbers(), count), width) {
+        println!("{}", line);
+    }
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! The demos, and the code they share.
+//!
+//! The `demo` binary is only a thin runner over what this library exposes,
+//! the same way a C# console app might sit on top of a class library.
+
+#![cfg_attr(feature = "nightly", feature(coroutines, iter_from_coroutine))]
+
+extern crate anstream;
+extern crate anstyle;
+extern crate anyhow;
+extern crate ctrlc;
+extern crate csv;
+extern crate env_logger;
+extern crate envy;
+extern crate glob;
+extern crate indicatif;
+extern crate itertools;
+extern crate log;
+extern crate notify;
+extern crate regex;
+extern crate serde;
+extern crate serde_json;
+extern crate thiserror;
+extern crate unicode_normalization;
+extern crate unicode_segmentation;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "async")]
+extern crate futures;
+#[cfg(feature = "async")]
+extern crate tokio;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+#[cfg(feature = "tracing")]
+extern crate tracing_subscriber;
+#[cfg(test)]
+extern crate tempfile;
+
+pub mod args;
+pub mod compare;
+pub mod diagnostics;
+pub mod exit_codes;
+pub mod head;
+pub mod hist;
+pub mod registry;
+pub mod tail;
+
+pub mod encoding;
+pub mod literal;
+pub mod numbers;
+pub mod progress;
+pub mod retry;
+pub mod storage;
+pub mod stats;
+pub mod str_ext;
+pub mod validated;
+pub mod accumulate;
+#[cfg(feature = "async")]
+pub mod async_read;
+
+mod read;
+
+pub use crate::read::{ open_input, process_file, read_input_lines, read_input_numbers, read_lines, read_lines_all, read_lines_from, read_lines_from_storage, read_lines_from_with, read_lines_with, read_lines_with_mode, read_with_timeout, AnyParseError, LineStream, ReadError, ReadMode, ReadOptions };
+
+pub mod demo0;
+pub mod demo1;
+pub mod demo2;
+pub mod demo3;
+pub mod demo4;
+pub mod demo5;
+pub mod demo6;
+pub mod demo7;
+#[cfg(feature = "async")]
+pub mod demo8;
+
+pub mod demo_streaming;
+pub mod demo_walk;
+pub mod demo_encoding;
+pub mod demo_lenient;
+pub mod demo_threads;
+pub mod demo_pipeline;
+pub mod demo_shared;
+pub mod demo_cancel;
+pub mod demo_timeout;
+pub mod demo_atomics;
+pub mod demo_scoped;
+pub mod demo_thread_local;
+pub mod demo_linq;
+pub mod demo_lazy;
+pub mod demo_iterator;
+pub mod demo_generator;
+pub mod demo_option;
+pub mod demo_ownership;
+pub mod demo_borrowing;
+pub mod demo_lifetimes;
+pub mod demo_traits;
+pub mod demo_dispatch;
+pub mod demo_generics;
+pub mod demo_storage;
+pub mod demo_enums;
+pub mod demo_patterns;
+pub mod demo_structs;
+pub mod demo_drop;
+pub mod demo_rc;
+pub mod demo_refcell;
+pub mod demo_pointers;
+pub mod demo_closures;
+pub mod demo_process;
+pub mod demo_conversions;
+pub mod demo_validate;
+pub mod demo_units;
+pub mod demo_defaults;
+pub mod demo_extensions;
+pub mod demo_equality;
+pub mod demo_formatting;
+pub mod demo_strings;
+pub mod demo_unicode;
+pub mod demo_collections;
+pub mod demo_entry;
+pub mod demo_slices;
+pub mod demo_sorting;
+pub mod demo_wc;
+pub mod demo_grep;
+pub mod demo_dedup;
+pub mod demo_csv;
+pub mod demo_json;
+pub mod demo_env;
+pub mod demo_logging;
+pub mod demo_anyhow;
+pub mod demo_thiserror;
+pub mod demo_panics;
+pub mod demo_retry;
+pub mod demo_accumulate;
+#[cfg(feature = "rayon")]
+pub mod demo_parallel;
+#[cfg(feature = "async")]
+pub mod demo_async_stream;
+#[cfg(feature = "tracing")]
+pub mod demo_tracing;
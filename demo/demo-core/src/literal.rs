@@ -0,0 +1,88 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::numbers::CheckedAdd;
+
+/// A number written the way Rust (and C#) source code allows: decimal, hex with
+/// a `0x` prefix or binary with `0b`, with `_` separating digits for readability.
+///
+/// `0x1F`, `0b1010` and `1_000_000` all parse. Implementing `FromStr` means it can
+/// be used with `str::parse` - and anything generic over `T: FromStr` - just like `u64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Literal(pub u64);
+
+/// The ways a number literal can be wrong.
+///
+/// `ParseIntError` can only tell us what kind of problem it found. Because we
+/// check the digits ourselves, we can say which digit was the problem too.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LiteralError {
+    /// There were no digits at all, only (at most) a prefix
+    Empty,
+    /// A character which isn't a digit in the number's base
+    InvalidDigit(char, u32),
+    /// A `_` at the start or end of the digits, or two in a row
+    MisplacedSeparator,
+    /// The number is too large for a `u64`
+    Overflow
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LiteralError::Empty                  => write!(f, "no digits"),
+            LiteralError::InvalidDigit(c, radix) => write!(f, "invalid digit '{}' for a base {} number", c, radix),
+            LiteralError::MisplacedSeparator     => write!(f, "'_' can only go between digits"),
+            LiteralError::Overflow               => write!(f, "number too large to fit in 64 bits")
+        }
+    }
+}
+
+impl Error for LiteralError {}
+
+impl FromStr for Literal {
+    type Err = LiteralError;
+
+    fn from_str(s: &str) -> Result<Literal, LiteralError> {
+        let s = s.trim();
+        let (radix, digits) = if let Some(rest) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            (16, rest)
+        } else if let Some(rest) = s.strip_prefix("0b").or_else(|| s.strip_prefix("0B")) {
+            (2, rest)
+        } else {
+            (10, s)
+        };
+
+        if digits.is_empty() {
+            return Err(LiteralError::Empty);
+        }
+        if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+            return Err(LiteralError::MisplacedSeparator);
+        }
+        let digits: String = digits.chars().filter(|&c| c != '_').collect();
+        if let Some(c) = digits.chars().find(|c| !c.is_digit(radix)) {
+            return Err(LiteralError::InvalidDigit(c, radix));
+        }
+
+        // Only digits are left, so the one thing from_str_radix can still
+        // object to is the size of the number
+        u64::from_str_radix(&digits, radix)
+            .map(Literal)
+            .map_err(|_| LiteralError::Overflow)
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// Adding two literals can overflow as parsing one can, so the sum is only there if
+// it fits in a u64
+impl CheckedAdd for Literal {
+    fn checked_add(self, other: Literal) -> Option<Literal> {
+        self.0.checked_add(other.0).map(Literal)
+    }
+}
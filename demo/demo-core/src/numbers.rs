@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::fmt;
+use std::num;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::{ read_lines, AnyParseError, ReadError };
+
+/// A line (or a value on a line) which couldn't be parsed, and where it was.
+///
+/// `E` is the error from parsing the line, which depends on what it was being parsed as.
+#[derive(Debug)]
+pub struct LineError<E = num::ParseIntError> {
+    /// The line number, starting from 1
+    pub line: usize,
+    /// The column the value started at, starting from 1, if the line held several
+    pub column: Option<usize>,
+    /// The text which didn't parse - the whole line, or the one value from it
+    pub text: String,
+    pub error: E
+}
+
+impl<E: fmt::Display> LineError<E> {
+    /// Describes what was wrong with the line, without saying where it was
+    pub fn problem(&self) -> String {
+        format!("{}: '{}'", self.error, self.text.trim())
+    }
+
+    /// The line number, followed by the column if there is one, e.g. `17` or `17:5`
+    pub fn position(&self) -> String {
+        match self.column {
+            Some(column) => format!("{}:{}", self.line, column),
+            None         => format!("{}", self.line)
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> LineError<E> {
+    /// This error with its parse error boxed, as `ReadError::boxed` does
+    pub fn boxed(self) -> LineError<AnyParseError> {
+        LineError { line: self.line, column: self.column, text: self.text, error: AnyParseError(Box::new(self.error)) }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for LineError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {}: {}", self.line, column, self.problem()),
+            None         => write!(f, "line {}: {}", self.line, self.problem())
+        }
+    }
+}
+
+// The parse error's message is already part of ours, so there's no separate source
+impl<E: fmt::Debug + fmt::Display> Error for LineError<E> {}
+
+/// Parses a single line of input as a `T` - anything which implements `FromStr`,
+/// which is Rust's version of C#'s `IParsable<T>`. `line` is the line's number,
+/// which is only used to say where the problem was if it doesn't parse.
+// A span for every line would drown out everything else, so it's at trace level
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(text)))]
+pub fn parse_line<T: FromStr>(line: usize, text: &str) -> Result<T, LineError<T::Err>> {
+    text.trim().parse().map_err(|error| LineError { line, column: None, text: text.to_string(), error })
+}
+
+/// Addition which says when the answer is too big for the type, rather than
+/// panicking or wrapping round - what C#'s `checked` does for `+`.
+///
+/// Each integer type has a `checked_add` method of its own, but std has no trait
+/// for it, so this is what lets generic code ask for one.
+pub trait CheckedAdd: Sized {
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+impl CheckedAdd for u64 {
+    fn checked_add(self, other: u64) -> Option<u64> {
+        u64::checked_add(self, other)
+    }
+}
+
+impl CheckedAdd for i64 {
+    fn checked_add(self, other: i64) -> Option<i64> {
+        i64::checked_add(self, other)
+    }
+}
+
+// A float doesn't overflow: past the largest f64, it's infinity
+impl CheckedAdd for f64 {
+    fn checked_add(self, other: f64) -> Option<f64> {
+        Some(self + other)
+    }
+}
+
+/// What separates the values on a line, when there's more than one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any amount of whitespace, like `string.Split()` with no arguments in C#
+    Whitespace,
+    /// A single character, such as `,`. Whitespace around each value is ignored.
+    Char(char)
+}
+
+/// Splits `text` into values, along with the column (starting from 1) each one starts at
+pub fn tokens(text: &str, delimiter: Delimiter) -> Vec<(usize, &str)> {
+    let tokens: Vec<&str> = match delimiter {
+        Delimiter::Whitespace => text.split_whitespace().collect(),
+        Delimiter::Char(c)    => text.split(c).map(|token| token.trim()).collect()
+    };
+    // Each token is a slice of text, pointing into the same memory - so how far
+    // its pointer is past the start of text is its byte offset in the line
+    tokens.into_iter()
+        .map(|token| {
+            let offset = token.as_ptr() as usize - text.as_ptr() as usize;
+            (text[..offset].chars().count() + 1, token)
+        })
+        .collect()
+}
+
+/// Parses every value on every line, stopping at the first which doesn't parse.
+///
+/// `lines` are numbered, as `read_lines_with` returns them. Each line turns into any
+/// number of values, and `flat_map` joins them all up into one sequence.
+pub fn parse_tokens<T: FromStr>(lines: &[(usize, String)], delimiter: Delimiter) -> Result<Vec<T>, LineError<T::Err>> {
+    lines.iter()
+        .flat_map(|&(line, ref text)| {
+            tokens(text, delimiter).into_iter().map(move |(column, token)| {
+                token.parse().map_err(|error| LineError { line, column: Some(column), text: token.to_string(), error })
+            })
+        })
+        .collect()
+}
+
+/// Reads a file of numbers, one per line, skipping over lines which don't parse.
+///
+/// The numbers which did parse come back along with an error for each line which
+/// didn't - the Rust version of calling `TryParse` in a loop in C#. Only failing
+/// to read the file at all is an error.
+pub fn read_numbers_lenient<P: AsRef<Path>>(path: P) -> Result<(Vec<u64>, Vec<LineError>), ReadError> {
+    let lines = read_lines(path)?;
+    Ok(parse_numbers_lenient(&lines))
+}
+
+/// Parses each line as a number like `read_numbers_lenient`, for lines which have
+/// already been read
+pub fn parse_numbers_lenient(lines: &[String]) -> (Vec<u64>, Vec<LineError>) {
+    let mut numbers = Vec::new();
+    let mut errors = Vec::new();
+    for (i, text) in lines.iter().enumerate() {
+        match parse_line(i + 1, text) {
+            Ok(n)  => numbers.push(n),
+            Err(e) => errors.push(e)
+        }
+    }
+    (numbers, errors)
+}
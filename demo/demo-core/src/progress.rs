@@ -0,0 +1,90 @@
+//! A progress bar for demos which read large inputs, shown on stderr.
+//!
+//! The bar counts the bytes as they're read, not the lines: the file's length is
+//! known before reading starts, so the bar can say how far through it is, how fast
+//! it's going and how long is left. The reader is wrapped, so whatever reads from it -
+//! a `LineStream`, `read_lines_from` - moves the bar without knowing it's there.
+//!
+//! indicatif draws the bar on stderr only when stderr is a terminal, so piped or
+//! redirected output is the same with or without it. It redraws on a thread of its
+//! own too, so the bar keeps ticking while a read is waiting on a slow disk or pipe -
+//! `IProgress<T>` reported to from the reading loop, with the UI thread left free.
+
+use std::fs::File;
+use std::io::{ self, BufRead, BufReader, Read };
+use std::time::Duration;
+
+use indicatif::{ ProgressBar, ProgressStyle };
+
+use crate::ReadError;
+
+/// How often the bar is redrawn, whether or not anything has been read since
+const TICK: Duration = Duration::from_millis(100);
+
+/// A bar for reading `len` bytes, or a spinner when the length isn't known, as it
+/// isn't for stdin. Nothing is drawn until the first tick.
+pub fn bar(len: Option<u64>) -> ProgressBar {
+    let bar = match len {
+        Some(len) => ProgressBar::new(len).with_style(style(
+            "{spinner} [{bar:30}] {binary_bytes}/{binary_total_bytes} ({binary_bytes_per_sec}, {eta} left)"
+        )),
+        None => ProgressBar::new_spinner().with_style(style(
+            "{spinner} {binary_bytes} ({binary_bytes_per_sec})"
+        ))
+    };
+    bar.enable_steady_tick(TICK);
+    bar
+}
+
+/// Wraps `read` so that every byte read through it moves `bar` on
+pub fn track<R: Read + 'static>(read: R, bar: &ProgressBar) -> Box<dyn BufRead> {
+    // The bar counts the bytes as the BufReader takes them, a buffer at a time
+    Box::new(BufReader::new(bar.wrap_read(read)))
+}
+
+/// Opens a file, or stdin when there's no file name, as `open_input` does - along
+/// with a bar which the reading moves on
+pub fn open_input(file_name: Option<&str>) -> io::Result<(Box<dyn BufRead>, ProgressBar)> {
+    match file_name {
+        Some(file_name) => {
+            let file = File::open(file_name)?;
+            let bar = bar(Some(file.metadata()?.len()));
+            Ok((track(file, &bar), bar))
+        },
+        None => {
+            let bar = bar(None);
+            Ok((track(io::stdin(), &bar), bar))
+        }
+    }
+}
+
+/// Takes `--progress` out of a demo's arguments, wherever it is, and says whether
+/// it was there. What's left is for the demo to make sense of as it always has
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let (flags, rest): (Vec<String>, Vec<String>) = args.iter().cloned().partition(|arg| arg == "--progress");
+    (!flags.is_empty(), rest)
+}
+
+/// Opens a file, or stdin, and hands it to `read` - with a bar on stderr while it
+/// reads if `with_progress`, or as the crate's `open_input` would if not. The bar is
+/// cleared once `read` is done, or left where it stopped, above the error, if it fails.
+pub fn read_input<T, F>(file_name: Option<&str>, with_progress: bool, read: F) -> Result<T, ReadError>
+    where F: FnOnce(Box<dyn BufRead>) -> Result<T, ReadError>
+{
+    let (input, bar) = if with_progress {
+        open_input(file_name)?
+    } else {
+        (crate::open_input(file_name)?, ProgressBar::hidden())
+    };
+    let result = read(input);
+    match result {
+        Ok(_)  => bar.finish_and_clear(),
+        Err(_) => bar.abandon()
+    }
+    result
+}
+
+// The templates are fixed, so one which doesn't parse is a bug here
+fn style(template: &str) -> ProgressStyle {
+    ProgressStyle::with_template(template).expect("progress template").progress_chars("=> ")
+}
@@ -0,0 +1,663 @@
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{ BufRead, BufReader, Read };
+use std::io;
+use std::num;
+use std::path::{ Path, PathBuf };
+use std::panic;
+use std::sync::mpsc::{ self, RecvTimeoutError };
+use std::thread;
+use std::time::Duration;
+
+use crate::encoding::{ decode, decode_lossy, Encoding };
+use crate::numbers::{ parse_line, LineError };
+use crate::storage::Storage;
+use crate::str_ext::StrExt;
+
+/// An enumeration of the possible errors we'll encounter while reading input.
+///
+/// `E` is the error we get when a value doesn't parse. It defaults to the one for
+/// integers, so plain `ReadError` is what most code wants.
+#[derive(Debug)]
+pub enum ReadError<E = num::ParseIntError> {
+    Io(io::Error),
+    Parse(E),
+    /// A value which couldn't be parsed, along with the line it was on
+    BadLine(LineError<E>),
+    /// The input wasn't valid text in the encoding it appeared to be in
+    Decode(Encoding),
+    /// The line with this number was longer than the maximum length given
+    LineTooLong(usize, usize),
+    /// The read was given up on after this long
+    TimedOut(Duration),
+    /// One of the above, attributed to the file it came from
+    InFile(PathBuf, Box<ReadError<E>>)
+}
+
+impl<E> ReadError<E> {
+    /// Attributes this error to the file at `path`
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> ReadError<E> {
+        ReadError::InFile(path.as_ref().to_path_buf(), Box::new(self))
+    }
+
+    /// Attributes this error to the file at `path`, if there is one. With no path
+    /// the input was stdin, and the error is left as it is.
+    pub fn in_input<P: AsRef<Path>>(self, path: Option<P>) -> ReadError<E> {
+        match path {
+            Some(path) => self.in_file(path),
+            None       => self
+        }
+    }
+}
+
+impl ReadError {
+    /// A reader's error, as a `ReadError<E>` for code which parses what it read as
+    /// something other than an integer, as demo 6 can.
+    ///
+    /// The readers only read, so their errors are never `Parse` or `BadLine`, and
+    /// everything else carries over as it is.
+    pub fn unparsed<E>(self) -> ReadError<E> {
+        match self {
+            ReadError::Io(e)                => ReadError::Io(e),
+            ReadError::Decode(encoding)     => ReadError::Decode(encoding),
+            ReadError::LineTooLong(line, max) => ReadError::LineTooLong(line, max),
+            ReadError::TimedOut(timeout)    => ReadError::TimedOut(timeout),
+            ReadError::InFile(path, e)      => ReadError::InFile(path, Box::new(e.unparsed())),
+            ReadError::Parse(_) | ReadError::BadLine(_) => unreachable!("the readers don't parse")
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> ReadError<E> {
+    /// This error with its parse error boxed, so that code which parses values of
+    /// several types - demo 6 can parse six - can return one error type for them all
+    pub fn boxed(self) -> ReadError<AnyParseError> {
+        match self {
+            ReadError::Io(e)                => ReadError::Io(e),
+            ReadError::Parse(e)             => ReadError::Parse(AnyParseError(Box::new(e))),
+            ReadError::BadLine(e)           => ReadError::BadLine(e.boxed()),
+            ReadError::Decode(encoding)     => ReadError::Decode(encoding),
+            ReadError::LineTooLong(line, max) => ReadError::LineTooLong(line, max),
+            ReadError::TimedOut(timeout)    => ReadError::TimedOut(timeout),
+            ReadError::InFile(path, e)      => ReadError::InFile(path, Box::new(e.boxed()))
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The underlying error is reported by `source`, so it isn't repeated here
+        match *self {
+            ReadError::Io(_)    => write!(f, "could not read file"),
+            ReadError::Parse(_) => write!(f, "could not parse number"),
+            ReadError::Decode(encoding) => write!(f, "file is not valid {}", encoding),
+            ReadError::LineTooLong(line, max) => write!(f, "line {} is longer than {} characters", line, max),
+            ReadError::TimedOut(timeout) => write!(f, "timed out after {}ms", timeout.as_millis()),
+            ReadError::BadLine(ref err) => write!(f, "{}", err),
+            // Written the way compilers report errors, e.g. numbers.txt:17: ...
+            ReadError::InFile(ref path, ref err) => match **err {
+                ReadError::BadLine(ref err) => write!(f, "{}:{}: {}", path.display(), err.position(), err.problem()),
+                ref err => write!(f, "{}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ReadError::Io(ref err)    => Some(err),
+            ReadError::Parse(ref err) => Some(err),
+            ReadError::BadLine(ref err) => err.source(),
+            ReadError::Decode(_)      => None,
+            ReadError::LineTooLong(..) => None,
+            ReadError::TimedOut(_)    => None,
+            // The wrapped error's message is already part of ours, so skip to its cause
+            ReadError::InFile(_, ref err) => err.source()
+        }
+    }
+}
+
+/// A parse error of whatever type, boxed - the `dyn Exception` of parse errors.
+///
+/// `Box<dyn Error>` isn't an `Error` itself, so it can't be a `ReadError`'s `E`;
+/// this wrapper is, and passes the message and cause of the error inside straight
+/// through.
+#[derive(Debug)]
+pub struct AnyParseError(pub Box<dyn Error + Send + Sync>);
+
+impl fmt::Display for AnyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for AnyParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl<E> From<io::Error> for ReadError<E> {
+    fn from(e: io::Error) -> ReadError<E> {
+        ReadError::Io(e)
+    }
+}
+
+impl From<num::ParseIntError> for ReadError {
+    fn from(e: num::ParseIntError) -> ReadError {
+        ReadError::Parse(e)
+    }
+}
+
+impl<E> From<LineError<E>> for ReadError<E> {
+    fn from(e: LineError<E>) -> ReadError<E> {
+        ReadError::BadLine(e)
+    }
+}
+
+/// Reads the file at `path` as a vector of lines.
+///
+/// This is the canonical version of the `read_file` function which demos 2 to 5
+/// used to write for themselves, and now call. It understands byte order marks
+/// and UTF-16 text the way C#'s `StreamReader` does (see `encoding.rs`).
+// With the tracing feature, each read is a span of its own, at debug level so it's
+// only recorded when asked for: `RUST_LOG=demo_core=debug` (see demo_tracing.rs)
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display())))]
+pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>, ReadError> {
+    let file = File::open(path)?;
+    read_lines_from(BufReader::new(file))
+}
+
+/// Reads the file at `path` as a vector of lines like `read_lines`, choosing
+/// what to do about invalid text with `mode`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display())))]
+pub fn read_lines_with_mode<P: AsRef<Path>>(path: P, mode: ReadMode) -> Result<Vec<String>, ReadError> {
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+    let text = match mode {
+        ReadMode::Strict => decode(&bytes)?,
+        ReadMode::Lossy  => decode_lossy(&bytes)
+    };
+    Ok(split_lines(&text))
+}
+
+/// Reads every file in `paths` in turn, concatenating their lines.
+///
+/// Errors are attributed to the file they came from, and the first one stops the read.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn read_lines_all<P: AsRef<Path>>(paths: &[P]) -> Result<Vec<String>, ReadError> {
+    let files = paths.iter()
+        .map(|path| read_lines(path).map_err(|e| e.in_file(path)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(files.into_iter().flatten().collect())
+}
+
+/// Reads the file at `path`, keeping the lines `filter` accepts and turning each of
+/// them into a `T` with `map` - LINQ's `File.ReadLines(path).Where(filter).Select(map)`.
+///
+/// `impl Fn` in argument position is shorthand for a generic parameter, so each call
+/// is compiled for the exact closures it's given, and they can be inlined.
+pub fn process_file<P: AsRef<Path>, T>(path: P, filter: impl Fn(&str) -> bool, map: impl Fn(&str) -> T) -> Result<Vec<T>, ReadError> {
+    let lines = read_lines(path)?;
+    Ok(lines.iter().map(|line| &line[..]).filter(|line| filter(line)).map(map).collect())
+}
+
+/// Reads the file at `path` as a vector of lines like `read_lines`, giving up
+/// with `ReadError::TimedOut` if that takes longer than `timeout`.
+///
+/// Blocking reads can't be interrupted, so the read happens on a thread of its
+/// own while we wait for it with a time limit. A read which times out isn't
+/// stopped: the thread carries on in the background, and its result is thrown
+/// away when it finishes - just like the task behind C#'s `Task.WaitAsync`.
+pub fn read_with_timeout<P: AsRef<Path>>(path: P, timeout: Duration) -> Result<Vec<String>, ReadError> {
+    let path = path.as_ref().to_path_buf();
+    let (sender, receiver) = mpsc::channel();
+    let reader = thread::spawn(move || {
+        // Nobody is listening any more if we timed out, so a failed send is fine
+        let _ = sender.send(read_lines(path));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(ReadError::TimedOut(timeout)),
+        // The sender was dropped without sending, so the thread panicked: pass it on
+        Err(RecvTimeoutError::Disconnected) => match reader.join() {
+            Ok(()) => unreachable!("the reading thread finished without sending a result"),
+            Err(panic) => panic::resume_unwind(panic)
+        }
+    }
+}
+
+/// Reads an already open reader (such as stdin) as a vector of lines
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn read_lines_from<R: BufRead>(mut read: R) -> Result<Vec<String>, ReadError> {
+    // We can't tell how to split the input into lines until we know its encoding,
+    // so it is read in full and decoded first
+    let mut bytes = Vec::new();
+    read.read_to_end(&mut bytes)?;
+    Ok(split_lines(&decode(&bytes)?))
+}
+
+/// Reads whatever is stored under `key` in `storage` as a vector of lines.
+///
+/// Each storage has its own error type, so it has to be one a `ReadError` can be
+/// made from - the where clause says so, and `?` does the converting.
+pub fn read_lines_from_storage<S: Storage>(storage: &S, key: &str) -> Result<Vec<String>, ReadError>
+    where ReadError: From<S::Error>
+{
+    let bytes = storage.load(key)?;
+    Ok(split_lines(&decode(&bytes)?))
+}
+
+/// Reads the file at `path` as a vector of lines, the way `options` says to.
+///
+/// Each line comes back with its line number in the file, which would otherwise
+/// be thrown off by any lines which were skipped.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display())))]
+pub fn read_lines_with<P: AsRef<Path>>(path: P, options: &ReadOptions) -> Result<Vec<(usize, String)>, ReadError> {
+    let file = File::open(path)?;
+    read_lines_from_with(BufReader::new(file), options)
+}
+
+/// Reads an already open reader as a vector of numbered lines, like `read_lines_with`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn read_lines_from_with<R: BufRead>(mut read: R, options: &ReadOptions) -> Result<Vec<(usize, String)>, ReadError> {
+    let mut bytes = Vec::new();
+    read.read_to_end(&mut bytes)?;
+    options.apply(&options.decode(&bytes)?)
+}
+
+// Splits on \n or \r\n, the same as BufRead::lines
+pub(crate) fn split_lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+/// Opens the named file for reading, or stdin if no file is named.
+///
+/// This is the usual convention for console tools, so input can be piped in.
+pub fn open_input(file_name: Option<&str>) -> io::Result<Box<dyn BufRead>> {
+    match file_name {
+        Some(file_name) => {
+            let file = File::open(file_name)?;
+            Ok(Box::new(BufReader::new(file)))
+        },
+        None => Ok(Box::new(io::stdin().lock()))
+    }
+}
+
+/// Reads the named file as a vector of lines, or stdin if no file is named, with
+/// any error attributed to the file
+pub fn read_input_lines(file_name: Option<&str>) -> Result<Vec<String>, ReadError> {
+    match file_name {
+        Some(file_name) => read_lines(file_name).map_err(|e| e.in_file(file_name)),
+        None            => read_lines_from(io::stdin().lock())
+    }
+}
+
+/// Reads a `u64` from each line of the named file, or of stdin, like
+/// `read_input_lines`. It stops at the first line which doesn't parse.
+pub fn read_input_numbers(file_name: Option<&str>) -> Result<Vec<u64>, ReadError> {
+    // Select, but with a Result at each step: collecting them into a single Result
+    // stops at the first error, where LINQ would have thrown
+    read_input_lines(file_name)?
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map_err(|e| ReadError::from(e).in_input(file_name)))
+        .collect()
+}
+
+/// Options for `read_lines_with`, set up builder style - each setter takes the
+/// options by value and hands them back, so calls chain:
+/// `ReadOptions::new().skip_blank(true).trim(true).max_line_len(4096).mode(ReadMode::Lossy)`.
+///
+/// This is how Rust gets by without C#'s optional and named parameters: only the
+/// options which differ from the defaults need mentioning, by name. The defaults
+/// read like `read_lines` does, keeping every line exactly as it is.
+///
+/// The fields are public too, so struct update syntax works as well as the setters -
+/// `ReadOptions { trim: true, ..ReadOptions::default() }` - much like a C# object
+/// initializer.
+#[derive(Clone, Debug, Default)]
+pub struct ReadOptions {
+    /// Whether to skip lines which are empty or only whitespace
+    pub skip_blank: bool,
+    /// Whether to skip comment lines, which start with `#` or `//`
+    pub skip_comments: bool,
+    /// Whether to trim whitespace from both ends of each line
+    pub trim: bool,
+    /// The longest a line can be, in characters, if there's a limit
+    pub max_line_len: Option<usize>,
+    /// The encoding to decode as, or `None` to work it out from the byte order mark
+    pub encoding: Option<Encoding>,
+    /// What to do about input which isn't valid text
+    pub mode: ReadMode
+}
+
+impl ReadOptions {
+    pub fn new() -> ReadOptions {
+        ReadOptions::default()
+    }
+
+    /// Whether to skip lines which are empty or only whitespace
+    pub fn skip_blank(mut self, skip: bool) -> ReadOptions {
+        self.skip_blank = skip;
+        self
+    }
+
+    /// Whether to skip comment lines, which start with `#` or `//`
+    pub fn skip_comments(mut self, skip: bool) -> ReadOptions {
+        self.skip_comments = skip;
+        self
+    }
+
+    /// Whether to trim whitespace from both ends of each line
+    pub fn trim(mut self, trim: bool) -> ReadOptions {
+        self.trim = trim;
+        self
+    }
+
+    /// The longest a line can be, in characters. A longer line is an error.
+    pub fn max_line_len(mut self, max: usize) -> ReadOptions {
+        self.max_line_len = Some(max);
+        self
+    }
+
+    /// Decodes the input as `encoding` rather than working it out from the byte order mark
+    pub fn encoding(mut self, encoding: Encoding) -> ReadOptions {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// What to do about input which isn't valid text
+    pub fn mode(mut self, mode: ReadMode) -> ReadOptions {
+        self.mode = mode;
+        self
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, ReadError> {
+        let (detected, bom_len) = Encoding::detect(bytes);
+        let encoding = self.encoding.unwrap_or(detected);
+        // A byte order mark for some other encoding is just more (invalid) data
+        let bytes = if encoding == detected { &bytes[bom_len..] } else { bytes };
+        match self.mode {
+            ReadMode::Strict => encoding.decode(bytes).ok_or(ReadError::Decode(encoding)),
+            ReadMode::Lossy  => Ok(encoding.decode_lossy(bytes))
+        }
+    }
+
+    // Numbers the lines of text from 1, then checks, trims and skips them as asked
+    fn apply(&self, text: &str) -> Result<Vec<(usize, String)>, ReadError> {
+        let mut lines = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            if let Some(max) = self.max_line_len {
+                if line.chars().count() > max {
+                    return Err(ReadError::LineTooLong(i + 1, max));
+                }
+            }
+            let line = if self.trim { line.trim() } else { line };
+            if self.keep(line) {
+                lines.push((i + 1, line.to_string()));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn keep(&self, line: &str) -> bool {
+        !(self.skip_blank && line.is_blank() || self.skip_comments && line.is_comment())
+    }
+}
+
+/// What to do about input which isn't valid text.
+///
+/// `#[default]` tells `derive(Default)` which variant to pick, which it can't guess.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadMode {
+    /// Fail with `ReadError::Decode`
+    #[default]
+    Strict,
+    /// Replace invalid data with U+FFFD, the replacement character, and carry on
+    Lossy
+}
+
+/// A lazy stream over the lines of a reader.
+///
+/// Unlike `read_lines`, nothing is read until the next line is asked for, so
+/// memory use stays constant however large the file is - the equivalent of C#'s
+/// `File.ReadLines` rather than `File.ReadAllLines`.
+///
+/// Lines are expected to be UTF-8. Each line is checked separately, so in
+/// `ReadMode::Lossy` one bad line doesn't affect the rest.
+pub struct LineStream<R> {
+    read: R,
+    mode: ReadMode
+}
+
+impl LineStream<BufReader<File>> {
+    /// Opens the file at `path` for streaming
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<LineStream<BufReader<File>>, ReadError> {
+        let file = File::open(path)?;
+        Ok(LineStream::new(BufReader::new(file)))
+    }
+}
+
+impl<R: BufRead> LineStream<R> {
+    /// Streams the lines of an already open reader, failing on invalid text
+    pub fn new(read: R) -> LineStream<R> {
+        LineStream::with_mode(read, ReadMode::Strict)
+    }
+
+    /// Streams the lines of an already open reader, handling invalid text according to `mode`
+    pub fn with_mode(read: R, mode: ReadMode) -> LineStream<R> {
+        LineStream { read, mode }
+    }
+}
+
+impl<R: BufRead> Iterator for LineStream<R> {
+    type Item = Result<String, ReadError>;
+
+    fn next(&mut self) -> Option<Result<String, ReadError>> {
+        // BufRead::lines insists on UTF-8, so read the raw bytes of each line
+        // ourselves and decide what to do with them
+        let mut bytes = Vec::new();
+        match self.read.read_until(b'\n', &mut bytes) {
+            Ok(0) => None,
+            Ok(_) => {
+                if bytes.ends_with(b"\n") {
+                    bytes.pop();
+                    if bytes.ends_with(b"\r") {
+                        bytes.pop();
+                    }
+                }
+                Some(match self.mode {
+                    ReadMode::Strict => String::from_utf8(bytes).map_err(|_| ReadError::Decode(Encoding::Utf8)),
+                    ReadMode::Lossy  => Ok(String::from_utf8_lossy(&bytes).into_owned())
+                })
+            },
+            Err(e) => Some(Err(ReadError::from(e)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{ ErrorKind, Write };
+
+    use tempfile::{ tempdir, NamedTempFile };
+
+    use super::*;
+    use crate::numbers::parse_line;
+    use crate::storage::{ FileStorage, MemoryStorage };
+
+    // A read_file in the style of demo 7, so both kinds of error can be checked
+    fn read_file(path: &Path) -> Result<Vec<u64>, ReadError> {
+        let mut numbers = Vec::new();
+        for (i, line) in read_lines(path)?.iter().enumerate() {
+            numbers.push(parse_line(i + 1, line)?);
+        }
+        Ok(numbers)
+    }
+
+    fn temp_file(contents: &[u8]) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(contents).unwrap();
+        file
+    }
+
+    #[test]
+    fn reads_numbers() {
+        let file = temp_file(b"1\n2\r\n3");
+        assert_eq!(read_file(file.path()).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        let dir = tempdir().unwrap();
+        match read_file(&dir.path().join("missing.txt")) {
+            Err(ReadError::Io(ref e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+            other => panic!("expected an I/O error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn directory_is_an_io_error() {
+        let dir = tempdir().unwrap();
+        match read_file(dir.path()) {
+            Err(ReadError::Io(_)) => {},
+            other => panic!("expected an I/O error, got {:?}", other)
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_file_is_an_io_error() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let file = temp_file(b"1\n");
+        fs::set_permissions(file.path(), fs::Permissions::from_mode(0o000)).unwrap();
+        // Permissions don't apply to root, so there's nothing to test when running as root
+        if File::open(file.path()).is_ok() {
+            return;
+        }
+        match read_file(file.path()) {
+            Err(ReadError::Io(ref e)) => assert_eq!(e.kind(), ErrorKind::PermissionDenied),
+            other => panic!("expected an I/O error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn deleted_file_is_an_io_error() {
+        let file = temp_file(b"1\n");
+        let path = file.path().to_path_buf();
+        file.close().unwrap();
+        match read_file(&path) {
+            Err(ReadError::Io(ref e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+            other => panic!("expected an I/O error, got {:?}", other)
+        }
+    }
+
+    // On Unix, deleting a file only removes its name - a reader which already has
+    // it open carries on regardless. (Windows won't let you delete it at all.)
+    #[cfg(unix)]
+    #[test]
+    fn file_deleted_after_opening_can_still_be_read() {
+        let file = temp_file(b"1\n2\n");
+        let stream = LineStream::open(file.path()).unwrap();
+        fs::remove_file(file.path()).unwrap();
+        let lines: Result<Vec<String>, ReadError> = stream.collect();
+        assert_eq!(lines.unwrap(), vec!["1", "2"]);
+    }
+
+    #[test]
+    fn non_numeric_line_is_a_parse_error() {
+        let file = temp_file(b"1\nabc\n3\n");
+        match read_file(file.path()) {
+            Err(ReadError::BadLine(ref e)) => {
+                assert_eq!(e.line, 2);
+                assert_eq!(e.text, "abc");
+            },
+            other => panic!("expected a parse error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn parse_int_error_converts_with_question_mark() {
+        fn parse(text: &str) -> Result<u64, ReadError> {
+            Ok(text.parse()?)
+        }
+        match parse("abc") {
+            Err(ReadError::Parse(_)) => {},
+            other => panic!("expected a parse error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_is_a_decode_error() {
+        let file = temp_file(b"1\n\xff\n");
+        match read_file(file.path()) {
+            Err(ReadError::Decode(Encoding::Utf8)) => {},
+            other => panic!("expected a decode error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn errors_in_files_name_the_file() {
+        let file = temp_file(b"x\n");
+        let err = read_file(file.path()).unwrap_err().in_file("numbers.txt");
+        assert_eq!(err.to_string(), "numbers.txt:1: invalid digit found in string: 'x'");
+    }
+
+    #[test]
+    fn process_file_filters_then_maps() {
+        let file = temp_file(b"1\n\n22\n# 333\n");
+        let lens = process_file(file.path(), |line| !line.is_empty() && !line.starts_with('#'), str::len);
+        assert_eq!(lens.unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn reads_from_either_storage() {
+        let file = temp_file(b"1\n2\r\n3");
+        let (dir, name) = (file.path().parent().unwrap(), file.path().file_name().unwrap().to_str().unwrap());
+        let mut memory = MemoryStorage::new();
+        memory.store(name, fs::read(file.path()).unwrap());
+        let from_file = read_lines_from_storage(&FileStorage::new(dir), name);
+        let from_memory = read_lines_from_storage(&memory, name);
+        assert_eq!(from_file.unwrap(), vec!["1", "2", "3"]);
+        assert_eq!(from_memory.unwrap(), vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn missing_key_is_an_io_error() {
+        match read_lines_from_storage(&MemoryStorage::new(), "missing") {
+            Err(ReadError::Io(ref e)) => {
+                assert_eq!(e.kind(), ErrorKind::NotFound);
+                assert_eq!(e.to_string(), "nothing stored under 'missing'");
+            },
+            other => panic!("expected an I/O error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn read_within_timeout_succeeds() {
+        let file = temp_file(b"1\n2\n");
+        assert_eq!(read_with_timeout(file.path(), Duration::from_secs(10)).unwrap(), vec!["1", "2"]);
+    }
+
+    // Opening a named pipe blocks until something opens the other end, which
+    // nothing ever will
+    #[cfg(unix)]
+    #[test]
+    fn read_which_never_finishes_times_out() {
+        use std::process::Command;
+
+        let dir = tempdir().unwrap();
+        let fifo = dir.path().join("fifo");
+        assert!(Command::new("mkfifo").arg(&fifo).status().unwrap().success());
+        match read_with_timeout(&fifo, Duration::from_millis(100)) {
+            Err(ReadError::TimedOut(timeout)) => assert_eq!(timeout, Duration::from_millis(100)),
+            other => panic!("expected a timeout, got {:?}", other)
+        }
+    }
+}
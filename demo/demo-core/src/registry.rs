@@ -0,0 +1,260 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::{ AnyParseError, ReadError };
+
+use crate::demo0;
+use crate::demo1;
+use crate::demo2;
+use crate::demo3;
+use crate::demo4;
+use crate::demo5;
+use crate::demo6;
+use crate::demo7;
+use crate::demo_streaming;
+use crate::demo_walk;
+use crate::demo_encoding;
+use crate::demo_lenient;
+use crate::demo_threads;
+use crate::demo_pipeline;
+use crate::demo_shared;
+use crate::demo_cancel;
+use crate::demo_timeout;
+use crate::demo_atomics;
+use crate::demo_scoped;
+use crate::demo_thread_local;
+use crate::demo_linq;
+use crate::demo_lazy;
+use crate::demo_iterator;
+use crate::demo_generator;
+use crate::demo_option;
+use crate::demo_ownership;
+use crate::demo_borrowing;
+use crate::demo_lifetimes;
+use crate::demo_traits;
+use crate::demo_dispatch;
+use crate::demo_generics;
+use crate::demo_storage;
+use crate::demo_enums;
+use crate::demo_patterns;
+use crate::demo_structs;
+use crate::demo_drop;
+use crate::demo_rc;
+use crate::demo_refcell;
+use crate::demo_pointers;
+use crate::demo_closures;
+use crate::demo_process;
+use crate::demo_conversions;
+use crate::demo_validate;
+use crate::demo_units;
+use crate::demo_defaults;
+use crate::demo_extensions;
+use crate::demo_equality;
+use crate::demo_formatting;
+use crate::demo_strings;
+use crate::demo_unicode;
+use crate::demo_collections;
+use crate::demo_entry;
+use crate::demo_slices;
+use crate::demo_sorting;
+use crate::demo_wc;
+use crate::demo_grep;
+use crate::demo_dedup;
+use crate::demo_csv;
+use crate::demo_json;
+use crate::demo_env;
+use crate::demo_logging;
+use crate::demo_anyhow;
+use crate::demo_thiserror;
+use crate::demo_panics;
+use crate::demo_retry;
+use crate::demo_accumulate;
+#[cfg(feature = "async")]
+use crate::demo8;
+#[cfg(feature = "rayon")]
+use crate::demo_parallel;
+#[cfg(feature = "async")]
+use crate::demo_async_stream;
+#[cfg(feature = "tracing")]
+use crate::demo_tracing;
+
+/// A demo which knows how to describe and run itself.
+///
+/// Adding a demo to the runner is a matter of implementing this trait and
+/// listing the implementation in `all`.
+pub trait Demo {
+    /// The name used to pick the demo on the command line, e.g. `demo5`
+    fn name(&self) -> &'static str;
+
+    /// A short description of what the demo shows
+    fn description(&self) -> &'static str;
+
+    /// A one-line summary of the error handling technique the demo illustrates
+    fn technique(&self) -> &'static str;
+
+    /// Runs the demo.
+    ///
+    /// `args` follows the `env::args()` convention: the first element is the
+    /// name the demo was invoked with, and its own arguments follow.
+    fn run(&self, args: &[String]) -> Result<(), DemoError>;
+}
+
+/// An error which stopped a demo from running
+#[derive(Debug)]
+pub enum DemoError {
+    /// The demo was invoked with arguments it didn't understand
+    Usage(String),
+    /// The demo failed to read its input
+    Read(ReadError),
+    /// The output couldn't be written - stdout was closed before it was done, say
+    Write(io::Error),
+    /// The demo failed to read its input as values of some other type than `u64` -
+    /// demo 6's `f64`s, say - with the parse error boxed (see `ReadError::boxed`)
+    Parse(ReadError<AnyParseError>),
+    /// Code which should have given the same result didn't
+    Mismatch(String),
+    /// The demo was cancelled before it finished
+    Cancelled,
+    /// The Ctrl-C handler which lets the demo be cancelled couldn't be installed
+    Handler(ctrlc::Error),
+    /// The runner's settings, from demo.toml or the environment, didn't make sense
+    Config(String),
+    /// A number worked out from the input was too big for the type it was worked
+    /// out in - a checked operation returned None where C# would have wrapped
+    Overflow(String),
+    /// The input parsed, but broke the rules for what it may hold - on more lines
+    /// than one error could point at
+    Invalid(String)
+}
+
+impl fmt::Display for DemoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DemoError::Usage(ref message)    => write!(f, "{}", message),
+            DemoError::Read(ref err)         => write!(f, "{}", err),
+            DemoError::Write(_)              => write!(f, "could not write output"),
+            DemoError::Parse(ref err)        => write!(f, "{}", err),
+            DemoError::Mismatch(ref message) => write!(f, "{}", message),
+            DemoError::Cancelled             => write!(f, "cancelled"),
+            DemoError::Handler(_)            => write!(f, "could not install a Ctrl-C handler"),
+            DemoError::Config(ref message)   => write!(f, "{}", message),
+            DemoError::Overflow(ref message) => write!(f, "{}", message),
+            DemoError::Invalid(ref message)  => write!(f, "{}", message)
+        }
+    }
+}
+
+impl Error for DemoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // A read or parse error is reported as if it were this error, so skip straight to its cause
+        match *self {
+            DemoError::Usage(_)         => None,
+            DemoError::Read(ref err)    => err.source(),
+            DemoError::Write(ref err)   => Some(err),
+            DemoError::Parse(ref err)   => err.source(),
+            DemoError::Mismatch(_)      => None,
+            DemoError::Cancelled        => None,
+            DemoError::Handler(ref err) => Some(err),
+            DemoError::Config(_)        => None,
+            DemoError::Overflow(_)      => None,
+            DemoError::Invalid(_)       => None
+        }
+    }
+}
+
+impl From<ReadError> for DemoError {
+    fn from(e: ReadError) -> DemoError {
+        DemoError::Read(e)
+    }
+}
+
+/// Every demo known to the runner, in presentation order
+pub fn all() -> Vec<Box<dyn Demo>> {
+    #[allow(unused_mut)]
+    let mut demos: Vec<Box<dyn Demo>> = vec![
+        Box::new(demo0::Demo0),
+        Box::new(demo1::Demo1),
+        Box::new(demo2::Demo2),
+        Box::new(demo3::Demo3),
+        Box::new(demo4::Demo4),
+        Box::new(demo5::Demo5),
+        Box::new(demo6::Demo6),
+        Box::new(demo7::Demo7),
+        Box::new(demo_streaming::StreamingDemo),
+        Box::new(demo_walk::WalkDemo),
+        Box::new(demo_encoding::EncodingDemo),
+        Box::new(demo_lenient::LenientDemo),
+        Box::new(demo_threads::ThreadsDemo),
+        Box::new(demo_pipeline::PipelineDemo),
+        Box::new(demo_shared::SharedDemo),
+        Box::new(demo_cancel::CancelDemo),
+        Box::new(demo_timeout::TimeoutDemo),
+        Box::new(demo_atomics::AtomicsDemo),
+        Box::new(demo_scoped::ScopedDemo),
+        Box::new(demo_thread_local::ThreadLocalDemo),
+        Box::new(demo_linq::LinqDemo),
+        Box::new(demo_lazy::LazyDemo),
+        Box::new(demo_iterator::IteratorDemo),
+        Box::new(demo_generator::GeneratorDemo),
+        Box::new(demo_option::OptionDemo),
+        Box::new(demo_ownership::OwnershipDemo),
+        Box::new(demo_borrowing::BorrowingDemo),
+        Box::new(demo_lifetimes::LifetimesDemo),
+        Box::new(demo_traits::TraitsDemo),
+        Box::new(demo_dispatch::DispatchDemo),
+        Box::new(demo_generics::GenericsDemo),
+        Box::new(demo_storage::StorageDemo),
+        Box::new(demo_enums::EnumsDemo),
+        Box::new(demo_patterns::PatternsDemo),
+        Box::new(demo_structs::StructsDemo),
+        Box::new(demo_drop::DropDemo),
+        Box::new(demo_rc::RcDemo),
+        Box::new(demo_refcell::RefCellDemo),
+        Box::new(demo_pointers::PointersDemo),
+        Box::new(demo_closures::ClosuresDemo),
+        Box::new(demo_process::ProcessDemo),
+        Box::new(demo_conversions::ConversionsDemo),
+        Box::new(demo_validate::ValidateDemo),
+        Box::new(demo_units::UnitsDemo),
+        Box::new(demo_defaults::DefaultsDemo),
+        Box::new(demo_extensions::ExtensionsDemo),
+        Box::new(demo_equality::EqualityDemo),
+        Box::new(demo_formatting::FormattingDemo),
+        Box::new(demo_strings::StringsDemo),
+        Box::new(demo_unicode::UnicodeDemo),
+        Box::new(demo_collections::CollectionsDemo),
+        Box::new(demo_entry::EntryDemo),
+        Box::new(demo_slices::SlicesDemo),
+        Box::new(demo_sorting::SortingDemo),
+        Box::new(demo_wc::WcDemo),
+        Box::new(demo_grep::GrepDemo),
+        Box::new(demo_dedup::DedupDemo),
+        Box::new(demo_csv::CsvDemo),
+        Box::new(demo_json::JsonDemo),
+        Box::new(demo_env::EnvDemo),
+        Box::new(demo_logging::LoggingDemo),
+        Box::new(demo_anyhow::AnyhowDemo),
+        Box::new(demo_thiserror::ThiserrorDemo),
+        Box::new(demo_panics::PanicsDemo),
+        Box::new(demo_retry::RetryDemo),
+        Box::new(demo_accumulate::AccumulateDemo),
+    ];
+    // Demos which need an optional dependency are only there when it's turned on
+    #[cfg(feature = "async")]
+    demos.push(Box::new(demo8::Demo8));
+    #[cfg(feature = "rayon")]
+    demos.push(Box::new(demo_parallel::ParallelDemo));
+    #[cfg(feature = "async")]
+    demos.push(Box::new(demo_async_stream::AsyncStreamDemo));
+    #[cfg(feature = "tracing")]
+    demos.push(Box::new(demo_tracing::TracingDemo));
+    demos
+}
+
+/// Finds a demo by name. Numbered demos may also be found by number alone,
+/// so `5` finds `demo5`.
+pub fn find(name: &str) -> Option<Box<dyn Demo>> {
+    let numbered = format!("demo{}", name);
+    all().into_iter().find(|demo| demo.name() == name || demo.name() == numbered)
+}
@@ -0,0 +1,124 @@
+//! Trying a read again when it fails for a reason which might not last.
+//!
+//! A file on a network share, or a pipe from another process, can fail to read
+//! one moment and read fine the next. `retry` runs a read until it works, or until
+//! the `RetryPolicy` says to give up, waiting longer after each failure so a
+//! struggling server isn't hammered - Polly's `WaitAndRetry` in .NET, as a
+//! function taking a closure rather than a policy object wrapping a delegate.
+
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::thread;
+use std::time::Duration;
+
+use log::warn;
+
+use crate::ReadError;
+
+/// How many times to try, and how long to wait between tries.
+///
+/// Set up builder style like `ReadOptions`:
+/// `RetryPolicy::new().attempts(5).delay(Duration::from_millis(50)).max_delay(Duration::from_secs(1))`.
+/// The wait starts at `delay` and is multiplied by `factor` after each failure -
+/// exponential backoff - but never goes over `max_delay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// How many times to try in all, including the first; at least 1
+    pub attempts: u32,
+    /// How long to wait after the first failure
+    pub delay: Duration,
+    /// What each wait is multiplied by to give the next
+    pub factor: u32,
+    /// The longest to wait between tries, however many there have been
+    pub max_delay: Duration
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            attempts: 3,
+            delay: Duration::from_millis(100),
+            factor: 2,
+            max_delay: Duration::from_secs(5)
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// How many times to try in all, including the first
+    pub fn attempts(mut self, attempts: u32) -> RetryPolicy {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// How long to wait after the first failure
+    pub fn delay(mut self, delay: Duration) -> RetryPolicy {
+        self.delay = delay;
+        self
+    }
+
+    /// What each wait is multiplied by to give the next. 1 waits the same each time
+    pub fn factor(mut self, factor: u32) -> RetryPolicy {
+        self.factor = factor;
+        self
+    }
+
+    /// The longest to wait between tries
+    pub fn max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The waits between tries, in order - one fewer than there are attempts
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let (factor, max_delay) = (self.factor, self.max_delay);
+        let first = self.delay.min(max_delay);
+        std::iter::successors(Some(first), move |delay| {
+            Some(delay.checked_mul(factor).map_or(max_delay, |next| next.min(max_delay)))
+        })
+        .take(self.attempts.max(1) as usize - 1)
+    }
+}
+
+/// Whether `e` is worth trying again for: an I/O error, in a file or not. Anything
+/// else - a line which doesn't parse, text in the wrong encoding - will be just
+/// the same next time, and so will a file which isn't there or can't be opened.
+pub fn is_transient<E>(e: &ReadError<E>) -> bool {
+    match *e {
+        ReadError::Io(ref e) => !matches!(e.kind(), ErrorKind::NotFound | ErrorKind::PermissionDenied),
+        ReadError::InFile(_, ref e) => is_transient(e),
+        _ => false
+    }
+}
+
+/// Runs `op`, and runs it again after a transient error, as `policy` allows. Returns
+/// the first success, the first error which isn't transient, or the last error if
+/// every attempt failed.
+///
+/// `op` is `FnMut`, as each attempt usually has to start again from the beginning
+/// - reopen the file - and may count the attempts it's made.
+pub fn retry<T, E, F>(mut op: F, policy: &RetryPolicy) -> Result<T, ReadError<E>>
+    where E: Display, F: FnMut() -> Result<T, ReadError<E>>
+{
+    let mut delays = policy.delays();
+    let mut attempt = 1;
+    loop {
+        let result = op();
+        if let Err(ref e) = result {
+            // Out of delays is out of attempts, and the last error is returned
+            if is_transient(e) {
+                if let Some(delay) = delays.next() {
+                    warn!("attempt {} of {} failed: {}; trying again in {}ms", attempt, policy.attempts, e, delay.as_millis());
+                    thread::sleep(delay);
+                    attempt += 1;
+                    continue;
+                }
+            }
+        }
+        return result;
+    }
+}
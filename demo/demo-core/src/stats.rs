@@ -0,0 +1,167 @@
+//! Summary statistics, and the `stats` command, which works them out for a file.
+//!
+//! This is where the error handling demos lead: demo 6's reader turns a file into
+//! numbers, or explains exactly why it couldn't, and the numbers are then worth
+//! something.
+
+use std::fmt;
+use std::path::Path;
+
+use crate::demo6;
+use crate::registry::DemoError;
+
+/// Summary statistics for a set of numbers.
+///
+/// How it prints is up to its `Display` and `Debug` implementations, which honour
+/// whatever the caller asks for in the format string: `{:.4}` sets how many places
+/// the mean gets, `{:>40}` pads it out, and `{:#}` lays it out over several lines.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    /// Wider than the numbers, so that no count of them can overflow it
+    pub sum: u128,
+    pub min: Option<u64>,
+    pub max: Option<u64>
+}
+
+impl Stats {
+    /// Works everything out in one pass, with a fold - LINQ's Aggregate - which
+    /// carries the statistics so far from one number to the next
+    pub fn of(numbers: &[u64]) -> Stats {
+        numbers.iter().fold(Stats::default(), |stats, &n| stats.with(n))
+    }
+
+    /// These statistics with one more number counted in, for numbers which arrive
+    /// one at a time rather than in a slice
+    pub fn with(self, n: u64) -> Stats {
+        Stats {
+            count: self.count + 1,
+            sum: self.sum + u128::from(n),
+            min: Some(self.min.map_or(n, |min| min.min(n))),
+            max: Some(self.max.map_or(n, |max| max.max(n)))
+        }
+    }
+
+    /// The statistics of both sets of numbers together, for numbers which were
+    /// split up - between threads, say - and summed up separately
+    pub fn merge(self, other: Stats) -> Stats {
+        Stats {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+            min: self.min.into_iter().chain(other.min).min(),
+            max: self.max.into_iter().chain(other.max).max()
+        }
+    }
+
+    /// The average, or `None` if there were no numbers to take it of
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum as f64 / self.count as f64)
+        }
+    }
+
+    // Formatter is an fmt::Write, and so is String, so this can write to either
+    fn write_summary<W: fmt::Write>(&self, out: &mut W, places: usize, lines: bool) -> fmt::Result {
+        let (min, max, mean) = match (self.min, self.max, self.mean()) {
+            (Some(min), Some(max), Some(mean)) => (min, max, mean),
+            _ => return write!(out, "no numbers")
+        };
+        if lines {
+            writeln!(out, "count {:>12}", self.count)?;
+            writeln!(out, "sum   {:>12}", self.sum)?;
+            writeln!(out, "min   {:>12}", min)?;
+            writeln!(out, "max   {:>12}", max)?;
+            write!(out, "mean  {:>12.*}", places, mean)
+        } else {
+            write!(out, "{} numbers, sum {}, from {} to {}, mean {:.*}", self.count, self.sum, min, max, places, mean)
+        }
+    }
+}
+
+// `{}` is Display, for people - C#'s ToString(). The Formatter carries the rest of
+// the format specifier, so this can ask for the precision, the width and the `#`
+// flag, where a C# type would implement IFormattable and parse a format string
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let places = f.precision().unwrap_or(2);
+        let lines = f.alternate();
+        let width = match f.width() {
+            Some(width) => width,
+            None        => return self.write_summary(f, places, lines)
+        };
+        // Padding applies to the whole summary, so write it out without any, and pad
+        // that. (Formatter::pad would take the precision as a length to cut it to)
+        let mut text = String::new();
+        self.write_summary(&mut text, places, lines)?;
+        match f.align() {
+            Some(fmt::Alignment::Right)  => write!(f, "{:>1$}", text, width),
+            Some(fmt::Alignment::Center) => write!(f, "{:^1$}", text, width),
+            _                            => write!(f, "{:<1$}", text, width)
+        }
+    }
+}
+
+// `{:?}` is Debug, for programmers. Derived, it would print `min: Some(1)`; written
+// by hand, it can show the range and the mean instead. debug_struct still does the
+// layout, so `{:#?}` spreads it over lines just as a derived Debug would
+impl fmt::Debug for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Stats");
+        debug.field("count", &self.count).field("sum", &self.sum);
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            debug.field("range", &(min..=max));
+        }
+        if let Some(mean) = self.mean() {
+            debug.field("mean", &format_args!("{:.2}", mean));
+        }
+        debug.finish()
+    }
+}
+
+/// The middle number once they're sorted, or the mean of the middle two for an
+/// even count
+pub fn median(numbers: &[u64]) -> Option<f64> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0                   => None,
+        len if len % 2 == 1 => Some(sorted[middle] as f64),
+        _                   => Some((sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0)
+    }
+}
+
+/// The population standard deviation - how far the numbers are from their mean.
+///
+/// Welford's method, as a fold: the count, the mean so far and the sum of squared
+/// differences from it are updated a number at a time. That reads the numbers
+/// once, and never squares anything as big as the numbers themselves.
+pub fn std_dev(numbers: &[u64]) -> Option<f64> {
+    let (count, _, squares) = numbers.iter().fold((0u64, 0.0, 0.0), |(count, mean, squares), &n| {
+        let count = count + 1;
+        let n = n as f64;
+        let delta = n - mean;
+        let mean = mean + delta / count as f64;
+        (count, mean, squares + delta * (n - mean))
+    });
+    if count == 0 {
+        None
+    } else {
+        Some((squares / count as f64).sqrt())
+    }
+}
+
+/// Runs the `stats` command over the numbers in `file_name`.
+pub fn run(file_name: &str) -> Result<(), DemoError> {
+    let path = Path::new(file_name);
+    let numbers = demo6::read_values::<u64>(path, None).map_err(|e| e.in_file(path))?;
+
+    println!("{:#}", Stats::of(&numbers));
+    if let (Some(median), Some(std_dev)) = (median(&numbers), std_dev(&numbers)) {
+        println!("median{:>12.2}", median);
+        println!("stddev{:>12.2}", std_dev);
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::ReadError;
+
+/// Somewhere input can be loaded from by name.
+///
+/// Each kind of storage fails in its own way, so the error is an *associated type*:
+/// every implementation names exactly one `Error`, and code using a storage `S`
+/// refers to it as `S::Error`. C# would make it a second type parameter,
+/// `IStorage<TError>` - but then one type could implement `IStorage<IOException>`
+/// and `IStorage<KeyNotFoundException>` both, and every generic method taking a
+/// storage would need a `TError` parameter of its own to name its error.
+pub trait Storage {
+    type Error: Error + 'static;
+
+    /// The raw bytes stored under `key`
+    fn load(&self, key: &str) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// Storage in a directory, with each key naming a file in it
+pub struct FileStorage {
+    pub root: PathBuf
+}
+
+impl FileStorage {
+    pub fn new<P: Into<PathBuf>>(root: P) -> FileStorage {
+        FileStorage { root: root.into() }
+    }
+}
+
+impl Storage for FileStorage {
+    type Error = io::Error;
+
+    fn load(&self, key: &str) -> Result<Vec<u8>, io::Error> {
+        fs::read(self.root.join(key))
+    }
+}
+
+/// Storage in memory, like a `Dictionary<string, byte[]>`
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: HashMap<String, Vec<u8>>
+}
+
+impl MemoryStorage {
+    pub fn new() -> MemoryStorage {
+        MemoryStorage::default()
+    }
+
+    /// Stores `bytes` under `key`, replacing anything already there
+    pub fn store<K: Into<String>>(&mut self, key: K, bytes: Vec<u8>) {
+        self.entries.insert(key.into(), bytes);
+    }
+}
+
+impl Storage for MemoryStorage {
+    type Error = MissingKey;
+
+    fn load(&self, key: &str) -> Result<Vec<u8>, MissingKey> {
+        self.entries.get(key).cloned().ok_or_else(|| MissingKey(key.to_string()))
+    }
+}
+
+/// The one way `MemoryStorage` can fail: nothing was stored under the key
+#[derive(Debug, PartialEq, Eq)]
+pub struct MissingKey(pub String);
+
+impl fmt::Display for MissingKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "nothing stored under '{}'", self.0)
+    }
+}
+
+impl Error for MissingKey {}
+
+// A missing key is a missing file by another name, so it's reported as one - with the
+// MissingKey kept as the cause, the way an IOException wraps an InnerException
+impl<E> From<MissingKey> for ReadError<E> {
+    fn from(e: MissingKey) -> ReadError<E> {
+        ReadError::Io(io::Error::new(io::ErrorKind::NotFound, e))
+    }
+}
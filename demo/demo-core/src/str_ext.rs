@@ -0,0 +1,40 @@
+use crate::literal::{ Literal, LiteralError };
+
+/// Extra methods for string slices - Rust's answer to C# extension methods.
+///
+/// C# adds `static` methods with a `this string` parameter to a static class. Rust
+/// declares a trait and implements it for the type, here `str`, which covers every
+/// `&str`, and every `String` too through `Deref`. As with C#'s `using` for the
+/// namespace, the methods only exist where the trait is imported:
+/// `use demo_core::str_ext::StrExt;`.
+///
+/// Unlike C#, Rust is strict about who may implement what. A trait can only be
+/// implemented for a type if the crate defines one or the other - the *orphan
+/// rule* - so two crates can never give the same type clashing implementations.
+/// A trait of our own, like this one, can be implemented for anything.
+pub trait StrExt {
+    /// Parses a whole number written any way `Literal` understands - `42`, `0x2A`,
+    /// `0b101010` or `1_000` - ignoring whitespace around it
+    fn parse_u64_friendly(&self) -> Result<u64, LiteralError>;
+
+    /// Whether this is empty, or nothing but whitespace
+    fn is_blank(&self) -> bool;
+
+    /// Whether this is a comment line, starting with `#` or `//` after any whitespace
+    fn is_comment(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn parse_u64_friendly(&self) -> Result<u64, LiteralError> {
+        self.parse::<Literal>().map(|literal| literal.0)
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+
+    fn is_comment(&self) -> bool {
+        let line = self.trim_start();
+        line.starts_with('#') || line.starts_with("//")
+    }
+}
@@ -0,0 +1,173 @@
+//! The `tail` command, which prints the last few lines of a file.
+//!
+//! The last lines are the hard end to get at. Reading every line and keeping the
+//! last few works for anything, but takes as long as the file is big. A file can be
+//! read from the end instead, which takes as long as the lines wanted are.
+//!
+//! With `-f` it then carries on, printing whatever is added to the file until
+//! Ctrl-C is pressed.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{ self, BufRead, Read, Seek, SeekFrom, Write };
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::sync::mpsc::{ self, Receiver, RecvTimeoutError, Sender };
+use std::time::Duration;
+
+use notify::{ RecommendedWatcher, RecursiveMode, Watcher };
+
+use crate::{ LineStream, ReadError };
+use crate::diagnostics;
+use crate::registry::DemoError;
+
+/// How much is read at a time, working back from the end
+const CHUNK: usize = 4096;
+
+/// How long `follow` waits for a change before looking anyway
+pub const POLL: Duration = Duration::from_millis(500);
+
+/// Where the last `count` lines start, found by reading backwards from the end.
+///
+/// `Seek` is `Stream.Seek`, and `SeekFrom::End(0)` is `SeekOrigin.End` - it also
+/// says how long the stream is. Each chunk before that is searched from its end for
+/// line breaks, bytes rather than text, so nothing needs decoding. A break at the
+/// very end finishes the last line rather than starting an empty one, so it isn't
+/// counted.
+pub fn start_of_last_lines<R: Read + Seek>(read: &mut R, count: usize) -> io::Result<u64> {
+    let len = read.seek(SeekFrom::End(0))?;
+    let mut to_find = count;
+    if to_find == 0 {
+        return Ok(len);
+    }
+    let mut chunk = [0; CHUNK];
+    let mut pos = len;
+    while pos > 0 {
+        let size = (CHUNK as u64).min(pos) as usize;
+        pos -= size as u64;
+        read.seek(SeekFrom::Start(pos))?;
+        read.read_exact(&mut chunk[..size])?;
+        for (i, &byte) in chunk[..size].iter().enumerate().rev() {
+            let at = pos + i as u64;
+            if byte == b'\n' && at + 1 != len {
+                to_find -= 1;
+                if to_find == 0 {
+                    return Ok(at + 1);
+                }
+            }
+        }
+    }
+    Ok(0)
+}
+
+/// The last `count` lines of a stream which can only be read forwards, such as
+/// stdin.
+///
+/// A `VecDeque` is a ring buffer: once it holds `count` lines, each line pushed on
+/// the back pops the oldest off the front, so it never holds more than `count`
+/// lines however long the input is. .NET has no ring buffer, but a `Queue<T>` used
+/// the same way is one. It grows as lines arrive rather than starting at `count`,
+/// which comes from the command line - `-n 100000000000` shouldn't allocate room
+/// for that many lines before reading one.
+pub fn last_lines<R: BufRead>(lines: LineStream<R>, count: usize) -> Result<VecDeque<String>, ReadError> {
+    let mut last = VecDeque::new();
+    if count == 0 {
+        return Ok(last);
+    }
+    for line in lines {
+        if last.len() == count {
+            last.pop_front();
+        }
+        last.push_back(line?);
+    }
+    Ok(last)
+}
+
+/// Copies whatever is added to `file` to `out`, from wherever it's got to, until
+/// `stop` is set or `events` is closed.
+///
+/// Each message on `events` says something may have changed - the watcher sends
+/// one when the file does, and the Ctrl-C handler when it's time to stop. Waiting
+/// for one blocks, without spinning, but only for `POLL`, so the file is looked at
+/// every so often whether a message came or not. That's the fallback when there's
+/// no watcher, and a safety net when there is one.
+///
+/// A file which has shrunk has been truncated, the way a log is when it rotates, so
+/// it's read again from the start.
+pub fn follow<W: Write>(file: &mut File, out: &mut W, events: &Receiver<()>, stop: &AtomicBool) -> io::Result<()> {
+    loop {
+        // Read the flag first, so that anything written before it was set is copied
+        let stopping = stop.load(Ordering::SeqCst);
+        if file.stream_position()? > file.metadata()?.len() {
+            file.seek(SeekFrom::Start(0))?;
+        }
+        io::copy(file, out)?;
+        out.flush()?;
+        if stopping {
+            return Ok(());
+        }
+        if let Err(RecvTimeoutError::Disconnected) = events.recv_timeout(POLL) {
+            return Ok(());
+        }
+    }
+}
+
+/// Watches `path`, sending a message to `events` whenever something happens to it.
+///
+/// This is `FileSystemWatcher`, and `notify` picks the best way to watch on each
+/// platform, as .NET does: inotify on Linux, FSEvents on macOS and
+/// ReadDirectoryChangesW on Windows. It stops watching when it's dropped.
+pub fn watch(path: &Path, events: Sender<()>) -> notify::Result<RecommendedWatcher> {
+    let mut watcher = notify::recommended_watcher(move |_| { let _ = events.send(()); })?;
+    watcher.watch(path, RecursiveMode::NonRecursive)?;
+    Ok(watcher)
+}
+
+/// Runs the `tail` command: prints the last `count` lines of `file_name`, or of
+/// stdin if it's `None`, and then, if `following`, whatever is added to the file.
+///
+/// A file is read backwards, and what follows the start of its last lines is copied
+/// to stdout as bytes, untouched. Stdin can't seek, so it goes through the ring
+/// buffer.
+pub fn run(following: bool, count: usize, file_name: Option<&str>) -> Result<(), DemoError> {
+    let file_name = match file_name {
+        Some(file_name) => file_name,
+        None if following => return Err(DemoError::Usage("-f needs a file to follow".to_string())),
+        None => {
+            for line in last_lines(LineStream::new(io::stdin().lock()), count)? {
+                println!("{}", line);
+            }
+            return Ok(());
+        }
+    };
+    let in_file = |e: io::Error| ReadError::from(e).in_file(file_name);
+    let mut file = File::open(file_name).map_err(in_file)?;
+    let start = start_of_last_lines(&mut file, count).map_err(in_file)?;
+    file.seek(SeekFrom::Start(start)).map_err(in_file)?;
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    io::copy(&mut file, &mut out).map_err(in_file)?;
+    out.flush().map_err(in_file)?;
+    if !following {
+        return Ok(());
+    }
+
+    // Ctrl-C is how following ends, so it's the normal way out rather than an error:
+    // the handler sets the flag and wakes the loop, which finishes what it's copying
+    let (events, changes) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    let handler_stop = Arc::clone(&stop);
+    let handler_events = events.clone();
+    ctrlc::set_handler(move || {
+        handler_stop.store(true, Ordering::SeqCst);
+        let _ = handler_events.send(());
+    }).map_err(DemoError::Handler)?;
+
+    // Without a watcher, the wait in follow times out every POLL instead
+    let _watcher = watch(Path::new(file_name), events)
+        .map_err(|e| diagnostics::warning(format_args!("Can't watch {} ({}), so checking every {:?}", file_name, e, POLL)))
+        .ok();
+    follow(&mut file, &mut out, &changes, &stop).map_err(in_file)?;
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// A TCP or UDP port number, 1 to 65535.
+///
+/// The `u16` inside is private, so the only way to get a `Port` is through
+/// `TryFrom`, which checks it. Once you have one it's known to be valid, and nothing
+/// which takes a `Port` needs to check again - the type *is* the validation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Port(u16);
+
+/// A percentage score, 0 to 100
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Score(u8);
+
+/// Why some text isn't a valid `Port` or `Score`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvalidValue {
+    /// It isn't a whole number at all
+    NotANumber(ParseIntError),
+    /// It's a number, but not one which is allowed
+    OutOfRange { value: i64, min: i64, max: i64 }
+}
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InvalidValue::NotANumber(_)               => write!(f, "not a whole number"),
+            InvalidValue::OutOfRange { min, max, .. } => write!(f, "not between {} and {}", min, max)
+        }
+    }
+}
+
+impl Error for InvalidValue {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InvalidValue::NotANumber(ref err) => Some(err),
+            InvalidValue::OutOfRange { .. }   => None
+        }
+    }
+}
+
+// Parsed as an i64 first, so that -5 is reported as out of range rather than as an
+// invalid digit
+fn parse_in_range(text: &str, min: i64, max: i64) -> Result<i64, InvalidValue> {
+    let value: i64 = text.trim().parse().map_err(InvalidValue::NotANumber)?;
+    if value < min || value > max {
+        return Err(InvalidValue::OutOfRange { value, min, max });
+    }
+    Ok(value)
+}
+
+impl TryFrom<&str> for Port {
+    type Error = InvalidValue;
+
+    fn try_from(text: &str) -> Result<Port, InvalidValue> {
+        let value = parse_in_range(text, 1, u16::MAX.into())?;
+        Ok(Port(value as u16))
+    }
+}
+
+impl TryFrom<&str> for Score {
+    type Error = InvalidValue;
+
+    fn try_from(text: &str) -> Result<Score, InvalidValue> {
+        let value = parse_in_range(text, 0, 100)?;
+        Ok(Score(value as u8))
+    }
+}
+
+// FromStr makes them work with str::parse - and so with parse_line, and everything
+// else which is generic over T: FromStr, such as demo 6
+impl FromStr for Port {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Port, InvalidValue> {
+        Port::try_from(s)
+    }
+}
+
+impl FromStr for Score {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Score, InvalidValue> {
+        Score::try_from(s)
+    }
+}
+
+impl Port {
+    pub fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl Score {
+    pub fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Port {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
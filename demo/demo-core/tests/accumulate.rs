@@ -0,0 +1,58 @@
+//! Validated: checks which all run, and report every failure between them.
+
+extern crate demo_core;
+
+use demo_core::demo_accumulate::{ validate_all, validate_line, ValidationError };
+use demo_core::accumulate::Validated;
+
+fn invalid(errors: &[&str]) -> Validated<i32, String> {
+    Validated::Invalid(errors.iter().map(|e| e.to_string()).collect())
+}
+
+#[test]
+fn and_keeps_the_errors_from_both_sides() {
+    let valid: Validated<i32, String> = Validated::Valid(1);
+    assert_eq!(valid.clone().and(Validated::Valid(2)), Validated::Valid((1, 2)));
+    assert_eq!(valid.and(invalid(&["b"])), Validated::Invalid(vec!["b".to_string()]));
+    assert_eq!(invalid(&["a"]).and(invalid(&["b", "c"])), Validated::Invalid(vec!["a".to_string(), "b".to_string(), "c".to_string()]));
+}
+
+#[test]
+fn collecting_keeps_every_error_where_result_keeps_the_first() {
+    let checks = vec![Ok(1), Err("a"), Ok(3), Err("b")];
+    let results: Result<Vec<i32>, &str> = checks.iter().cloned().collect();
+    assert_eq!(results, Err("a"));
+    let validated: Validated<Vec<i32>, &str> = checks.into_iter().map(Validated::from).collect();
+    assert_eq!(validated, Validated::Invalid(vec!["a", "b"]));
+
+    let all_valid: Validated<Vec<i32>, &str> = vec![Validated::Valid(1), Validated::Valid(2)].into_iter().collect();
+    assert_eq!(all_valid.into_result(), Ok(vec![1, 2]));
+}
+
+#[test]
+fn every_field_of_a_line_is_checked() {
+    let errors = validate_line(7, ",0,101").into_result().unwrap_err();
+    let fields: Vec<&str> = errors.iter().map(|e| e.field).collect();
+    assert_eq!(fields, vec!["name", "port", "score"]);
+    assert_eq!(errors[1].to_string(), "line 7: port: not between 1 and 65535: '0'");
+
+    let server = validate_line(1, "web, 80, 95").into_result().unwrap();
+    assert_eq!((&server.name[..], server.port.get(), server.score.get()), ("web", 80, 95));
+}
+
+#[test]
+fn a_line_with_the_wrong_number_of_fields_is_one_error() {
+    assert_eq!(validate_line(3, "mail,25").into_result().unwrap_err(), vec![ValidationError {
+        line: 3,
+        field: "line",
+        problem: "expected 3 fields, found 2".to_string()
+    }]);
+}
+
+#[test]
+fn every_line_of_a_file_is_checked() {
+    let lines: Vec<String> = vec!["web,80,95", "", ",0,50", "db,70000,101", "ftp,21,60"].into_iter().map(String::from).collect();
+    let errors = validate_all(&lines).into_result().unwrap_err();
+    let places: Vec<(usize, &str)> = errors.iter().map(|e| (e.line, e.field)).collect();
+    assert_eq!(places, vec![(3, "name"), (3, "port"), (4, "port"), (4, "score")]);
+}
@@ -0,0 +1,68 @@
+//! Demo 6 again with anyhow: errors wrapped in context, and found again by downcasting.
+
+extern crate anyhow;
+extern crate demo_core;
+
+use std::error::Error;
+use std::io;
+use std::num::ParseIntError;
+use std::path::Path;
+
+use demo_core::demo_anyhow::{ demo_error, read_files, read_values };
+use demo_core::exit_codes;
+use demo_core::registry::DemoError;
+
+fn messages(e: &anyhow::Error) -> Vec<String> {
+    e.chain().map(|cause| cause.to_string()).collect()
+}
+
+#[test]
+fn reads_the_numbers_in_each_file() {
+    assert_eq!(read_values(Path::new("tests/fixtures/valid.txt")).unwrap(), vec![1, 2, 3]);
+    let files = vec!["tests/fixtures/valid.txt".to_string(), "tests/fixtures/valid.txt".to_string()];
+    assert_eq!(read_files(&files).unwrap(), vec![1, 2, 3, 1, 2, 3]);
+}
+
+#[test]
+fn each_context_is_a_cause_of_the_one_added_after_it() {
+    let files = vec!["tests/fixtures/non-numeric.txt".to_string()];
+    let e = read_files(&files).unwrap_err();
+    assert_eq!(messages(&e), vec![
+        "in tests/fixtures/non-numeric.txt",
+        "line 2: 'two' is not a number",
+        "invalid digit found in string"
+    ]);
+    // `{:#}` writes the whole chain on one line
+    assert_eq!(format!("{:#}", e), "in tests/fixtures/non-numeric.txt: line 2: 'two' is not a number: invalid digit found in string");
+}
+
+#[test]
+fn the_original_error_can_be_downcast_to() {
+    let e = read_values(Path::new("tests/fixtures/non-numeric.txt")).unwrap_err();
+    assert!(e.root_cause().is::<ParseIntError>());
+    assert_eq!(exit_codes::for_demo_error(&demo_error(e)), exit_codes::PARSE);
+
+    let e = read_values(Path::new("tests/fixtures/missing.txt")).unwrap_err();
+    assert_eq!(messages(&e)[0], "reading numbers file");
+    let io_error = e.downcast_ref::<io::Error>().unwrap();
+    assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+    assert_eq!(exit_codes::for_demo_error(&demo_error(e)), exit_codes::IO);
+}
+
+#[test]
+fn every_context_is_still_a_cause_for_the_runner() {
+    let files = vec!["tests/fixtures/non-numeric.txt".to_string()];
+    let e = demo_error(read_files(&files).unwrap_err());
+    assert!(matches!(e, DemoError::Parse(_)));
+    let mut causes = Vec::new();
+    let mut cause = e.source();
+    while let Some(e) = cause {
+        causes.push(e.to_string());
+        cause = e.source();
+    }
+    assert_eq!(causes, vec![
+        "in tests/fixtures/non-numeric.txt",
+        "line 2: 'two' is not a number",
+        "invalid digit found in string"
+    ]);
+}
@@ -0,0 +1,24 @@
+//! Counting words and picking the most common, from the collections demo.
+
+extern crate demo_core;
+
+use demo_core::demo_collections::{ top_words, word_counts, words };
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+#[test]
+fn words_ignore_case_and_punctuation() {
+    let found: Vec<String> = words("The cat, the hat - THE END.").collect();
+    assert_eq!(found, ["the", "cat", "the", "hat", "the", "end"]);
+}
+
+// The heap keeps the most common, and breaks ties alphabetically
+#[test]
+fn top_words_are_most_common_first() {
+    let counts = word_counts(&lines("b a c\nc b\nc d\ne"));
+    assert_eq!(top_words(&counts, 3), [("c", 3), ("b", 2), ("a", 1)]);
+    assert_eq!(top_words(&counts, 10).len(), 5);
+    assert!(top_words(&counts, 0).is_empty());
+}
@@ -0,0 +1,29 @@
+//! Reading typed records from CSV, keeping the rows which don't fit as errors.
+
+extern crate demo_core;
+
+use demo_core::demo_csv::{ read_orders, Order };
+
+#[test]
+fn rows_deserialize_by_header_name() {
+    let csv = "note,unit price,quantity,customer,id\n,1.5,2,\"Smith, J\",7\n";
+    let (orders, errors) = read_orders(csv.as_bytes()).unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(orders, vec![Order { id: 7, customer: "Smith, J".to_string(), quantity: 2, unit_price: 1.5, note: None }]);
+    assert_eq!(orders[0].total(), 3.0);
+}
+
+#[test]
+fn bad_rows_are_errors_with_their_line() {
+    let csv = "id,customer,quantity,unit price,note\n1,A,x,1.0,\n2,B,1,2.0,ok\n3,C,1\n";
+    let (orders, errors) = read_orders(csv.as_bytes()).unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(errors.iter().map(|e| e.line).collect::<Vec<usize>>(), vec![2, 4]);
+    assert_eq!(errors[0].text, "1,A,x,1.0,");
+    assert_eq!(errors[0].error.field(), Some(2));
+}
+
+#[test]
+fn invalid_text_stops_the_read() {
+    assert!(read_orders(&b"id\n\xff\n"[..]).is_err());
+}
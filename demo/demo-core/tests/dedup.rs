@@ -0,0 +1,25 @@
+//! The dedup demo's two ways of dropping repeated lines.
+
+extern crate demo_core;
+
+use demo_core::demo_dedup::{ collapse_runs, count_unique, unique };
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+#[test]
+fn unique_keeps_first_seen_order_and_borrows() {
+    let input = lines("b\na\nb\nc\na");
+    let found = unique(&input);
+    assert_eq!(found, vec!["b", "a", "c"]);
+    assert_eq!(found[0].as_ptr(), input[0].as_ptr());
+    assert_eq!(count_unique(&input), vec![("b", 2), ("a", 2), ("c", 1)]);
+}
+
+#[test]
+fn collapse_runs_only_merges_neighbours() {
+    let runs = collapse_runs(lines("a\na\nb\na\na\na"));
+    assert_eq!(runs, vec![("a".to_string(), 2), ("b".to_string(), 1), ("a".to_string(), 3)]);
+    assert_eq!(collapse_runs(Vec::new()), vec![]);
+}
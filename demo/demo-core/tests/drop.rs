@@ -0,0 +1,56 @@
+//! The drop demo's temp file guard, which deletes its file however the scope
+//! owning it is left.
+
+extern crate demo_core;
+
+use std::fs;
+use std::mem;
+use std::panic;
+use std::path::PathBuf;
+
+use demo_core::demo_drop::TempFileGuard;
+
+#[test]
+fn the_file_is_there_until_the_guard_is_dropped() {
+    let path = {
+        let guard = TempFileGuard::create("drop-test-scope", b"1\n2\n").unwrap();
+        assert_eq!(fs::read(guard.path()).unwrap(), b"1\n2\n");
+        guard.path().to_path_buf()
+    };
+    assert!(!path.exists());
+}
+
+#[test]
+fn dropping_early_deletes_it_early() {
+    let guard = TempFileGuard::create("drop-test-early", b"").unwrap();
+    let path = guard.path().to_path_buf();
+    mem::drop(guard);
+    assert!(!path.exists());
+}
+
+// Leaving the scope by `?` drops the guard as surely as falling off the end does
+#[test]
+fn an_early_return_still_deletes_it() {
+    fn fail_half_way(path: &mut PathBuf) -> Result<(), String> {
+        let guard = TempFileGuard::create("drop-test-return", b"x").map_err(|e| e.to_string())?;
+        *path = guard.path().to_path_buf();
+        "x".parse::<u64>().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+    let mut path = PathBuf::new();
+    assert!(fail_half_way(&mut path).is_err());
+    assert!(!path.as_os_str().is_empty() && !path.exists());
+}
+
+// And so does a panic, as it unwinds through the scope
+#[test]
+fn a_panic_still_deletes_it() {
+    let mut path = PathBuf::new();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let guard = TempFileGuard::create("drop-test-panic", b"x").unwrap();
+        path = guard.path().to_path_buf();
+        panic!("giving up with the file still open");
+    }));
+    assert!(result.is_err());
+    assert!(!path.as_os_str().is_empty() && !path.exists());
+}
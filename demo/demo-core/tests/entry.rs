@@ -0,0 +1,14 @@
+//! Counting lines with the Entry API, from the entry demo.
+
+extern crate demo_core;
+
+use demo_core::demo_entry::count_lines;
+
+#[test]
+fn counts_ignore_case_and_padding() {
+    let lines: Vec<String> = ["home", " Home ", "about", "HOME"].iter().map(|s| s.to_string()).collect();
+    let counts = count_lines(&lines);
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["home"], 3);
+    assert_eq!(counts["about"], 1);
+}
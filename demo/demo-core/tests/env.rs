@@ -0,0 +1,33 @@
+//! Settings read from environment variables, by hand and with envy.
+
+extern crate demo_core;
+
+use std::env::VarError;
+
+use demo_core::demo_env::{ deserialize, from_vars, Settings };
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|&(name, value)| (name.to_string(), value.to_string())).collect()
+}
+
+#[test]
+fn missing_variables_take_the_defaults() {
+    assert_eq!(from_vars(|_| Err(VarError::NotPresent)).unwrap(), Settings::default());
+    assert_eq!(deserialize(vars(&[("PATH", "/bin")])).unwrap(), Settings::default());
+}
+
+#[test]
+fn both_ways_parse_the_same_values() {
+    let set = vars(&[("DEMO_ENV_WORKERS", "8"), ("DEMO_ENV_VERBOSE", "true"), ("DEMO_ENV_TAGS", "a,b"), ("WORKERS", "2")]);
+    let expected = Settings { workers: 8, verbose: true, tags: vec!["a".to_string(), "b".to_string()], ..Settings::default() };
+    let by_hand = from_vars(|name| set.iter().find(|(n, _)| n == name).map(|(_, v)| v.clone()).ok_or(VarError::NotPresent));
+    assert_eq!(by_hand.unwrap(), expected);
+    assert_eq!(deserialize(set).unwrap(), expected);
+}
+
+#[test]
+fn values_which_do_not_parse_are_errors_not_defaults() {
+    let e = from_vars(|name| if name == "DEMO_ENV_TIMEOUT_MS" { Ok("soon".to_string()) } else { Err(VarError::NotPresent) });
+    assert_eq!(e.unwrap_err().to_string(), "DEMO_ENV_TIMEOUT_MS: invalid digit found in string: 'soon'");
+    assert!(deserialize(vars(&[("DEMO_ENV_VERBOSE", "yes")])).is_err());
+}
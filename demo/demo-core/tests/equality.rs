@@ -0,0 +1,37 @@
+//! The hand-written comparisons from the equality demo, which have to agree with
+//! each other for HashMap and sort to work.
+
+extern crate demo_core;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{ Hash, Hasher };
+
+use demo_core::demo_equality::{ parse_record, Record };
+
+fn hash_of(record: &Record) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    record.hash(&mut hasher);
+    hasher.finish()
+}
+
+// The line and the case of the name are left out of eq, so they must be left out of
+// the hash too, or equal records would land in different HashMap buckets
+#[test]
+fn equal_records_hash_the_same() {
+    let first = parse_record(1, "apple 3").unwrap();
+    let second = parse_record(9, "APPLE 0b11").unwrap();
+    assert_eq!(first, second);
+    assert_eq!(hash_of(&first), hash_of(&second));
+    assert_ne!(first, parse_record(2, "apple 4").unwrap());
+}
+
+#[test]
+fn records_sort_by_count_then_name() {
+    let mut records: Vec<Record> = ["cherry 7", "Banana 12", "apple 7"].iter()
+        .enumerate()
+        .map(|(i, text)| parse_record(i + 1, text).unwrap())
+        .collect();
+    records.sort();
+    let names: Vec<&str> = records.iter().map(|record| &record.name[..]).collect();
+    assert_eq!(names, ["Banana", "apple", "cherry"]);
+}
@@ -0,0 +1,28 @@
+//! How the grep demo reads its flags, and what it finds.
+
+extern crate demo_core;
+extern crate regex;
+
+use regex::Regex;
+
+use demo_core::demo_grep::{ highlight, matches, parse_args, Flags };
+
+#[test]
+fn flags_come_before_the_pattern() {
+    let args: Vec<String> = ["-vi", "-", "file.txt"].iter().map(|a| a.to_string()).collect();
+    let (flags, rest) = parse_args(&args).unwrap();
+    assert_eq!(flags, Flags { invert: true, ignore_case: true });
+    assert_eq!(rest, &args[1..]);
+    assert!(parse_args(&["-x".to_string()]).is_err());
+}
+
+#[test]
+fn matches_borrow_from_the_line() {
+    let regex = Regex::new(r"\d+").unwrap();
+    let line = "12 apples and 7 pears".to_string();
+    let found = matches(&regex, &line);
+    assert_eq!(found, vec!["12", "7"]);
+    assert_eq!(found[0].as_ptr(), line.as_ptr());
+    assert_eq!(highlight(&regex, &line), "[12] apples and [7] pears");
+    assert_eq!(highlight(&regex, "none"), "none");
+}
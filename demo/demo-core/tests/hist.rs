@@ -0,0 +1,40 @@
+//! How the `hist` command buckets numbers, and how long it draws the bars.
+
+extern crate demo_core;
+
+use demo_core::hist::{ buckets, render, Bucket };
+
+#[test]
+fn buckets_cover_the_whole_range() {
+    assert_eq!(buckets(&[], 3), vec![]);
+    assert_eq!(buckets(&[1, 2, 9, 10], 2), vec![
+        Bucket { start: 1, end: 5, count: 2 },
+        Bucket { start: 6, end: 10, count: 2 }
+    ]);
+    // 0 to 9 in 3 buckets is 4 wide, so the last is cut short at the largest number
+    assert_eq!(buckets(&[0, 9], 3).last(), Some(&Bucket { start: 8, end: 9, count: 1 }));
+}
+
+#[test]
+fn buckets_stop_when_there_are_no_more_numbers_to_hold() {
+    assert_eq!(buckets(&[4, 4, 4], 10), vec![Bucket { start: 4, end: 4, count: 3 }]);
+    assert_eq!(buckets(&[1, 3], 10).len(), 3);
+}
+
+#[test]
+fn buckets_reach_the_largest_u64() {
+    let all = buckets(&[0, u64::MAX], 2);
+    assert_eq!(all[1], Bucket { start: 1 << 63, end: u64::MAX, count: 1 });
+}
+
+#[test]
+fn render_scales_to_the_fullest_bucket() {
+    let lines = render(&buckets(&[1, 1, 1, 1, 10], 2), 8);
+    assert_eq!(lines, vec![
+        " 1 -  5 | ######## 4",
+        " 6 - 10 | ##       1"
+    ]);
+    // A count too small to round to a whole # still gets one
+    let lines = render(&buckets(&[1, 10, 10, 10, 10, 10, 10, 10, 10, 10], 2), 4);
+    assert_eq!(lines[0], " 1 -  5 | #    1");
+}
@@ -0,0 +1,40 @@
+//! Typed JSON in and out of the json demo's structs.
+
+extern crate demo_core;
+extern crate serde_json;
+
+use demo_core::demo_json::{ parse_jobs, Job, JobResult, Outcome, Output };
+
+#[test]
+fn jobs_parse_with_camel_case_names_and_defaults() {
+    let json = r#"[{ "name": "a", "sourceFile": "a.txt", "maxLines": 2, "output": { "kind": "top", "count": 1 } }]"#;
+    let jobs = parse_jobs(json.as_bytes()).unwrap();
+    assert_eq!(jobs, vec![Job {
+        name: "a".to_string(),
+        source_file: "a.txt".to_string(),
+        max_lines: Some(2),
+        output: Output::Top { count: 1 },
+        tags: vec![]
+    }]);
+}
+
+#[test]
+fn jobs_which_do_not_match_are_errors_with_a_position() {
+    let e = parse_jobs(r#"[{ "name": "a", "output": { "kind": "summary" } }]"#.as_bytes()).unwrap_err();
+    assert_eq!(e.to_string(), "missing field `sourceFile` at line 1 column 49");
+    assert!(parse_jobs(r#"[{ "name": "a", "sourceFile": "a", "output": { "kind": "pie" } }]"#.as_bytes()).is_err());
+}
+
+#[test]
+fn jobs_round_trip_leaving_out_none() {
+    let job = Job { name: "a".to_string(), source_file: "a.txt".to_string(), max_lines: None, output: Output::Summary, tags: vec![] };
+    let json = serde_json::to_string(&job).unwrap();
+    assert_eq!(json, r#"{"name":"a","sourceFile":"a.txt","output":{"kind":"summary"},"tags":[]}"#);
+    assert_eq!(parse_jobs(format!("[{}]", json).as_bytes()).unwrap(), vec![job]);
+}
+
+#[test]
+fn results_flatten_the_outcome() {
+    let result = JobResult { job: "a", lines_read: 2, outcome: Outcome::Top { values: vec![9, 4] } };
+    assert_eq!(serde_json::to_string(&result).unwrap(), r#"{"job":"a","linesRead":2,"kind":"top","values":[9,4]}"#);
+}
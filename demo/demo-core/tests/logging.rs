@@ -0,0 +1,40 @@
+//! What the logging demo logs, caught by a logger of the test's own.
+
+extern crate demo_core;
+extern crate log;
+
+use std::sync::Mutex;
+
+use log::{ Level, LevelFilter, Log, Metadata, Record };
+
+use demo_core::demo_logging::total;
+
+// Keeps every message instead of printing it. A process has one logger, and each
+// file in tests/ is its own process, so this one doesn't affect the others
+struct Captured(Mutex<Vec<(Level, String)>>);
+
+impl Log for Captured {
+    fn enabled(&self, _: &Metadata) -> bool { true }
+
+    fn log(&self, record: &Record) {
+        self.0.lock().unwrap().push((record.level(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Captured = Captured(Mutex::new(Vec::new()));
+
+#[test]
+fn skipped_lines_are_warnings_and_the_total_is_info() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    assert_eq!(total("1\ntwo\n3\n".as_bytes()).unwrap(), (4, 1));
+    let messages = LOGGER.0.lock().unwrap();
+    assert!(messages.contains(&(Level::Warn, "skipping line 2: invalid digit found in string: 'two'".to_string())));
+    assert_eq!(messages.last(), Some(&(Level::Info, "totalled 2 numbers, skipping 1".to_string())));
+    // Trace is below the maximum level, so the line-by-line messages were never made
+    assert!(messages.iter().all(|(level, _)| *level != Level::Trace));
+    assert!(messages.contains(&(Level::Debug, "mean 2.00".to_string())));
+}
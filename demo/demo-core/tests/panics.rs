@@ -0,0 +1,42 @@
+//! Panics caught at the edges, and the messages found in their payloads.
+
+extern crate demo_core;
+
+use std::panic;
+
+use demo_core::demo_panics::{ catch, on_thread, panic_message, BadInput };
+
+#[test]
+fn the_message_is_found_whatever_the_payload() {
+    assert_eq!(panic_message(&"literal"), "literal");
+    assert_eq!(panic_message(&format!("formatted {}", 1)), "formatted 1");
+    assert_eq!(panic_message(&BadInput { line: 3, text: "x".to_string() }), "bad input on line 3: 'x'");
+    assert_eq!(panic_message(&42), "a panic with a payload of an unknown type");
+}
+
+#[test]
+fn nothing_is_caught_without_a_panic() {
+    assert_eq!(catch(|| 1 + 1), Ok(2));
+    assert_eq!(on_thread(|| 1 + 1), Ok(2));
+}
+
+#[test]
+fn a_panic_is_caught_with_its_message() {
+    let numbers: Vec<u64> = (1..=3).collect();
+    assert_eq!(catch(move || numbers[10]), Err("index out of bounds: the len is 3 but the index is 10".to_string()));
+    assert_eq!(catch(|| -> u64 { panic!("line {}", 7) }), Err("line 7".to_string()));
+}
+
+#[test]
+fn a_payload_can_be_any_value() {
+    let bad = BadInput { line: 2, text: "two".to_string() };
+    let payload = panic::catch_unwind(|| panic::panic_any(bad.clone())).unwrap_err();
+    assert_eq!(payload.downcast_ref::<BadInput>(), Some(&bad));
+}
+
+#[test]
+fn a_panic_ends_only_its_own_thread() {
+    assert_eq!(on_thread(|| -> u64 { panic!("gave up") }), Err("gave up".to_string()));
+    // This thread carries on
+    assert_eq!(on_thread(|| 2), Ok(2));
+}
@@ -0,0 +1,60 @@
+//! The progress bar, moved on by the bytes read through it.
+
+extern crate demo_core;
+extern crate indicatif;
+
+use std::io::{ BufRead, Cursor, Read };
+
+use demo_core::{ read_lines_from, LineStream };
+use demo_core::progress::{ bar, open_input, read_input, take_flag, track };
+use indicatif::ProgressBar;
+
+#[test]
+fn reading_moves_the_bar_by_the_bytes_read() {
+    let text = "1\n22\n333\n";
+    let bar = ProgressBar::hidden();
+    let mut read = track(Cursor::new(text.as_bytes().to_vec()), &bar);
+    let mut line = String::new();
+    read.read_line(&mut line).unwrap();
+    // The BufReader takes what it can in one go, not one line at a time
+    assert_eq!(bar.position(), text.len() as u64);
+    read.read_to_string(&mut line).unwrap();
+    assert_eq!(line, text);
+    assert_eq!(bar.position(), text.len() as u64);
+}
+
+#[test]
+fn a_file_sets_the_length_and_is_read_to_the_end() {
+    let (read, bar) = open_input(Some("tests/fixtures/valid.txt")).unwrap();
+    let len = std::fs::metadata("tests/fixtures/valid.txt").unwrap().len();
+    assert_eq!(bar.length(), Some(len));
+    assert_eq!(LineStream::new(read).count(), 3);
+    assert_eq!(bar.position(), len);
+}
+
+#[test]
+fn an_unknown_length_is_a_spinner() {
+    assert_eq!(bar(None).length(), None);
+    assert_eq!(bar(Some(10)).length(), Some(10));
+}
+
+#[test]
+fn a_missing_file_is_an_error() {
+    assert!(open_input(Some("tests/fixtures/missing.txt")).is_err());
+}
+
+#[test]
+fn the_flag_is_taken_out_wherever_it_is() {
+    let args: Vec<String> = ["-c", "--progress", "visits.txt"].iter().map(|a| a.to_string()).collect();
+    assert_eq!(take_flag(&args), (true, vec!["-c".to_string(), "visits.txt".to_string()]));
+    assert_eq!(take_flag(&args[..1]), (false, vec!["-c".to_string()]));
+}
+
+#[test]
+fn the_input_is_read_the_same_with_or_without_a_bar() {
+    for with_progress in [false, true] {
+        let lines = read_input(Some("tests/fixtures/valid.txt"), with_progress, read_lines_from).unwrap();
+        assert_eq!(lines, ["1", "2", "3"]);
+        assert!(read_input(Some("tests/fixtures/missing.txt"), with_progress, read_lines_from).is_err());
+    }
+}
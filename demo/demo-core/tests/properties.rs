@@ -0,0 +1,197 @@
+//! Property-based tests for the number parsing, using proptest - Rust's FsCheck.
+//!
+//! Rather than a handful of hand-picked examples, each property is checked against
+//! hundreds of generated inputs, and any failure is shrunk to the simplest input
+//! which still fails.
+
+#[macro_use]
+extern crate proptest;
+extern crate demo_core;
+
+use std::io::Cursor;
+use std::num::ParseIntError;
+use std::sync::atomic::Ordering;
+
+use proptest::collection::vec;
+use proptest::prelude::any;
+
+use demo_core::{ read_lines_from, ReadError };
+use demo_core::demo_atomics::{ tally_parallel, tally_sequential };
+use demo_core::demo_generics::{ sum_values, SumError };
+use demo_core::demo_iterator::Chunked;
+use demo_core::demo_slices::longest_rise;
+use demo_core::demo_sorting::median;
+use demo_core::demo_strings::first_chars;
+use demo_core::literal::Literal;
+use demo_core::numbers::{ parse_line, parse_tokens, Delimiter };
+use demo_core::str_ext::StrExt;
+use demo_core::tail::start_of_last_lines;
+use demo_core::validated::Port;
+
+fn read_bytes(bytes: &[u8]) -> Result<Vec<String>, ReadError> {
+    read_lines_from(bytes)
+}
+
+proptest! {
+    // Whatever bytes turn up, reading and parsing them is an Ok or an Err - never a panic
+    #[test]
+    fn any_bytes_read_without_panicking(bytes in vec(any::<u8>(), 0..256)) {
+        if let Ok(lines) = read_bytes(&bytes) {
+            for (i, line) in lines.iter().enumerate() {
+                let _ = parse_line::<u64>(i + 1, line);
+                let _ = parse_line::<Literal>(i + 1, line);
+            }
+        }
+    }
+
+    #[test]
+    fn any_text_parses_as_a_literal_without_panicking(text in "\\PC*") {
+        let _ = text.parse::<Literal>();
+    }
+
+    // Writing numbers out one per line and reading them back gives the same numbers
+    #[test]
+    fn numbers_round_trip(numbers in vec(any::<u64>(), 0..64)) {
+        let text: String = numbers.iter().map(|n| format!("{}\n", n)).collect();
+        let lines = read_bytes(text.as_bytes()).unwrap();
+        let parsed: Result<Vec<u64>, _> = lines.iter()
+            .enumerate()
+            .map(|(i, line)| parse_line(i + 1, line))
+            .collect();
+        prop_assert_eq!(parsed.unwrap(), numbers);
+    }
+
+    // The same goes for several numbers to a line, split on whitespace
+    #[test]
+    fn tokens_round_trip(rows in vec(vec(any::<u64>(), 1..8), 0..16)) {
+        let lines: Vec<(usize, String)> = rows.iter()
+            .enumerate()
+            .map(|(i, row)| (i + 1, row.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(" ")))
+            .collect();
+        let parsed: Vec<u64> = parse_tokens(&lines, Delimiter::Whitespace).unwrap();
+        prop_assert_eq!(parsed, rows.concat());
+    }
+
+    // A Literal reads back whichever way the number is written
+    #[test]
+    fn literals_round_trip(n in any::<u64>()) {
+        prop_assert_eq!(format!("{}", n).parse::<Literal>(), Ok(Literal(n)));
+        prop_assert_eq!(format!("0x{:x}", n).parse::<Literal>(), Ok(Literal(n)));
+        prop_assert_eq!(format!("0X{:X}", n).parse::<Literal>(), Ok(Literal(n)));
+        prop_assert_eq!(format!("0b{:b}", n).parse::<Literal>(), Ok(Literal(n)));
+        prop_assert_eq!(with_separators(&n.to_string()).parse::<Literal>(), Ok(Literal(n)));
+    }
+
+    // Threads sharing atomic counters never lose a count, whichever ordering they use
+    #[test]
+    fn atomic_tallies_match_a_single_thread(lines in vec("[0-9a-z]{0,3}", 0..64)) {
+        let expected = tally_sequential(&lines);
+        prop_assert_eq!(tally_parallel(&lines, Ordering::Relaxed), expected);
+        prop_assert_eq!(tally_parallel(&lines, Ordering::SeqCst), expected);
+    }
+
+    // Chunking loses nothing, reorders nothing, and knows in advance how many chunks there'll be
+    #[test]
+    fn chunks_put_back_together(items in vec(any::<u8>(), 0..64), size in 1usize..10) {
+        let chunks = Chunked::new(items.iter().cloned(), size);
+        let expected_len = chunks.len();
+        let chunks: Vec<Vec<u8>> = chunks.collect();
+        prop_assert_eq!(chunks.len(), expected_len);
+        prop_assert!(chunks.iter().all(|chunk| !chunk.is_empty() && chunk.len() <= size));
+        prop_assert_eq!(chunks.concat(), items);
+    }
+
+    // Summing generically gets the same answer whichever type does the adding
+    #[test]
+    fn generic_sums_agree(numbers in vec(0u32..1_000_000, 0..64)) {
+        let lines: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        let expected: u64 = numbers.iter().map(|&n| u64::from(n)).sum();
+        prop_assert_eq!(sum_values::<u64>(&lines).unwrap(), expected);
+        prop_assert_eq!(sum_values::<i64>(&lines).unwrap(), expected as i64);
+        prop_assert_eq!(sum_values::<Literal>(&lines).unwrap(), Literal(expected));
+    }
+
+    // A sum too big for the type says which line took it over, rather than wrapping
+    #[test]
+    fn generic_sums_stop_at_overflow(n in 1u64..1_000_000) {
+        let lines = vec![u64::MAX.to_string(), n.to_string()];
+        prop_assert!(matches!(sum_values::<u64>(&lines), Err(SumError::Overflow(2))));
+        prop_assert!(matches!(sum_values::<Literal>(&lines), Err(SumError::Overflow(2))));
+    }
+
+    // Every number a u16 can hold is a port, except 0, and nothing bigger is
+    #[test]
+    fn ports_are_exactly_1_to_65535(n in 0u32..200_000) {
+        let port: Result<Port, _> = n.to_string().parse();
+        prop_assert_eq!(port.is_ok(), (1..=65535).contains(&n));
+        if let Ok(port) = port {
+            prop_assert_eq!(u32::from(port.get()), n);
+        }
+    }
+
+    // Whitespace around a number, and the way it's written, make no difference
+    #[test]
+    fn friendly_parsing_ignores_padding(n in any::<u64>(), before in "[ \t]{0,3}", after in "[ \t]{0,3}") {
+        let padded = |text: String| format!("{}{}{}", before, text, after);
+        prop_assert_eq!(padded(n.to_string()).parse_u64_friendly(), Ok(n));
+        prop_assert_eq!(padded(format!("0x{:x}", n)).parse_u64_friendly(), Ok(n));
+        prop_assert!(!padded(n.to_string()).is_blank());
+        prop_assert!(padded(String::new()).is_blank());
+    }
+
+    // Slicing by characters never splits one, whatever the text is made of
+    #[test]
+    fn first_chars_takes_whole_characters(text in "\\PC*", n in 0usize..10) {
+        let expected: String = text.chars().take(n).collect();
+        prop_assert_eq!(first_chars(&text, n), &expected[..]);
+    }
+
+    // The longest rise really rises, and no longer one is hiding anywhere in the numbers
+    #[test]
+    fn longest_rise_is_rising_and_longest(numbers in vec(0u64..20, 0..32)) {
+        let rise = longest_rise(&numbers);
+        prop_assert!(rise.windows(2).all(|pair| pair[0] < pair[1]));
+        prop_assert_eq!(rise.is_empty(), numbers.is_empty());
+        let longer = rise.len() + 1;
+        prop_assert!(numbers.windows(longer).all(|run| run.windows(2).any(|pair| pair[0] >= pair[1])));
+    }
+
+    // Quickselect finds the same middle number as sorting everything would
+    #[test]
+    fn median_matches_a_full_sort(mut numbers in vec(any::<u64>(), 1..64)) {
+        let mut sorted = numbers.clone();
+        sorted.sort_unstable();
+        prop_assert_eq!(median(&mut numbers), Some(sorted[sorted.len() / 2]));
+    }
+
+    // Reading backwards a chunk at a time finds the same lines as reading forwards,
+    // wherever the chunks happen to split them
+    #[test]
+    fn tail_finds_the_same_lines_backwards(text in "[ab\n]{0,10000}", count in 0usize..20) {
+        let start = start_of_last_lines(&mut Cursor::new(text.as_bytes()), count).unwrap() as usize;
+        let lines: Vec<&str> = text.lines().collect();
+        let expected = lines[lines.len().saturating_sub(count)..].join("\n");
+        prop_assert_eq!(text[start..].strip_suffix('\n').unwrap_or(&text[start..]), expected);
+    }
+
+    // Anything u64 accepts, Literal accepts too, and agrees on the value
+    #[test]
+    fn literals_accept_what_u64_does(text in "[0-9]{1,25}") {
+        let expected: Result<u64, ParseIntError> = text.parse();
+        if let Ok(n) = expected {
+            prop_assert_eq!(text.parse::<Literal>(), Ok(Literal(n)));
+        }
+    }
+}
+
+// Groups the digits in threes, the way you'd write 1_000_000
+fn with_separators(digits: &str) -> String {
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push('_');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
@@ -0,0 +1,135 @@
+//! Runs each demo's read path against the fixtures in `tests/fixtures`, checking
+//! that every demo succeeds or fails the way the slides say it does.
+
+extern crate demo_core;
+
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use demo_core::{ demo3, demo4, demo5, demo6, demo7, read_input_numbers, read_lines_all, ReadError };
+use demo_core::demo4::Source;
+use demo_core::encoding::Encoding;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+fn lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn valid_file() {
+    let path = fixture("valid.txt");
+    assert_eq!(demo3::read_file(&path).unwrap(), lines(&["1", "2", "3"]));
+    assert_eq!(demo4::with_map_err(&Source::File(&path)).unwrap(), vec![1, 2, 3]);
+    assert_eq!(demo5::read_file(&path).unwrap(), lines(&["1", "2", "3"]));
+    assert_eq!(demo6::read_values::<u64>(&path, None).unwrap(), vec![1, 2, 3]);
+    assert_eq!(demo7::read_file(&path).unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn missing_file() {
+    let path = fixture("missing.txt");
+    assert_eq!(demo3::read_file(&path), Err("Could not open file"));
+    match demo4::with_map_err(&Source::File(&path)) {
+        Err(ReadError::InFile(_, ref e)) => match **e {
+            ReadError::Io(ref e) => assert_eq!(e.kind(), ErrorKind::NotFound),
+            ref other => panic!("expected an I/O error, got {:?}", other)
+        },
+        other => panic!("expected an I/O error, got {:?}", other)
+    }
+    match demo5::read_file(&path) {
+        Err(ReadError::Io(ref e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        other => panic!("expected an I/O error, got {:?}", other)
+    }
+    match demo6::read_values::<u64>(&path, None) {
+        Err(ReadError::Io(ref e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        other => panic!("expected an I/O error, got {:?}", other)
+    }
+    match demo7::read_file(&path) {
+        Err(ReadError::Io(ref e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        other => panic!("expected an I/O error, got {:?}", other)
+    }
+}
+
+#[test]
+fn bad_utf8() {
+    let path = fixture("bad-utf8.txt");
+    assert_eq!(demo3::read_file(&path), Err("An error occured while reading a line"));
+    match demo4::with_map_err(&Source::File(&path)) {
+        Err(ReadError::InFile(_, ref e)) => assert!(matches!(**e, ReadError::Decode(Encoding::Utf8))),
+        other => panic!("expected a decode error, got {:?}", other)
+    }
+    match demo5::read_file(&path) {
+        Err(ReadError::Decode(Encoding::Utf8)) => {},
+        other => panic!("expected a decode error, got {:?}", other)
+    }
+    match demo6::read_values::<u64>(&path, None) {
+        Err(ReadError::Decode(Encoding::Utf8)) => {},
+        other => panic!("expected a decode error, got {:?}", other)
+    }
+    match demo7::read_file(&path) {
+        Err(ReadError::Decode(Encoding::Utf8)) => {},
+        other => panic!("expected a decode error, got {:?}", other)
+    }
+}
+
+#[test]
+fn non_numeric_lines() {
+    let path = fixture("non-numeric.txt");
+    // Demos 3 and 5 only read lines, so there's nothing to go wrong
+    assert_eq!(demo3::read_file(&path).unwrap(), lines(&["1", "two", "3"]));
+    assert_eq!(demo5::read_file(&path).unwrap(), lines(&["1", "two", "3"]));
+    match demo6::read_values::<u64>(&path, None) {
+        Err(ReadError::BadLine(ref e)) => {
+            assert_eq!(e.line, 2);
+            assert_eq!(e.text, "two");
+        },
+        other => panic!("expected a parse error, got {:?}", other)
+    }
+    assert_eq!(demo4::with_map_err(&Source::File(&path)).unwrap_err().to_string(), format!("{}:2: invalid digit found in string: 'two'", path.display()));
+    match demo7::read_file(&path) {
+        Err(ReadError::BadLine(ref e)) => {
+            assert_eq!(e.line, 2);
+            assert_eq!(e.text, "two");
+        },
+        other => panic!("expected a parse error, got {:?}", other)
+    }
+}
+
+#[test]
+fn errors_name_the_file() {
+    let path = fixture("non-numeric.txt");
+    let err = demo7::read_file(&path).unwrap_err().in_file("non-numeric.txt");
+    assert_eq!(err.to_string(), "non-numeric.txt:2: invalid digit found in string: 'two'");
+}
+
+#[test]
+fn several_files_concatenate() {
+    let lines = read_lines_all(&[fixture("valid.txt"), fixture("non-numeric.txt")]).unwrap();
+    assert_eq!(lines, self::lines(&["1", "2", "3", "1", "two", "3"]));
+    let missing = fixture("missing.txt");
+    match read_lines_all(&[fixture("valid.txt"), missing.clone()]).unwrap_err() {
+        ReadError::InFile(path, _) => assert_eq!(path, missing),
+        other => panic!("expected the missing file to be named, got {:?}", other)
+    }
+}
+
+#[test]
+fn input_numbers_name_the_file() {
+    let valid = fixture("valid.txt");
+    assert_eq!(read_input_numbers(valid.to_str()).unwrap(), vec![1, 2, 3]);
+    let path = fixture("non-numeric.txt");
+    let err = read_input_numbers(path.to_str()).unwrap_err();
+    assert_eq!(err.to_string(), format!("{}:2: invalid digit found in string: 'two'", path.display()));
+}
+
+#[test]
+fn stdin_errors_name_no_file() {
+    let path = fixture("non-numeric.txt");
+    let named = demo7::read_file(&path).unwrap_err().in_input(Some("non-numeric.txt"));
+    assert_eq!(named.to_string(), "non-numeric.txt:2: invalid digit found in string: 'two'");
+    let unnamed = demo7::read_file(&path).unwrap_err().in_input(None::<&str>);
+    assert!(!matches!(unnamed, ReadError::InFile(..)), "{:?}", unnamed);
+}
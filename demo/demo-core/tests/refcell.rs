@@ -0,0 +1,37 @@
+//! The shared cache from the refcell demo, and what happens when its borrowing
+//! rules - checked at run time, not compile time - are broken.
+
+extern crate demo_core;
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use demo_core::demo_refcell::{ Reader, SharedCache };
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+#[test]
+fn readers_share_what_they_load() {
+    let cache = SharedCache::default();
+    let first = Reader::new("first", Rc::clone(&cache));
+    let second = Reader::new("second", Rc::clone(&cache));
+    let from_file = first.load(&fixture("valid.txt")).unwrap();
+    let from_cache = second.load(&fixture("valid.txt")).unwrap();
+    assert_eq!(*from_file, vec![1, 2, 3]);
+    assert!(Rc::ptr_eq(&from_file, &from_cache));
+    assert_eq!(first.stats(), (0, 1));
+    assert_eq!(second.stats(), (1, 0));
+}
+
+// Holding a borrow of the cache while a reader adds to it compiles without complaint,
+// and panics when the reader asks for borrow_mut
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn loading_while_the_cache_is_borrowed_panics() {
+    let cache = SharedCache::default();
+    let reader = Reader::new("reader", Rc::clone(&cache));
+    let _entries = cache.borrow();
+    let _ = reader.load(&fixture("valid.txt"));
+}
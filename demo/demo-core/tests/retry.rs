@@ -0,0 +1,106 @@
+//! Retrying reads which fail with I/O errors, waiting longer after each failure.
+
+extern crate demo_core;
+
+use std::cell::Cell;
+use std::io::{ self, Cursor, ErrorKind };
+use std::num::ParseIntError;
+use std::time::Duration;
+
+use demo_core::{ read_lines_from, ReadError };
+use demo_core::demo_retry::FlakyReader;
+use demo_core::retry::{ is_transient, retry, RetryPolicy };
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+// No waiting, so the tests don't
+fn immediate(attempts: u32) -> RetryPolicy {
+    RetryPolicy::new().attempts(attempts).delay(Duration::ZERO)
+}
+
+fn reset() -> ReadError {
+    ReadError::Io(io::Error::new(ErrorKind::ConnectionReset, "reset"))
+}
+
+#[test]
+fn each_wait_is_longer_up_to_the_maximum() {
+    let policy = RetryPolicy::new().attempts(6).delay(ms(100)).factor(3).max_delay(ms(1000));
+    assert_eq!(policy.delays().collect::<Vec<_>>(), vec![ms(100), ms(300), ms(900), ms(1000), ms(1000)]);
+    assert_eq!(RetryPolicy::new().attempts(1).delays().count(), 0);
+    // Never fewer than one attempt
+    assert_eq!(RetryPolicy::new().attempts(0).attempts, 1);
+}
+
+#[test]
+fn io_errors_are_transient_and_the_rest_are_not() {
+    assert!(is_transient(&reset()));
+    assert!(is_transient(&reset().in_file("numbers.txt")));
+    assert!(!is_transient(&ReadError::Parse("x".parse::<u64>().unwrap_err())));
+}
+
+// Asking again won't make a missing file appear, or an unreadable one readable
+#[test]
+fn a_missing_or_forbidden_file_is_not_transient() {
+    for kind in [ErrorKind::NotFound, ErrorKind::PermissionDenied] {
+        let e: ReadError = ReadError::Io(io::Error::new(kind, "no"));
+        assert!(!is_transient(&e));
+        assert!(!is_transient(&e.in_file("numbers.txt")));
+    }
+    let mut calls = 0;
+    let result = retry(|| {
+        calls += 1;
+        read_lines_from(io::BufReader::new(std::fs::File::open("tests/fixtures/missing.txt")?))
+    }, &immediate(3));
+    assert!(result.is_err());
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn tries_again_until_it_works() {
+    let mut calls = 0;
+    let result = retry(|| {
+        calls += 1;
+        if calls < 3 { Err(reset()) } else { Ok(calls) }
+    }, &immediate(3));
+    assert_eq!(result.unwrap(), 3);
+}
+
+#[test]
+fn gives_up_with_the_last_error() {
+    let mut calls = 0;
+    let result: Result<(), ReadError> = retry(|| {
+        calls += 1;
+        Err(ReadError::Io(io::Error::other(format!("failure {}", calls))))
+    }, &immediate(4));
+    match result {
+        Err(ReadError::Io(e)) => assert_eq!(e.to_string(), "failure 4"),
+        other => panic!("expected the last I/O error, got {:?}", other)
+    }
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn other_errors_are_not_retried() {
+    let mut calls = 0;
+    let result: Result<u64, ReadError<ParseIntError>> = retry(|| {
+        calls += 1;
+        Ok("x".parse::<u64>()?)
+    }, &immediate(3));
+    assert!(matches!(result, Err(ReadError::Parse(_))));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn a_flaky_reader_fails_the_first_reads_across_attempts() {
+    let failures = Cell::new(2);
+    let mut calls = 0;
+    let lines: Result<Vec<String>, ReadError> = retry(|| {
+        calls += 1;
+        read_lines_from(io::BufReader::new(FlakyReader::new(Cursor::new("1\n2\n"), &failures)))
+    }, &immediate(3));
+    assert_eq!(lines.unwrap(), vec!["1", "2"]);
+    assert_eq!(calls, 3);
+    assert_eq!(failures.get(), 0);
+}
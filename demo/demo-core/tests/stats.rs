@@ -0,0 +1,52 @@
+//! How Stats prints, which depends on what the format string asks for, and the
+//! other figures the `stats` command works out.
+
+extern crate demo_core;
+
+use demo_core::stats::{ median, std_dev, Stats };
+
+#[test]
+fn display_follows_the_format_specifier() {
+    let stats = Stats::of(&[1, 2, 4]);
+    assert_eq!(format!("{}", stats), "3 numbers, sum 7, from 1 to 4, mean 2.33");
+    assert_eq!(format!("{:.0}", stats), "3 numbers, sum 7, from 1 to 4, mean 2");
+    assert_eq!(format!("[{:>42.1}]", stats), "[   3 numbers, sum 7, from 1 to 4, mean 2.3]");
+    assert_eq!(format!("{:#}", stats).lines().count(), 5);
+    assert_eq!(format!("{}", Stats::of(&[])), "no numbers");
+}
+
+#[test]
+fn debug_shows_the_range_rather_than_options() {
+    let stats = Stats::of(&[5, 1]);
+    assert_eq!(format!("{:?}", stats), "Stats { count: 2, sum: 6, range: 1..=5, mean: 3.00 }");
+    assert_eq!(format!("{:?}", Stats::of(&[])), "Stats { count: 0, sum: 0 }");
+}
+
+#[test]
+fn the_sum_is_wider_than_the_numbers() {
+    let stats = Stats::of(&[u64::MAX, u64::MAX]);
+    assert_eq!(stats.sum, 2 * u128::from(u64::MAX));
+    assert_eq!(stats.mean(), Some(u64::MAX as f64));
+}
+
+#[test]
+fn merged_stats_are_those_of_all_the_numbers() {
+    let (first, second) = ([3, 9, 1], [4, 12]);
+    assert_eq!(Stats::of(&first).merge(Stats::of(&second)), Stats::of(&[3, 9, 1, 4, 12]));
+    assert_eq!(Stats::of(&first).merge(Stats::default()), Stats::of(&first));
+    assert_eq!(Stats::default().with(7), Stats::of(&[7]));
+}
+
+#[test]
+fn median_takes_the_middle_or_the_mean_of_two() {
+    assert_eq!(median(&[]), None);
+    assert_eq!(median(&[7, 1, 4]), Some(4.0));
+    assert_eq!(median(&[7, 1, 4, 2]), Some(3.0));
+}
+
+#[test]
+fn std_dev_is_the_population_one() {
+    assert_eq!(std_dev(&[]), None);
+    assert_eq!(std_dev(&[5, 5, 5]), Some(0.0));
+    assert_eq!(std_dev(&[2, 4, 4, 4, 5, 5, 7, 9]), Some(2.0));
+}
@@ -0,0 +1,49 @@
+//! Following a file as it grows, as `tail -f` does.
+
+extern crate demo_core;
+extern crate tempfile;
+
+use std::fs::{ File, OpenOptions };
+use std::io::{ Cursor, Seek, SeekFrom, Write };
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::sync::mpsc;
+use std::thread;
+
+use demo_core::LineStream;
+use demo_core::tail::{ follow, last_lines };
+
+#[test]
+fn follow_copies_what_is_added_until_stopped() {
+    let path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    let mut log = OpenOptions::new().append(true).open(&path).unwrap();
+    writeln!(log, "already there").unwrap();
+
+    let mut file = File::open(&path).unwrap();
+    file.seek(SeekFrom::End(0)).unwrap();
+    let (events, changes) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    let following = {
+        let stop = Arc::clone(&stop);
+        thread::spawn(move || {
+            let mut out = Vec::new();
+            follow(&mut file, &mut out, &changes, &stop).unwrap();
+            out
+        })
+    };
+
+    writeln!(log, "one").unwrap();
+    events.send(()).unwrap();
+    writeln!(log, "two").unwrap();
+    stop.store(true, Ordering::SeqCst);
+    events.send(()).unwrap();
+    assert_eq!(String::from_utf8(following.join().unwrap()).unwrap(), "one\ntwo\n");
+}
+
+#[test]
+fn last_lines_takes_a_count_larger_than_the_input() {
+    for &count in &[3, 100_000_000_000, usize::MAX] {
+        let last = last_lines(LineStream::new(Cursor::new("1\n2\n3\n")), count).unwrap();
+        assert_eq!(last, ["1", "2", "3"]);
+    }
+}
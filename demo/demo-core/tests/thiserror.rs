@@ -0,0 +1,81 @@
+//! Demo 6's ReadError rewritten with thiserror: the messages, the causes and the
+//! conversions it derives.
+
+extern crate anyhow;
+extern crate demo_core;
+
+use std::error::Error;
+use std::io::{ self, Cursor };
+use std::path::Path;
+
+use demo_core::demo_thiserror::{ read_files, read_values, ReadError };
+use demo_core::exit_codes;
+use demo_core::registry::DemoError;
+
+fn messages(e: &dyn Error) -> Vec<String> {
+    let mut messages = vec![e.to_string()];
+    let mut cause = e.source();
+    while let Some(e) = cause {
+        messages.push(e.to_string());
+        cause = e.source();
+    }
+    messages
+}
+
+#[test]
+fn reads_numbers_skipping_blank_lines_and_comments() {
+    assert_eq!(read_values(Cursor::new("1\n\n# two\n3\n")).unwrap(), vec![1, 3]);
+}
+
+#[test]
+fn from_converts_with_question_mark() {
+    fn parse(text: &str) -> Result<u64, ReadError> {
+        Ok(text.parse::<u64>()?)
+    }
+    let e = parse("x").unwrap_err();
+    assert!(matches!(e, ReadError::Parse(_)));
+    assert_eq!(messages(&e), vec!["could not parse number", "invalid digit found in string"]);
+    assert_eq!(exit_codes::for_demo_error(&DemoError::from(e)), exit_codes::PARSE);
+}
+
+#[test]
+fn a_bad_line_is_transparent() {
+    let e = read_values(Cursor::new("1\ntwo\n")).unwrap_err();
+    assert_eq!(messages(&e), vec!["line 2: invalid digit found in string: 'two'"]);
+}
+
+#[test]
+fn the_file_is_the_first_message_and_the_error_in_it_its_cause() {
+    let files = vec!["tests/fixtures/valid.txt".to_string(), "tests/fixtures/missing.txt".to_string()];
+    let e = read_files(&files).unwrap_err();
+    match e {
+        ReadError::InFile { ref path, ref error } => {
+            assert_eq!(path.to_str(), Some("tests/fixtures/missing.txt"));
+            assert!(matches!(**error, ReadError::Io(ref e) if e.kind() == io::ErrorKind::NotFound));
+        },
+        ref other => panic!("expected InFile, got {:?}", other)
+    }
+    assert_eq!(&messages(&e)[..2], ["in tests/fixtures/missing.txt", "could not read file"]);
+    assert_eq!(exit_codes::for_demo_error(&DemoError::from(e)), exit_codes::IO);
+}
+
+#[test]
+fn the_runner_reports_where_it_happened() {
+    let files = vec!["tests/fixtures/non-numeric.txt".to_string()];
+    match DemoError::from(read_files(&files).unwrap_err()) {
+        DemoError::Read(demo_core::ReadError::InFile(path, e)) => {
+            assert_eq!(path, Path::new("tests/fixtures/non-numeric.txt"));
+            assert!(matches!(*e, demo_core::ReadError::BadLine(ref e) if e.line == 2), "{:?}", e);
+        },
+        e => panic!("not attributed to the file: {:?}", e)
+    }
+}
+
+#[test]
+fn an_application_can_take_it_as_anyhow() {
+    fn run() -> anyhow::Result<Vec<u64>> {
+        Ok(read_values(Cursor::new("1\ntwo\n"))?)
+    }
+    let e = run().unwrap_err();
+    assert!(e.downcast_ref::<ReadError>().is_some());
+}
@@ -0,0 +1,41 @@
+//! The thread-local demo's parsing, which each worker thread does with a scratch
+//! buffer of its own.
+
+extern crate demo_core;
+
+use demo_core::ReadError;
+use demo_core::demo_thread_local::stats_parallel;
+use demo_core::stats::Stats;
+
+fn lines(text: &[&str]) -> Vec<String> {
+    text.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn separators_between_the_digits_are_skipped() {
+    let stats = stats_parallel(&lines(&["1,000", "2_000", " 30 ", "4,000,000"])).unwrap();
+    assert_eq!(stats, Stats::of(&[1000, 2000, 30, 4_000_000]));
+}
+
+#[test]
+fn every_line_is_parsed_once_whatever_the_thread() {
+    let numbers: Vec<u64> = (1..=101).collect();
+    let text: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    assert_eq!(stats_parallel(&text).unwrap(), Stats::of(&numbers));
+    assert_eq!(stats_parallel(&[]).unwrap(), Stats::default());
+}
+
+// Each thread is given a slice from the middle of the input, and still reports the
+// line's number in the whole of it
+#[test]
+fn a_bad_line_is_reported_where_it_is_in_the_input() {
+    let mut text = lines(&["1"; 12]);
+    text[9] = "1.5".to_string();
+    match stats_parallel(&text) {
+        Err(ReadError::BadLine(e)) => {
+            assert_eq!(e.line, 10);
+            assert_eq!(e.text, "1.5");
+        },
+        other => panic!("expected a bad line, got {:?}", other)
+    }
+}
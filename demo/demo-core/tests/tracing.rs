@@ -0,0 +1,83 @@
+//! The spans and fields the tracing demo records, as a JSON subscriber writes them.
+
+extern crate demo_core;
+extern crate serde_json;
+extern crate tracing;
+extern crate tracing_subscriber;
+
+use std::io::{ self, Write };
+use std::sync::{ Arc, Mutex };
+
+use serde_json::Value;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use demo_core::demo_tracing::total_file;
+
+// Somewhere for the subscriber to write which the test can read afterwards
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Buffer {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+#[test]
+fn events_carry_their_fields_and_spans() {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt().json().with_writer(move || writer.clone()).finish();
+    // with_default only affects this thread, for as long as the closure runs
+    let total = tracing::subscriber::with_default(subscriber, || total_file("tests/fixtures/non-numeric.txt"));
+    assert_eq!(total.unwrap(), 4);
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let events: Vec<Value> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    let skipped = events.iter().find(|event| event["fields"]["message"] == "skipping").unwrap();
+    assert_eq!(skipped["level"], "WARN");
+    assert_eq!(skipped["fields"]["line"], 2);
+    assert_eq!(skipped["fields"]["text"], "two");
+    assert_eq!(skipped["spans"][0]["file_name"], "tests/fixtures/non-numeric.txt");
+    assert_eq!(skipped["span"]["name"], "parse");
+
+    // Fields recorded after the span opened are there for the events after that
+    let totalled = events.iter().find(|event| event["fields"]["message"] == "totalled").unwrap();
+    assert_eq!(totalled["span"]["line_count"], 3);
+    assert!(totalled["span"]["duration_ms"].is_f64());
+    assert_eq!(totalled["fields"]["skipped"], 1);
+}
+
+#[test]
+fn the_shared_reader_and_parser_have_spans() {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::TRACE)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(move || writer.clone())
+        .finish();
+    tracing::subscriber::with_default(subscriber, || total_file("tests/fixtures/valid.txt")).unwrap();
+
+    // Each span prints an event when it closes, inside the spans it was opened in
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let closed: Vec<Value> = output.lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .filter(|event| event["fields"]["message"] == "close")
+        .collect();
+    let read = closed.iter().find(|event| event["span"]["name"] == "read_lines").unwrap();
+    assert_eq!(read["span"]["path"], "tests/fixtures/valid.txt");
+    assert_eq!(read["spans"][1]["name"], "read");
+    let parsed: Vec<&Value> = closed.iter().filter(|event| event["span"]["name"] == "parse_line").collect();
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[0]["span"]["line"], 1);
+}
+
+#[test]
+fn the_total_is_wider_than_the_numbers() {
+    let total = total_file("tests/fixtures/u64-max.txt").unwrap();
+    assert_eq!(total, 2 * u128::from(u64::MAX));
+}
@@ -0,0 +1,24 @@
+//! Comparing text the way a reader would, from the unicode demo.
+
+extern crate demo_core;
+
+use std::borrow::Cow;
+
+use demo_core::demo_unicode::{ comparison_key, strip_invisible };
+
+#[test]
+fn composed_and_decomposed_accents_compare_equal() {
+    let composed = "Caf\u{e9}";
+    let decomposed = "cafe\u{301}";
+    assert_ne!(composed, decomposed);
+    assert_eq!(comparison_key(composed), comparison_key(decomposed));
+}
+
+// Only text which had something to strip is copied
+#[test]
+fn stripping_borrows_clean_text() {
+    assert!(matches!(strip_invisible("plain"), Cow::Borrowed("plain")));
+    assert_eq!(strip_invisible("\u{FEFF}zero\u{200B}width"), "zerowidth");
+    // The zero-width joiner holds the family together, so it stays
+    assert_eq!(strip_invisible("\u{1F468}\u{200D}\u{1F469}").chars().count(), 3);
+}
@@ -0,0 +1,60 @@
+//! What the wc demo counts, and how it reads its flags.
+
+extern crate demo_core;
+
+use std::io::Cursor;
+
+use demo_core::demo_wc::prelude::*;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn counts_match_wc_for_newline_terminated_text() {
+    let counts = count(Cursor::new("one two\n  three  \n\nnaïve\n")).unwrap();
+    assert_eq!(counts, Counts { lines: 4, words: 4, chars: 25, bytes: 26 });
+}
+
+#[test]
+fn a_last_line_without_a_newline_is_not_a_line() {
+    assert_eq!(count(Cursor::new("a")).unwrap(), Counts { lines: 0, words: 1, chars: 1, bytes: 1 });
+    assert_eq!(count(Cursor::new("")).unwrap(), Counts::default());
+}
+
+#[test]
+fn windows_line_endings_are_counted_as_they_are() {
+    let counts = count(Cursor::new("one two\r\nthree\r\n")).unwrap();
+    assert_eq!(counts, Counts { lines: 2, words: 3, chars: 16, bytes: 16 });
+}
+
+#[test]
+fn invalid_text_is_still_counted() {
+    let counts = count(Cursor::new(&b"fine\n\xff\xfe ok\n"[..])).unwrap();
+    assert_eq!(counts, Counts { lines: 2, words: 2, chars: 9, bytes: 11 });
+}
+
+#[test]
+fn flags_can_be_combined_and_default_to_lines_words_and_bytes() {
+    let all = args(&["-lw", "-m", "a.txt", "-", "b.txt"]);
+    let (show, files) = parse_args(&all).unwrap();
+    assert_eq!(show, Show { lines: true, words: true, chars: true, bytes: false });
+    assert_eq!(files, vec!["a.txt", "-", "b.txt"]);
+
+    let none = args(&[]);
+    let (show, files) = parse_args(&none).unwrap();
+    assert_eq!(show, Show { lines: true, words: true, chars: false, bytes: true });
+    assert!(files.is_empty());
+
+    assert!(parse_args(&args(&["-q"])).is_err());
+}
+
+#[test]
+fn the_prelude_names_the_same_items_as_the_module() {
+    // Two paths to one type, so a value from one has the type of the other
+    let counts: demo_core::demo_wc::Counts = Counts::default();
+    assert_eq!(counts, Counts { lines: 0, words: 0, chars: 0, bytes: 0 });
+    let c = args(&["-c"]);
+    let (show, _): (demo_core::demo_wc::Show, Vec<&str>) = parse_args(&c).unwrap();
+    assert_eq!(Ok((show, vec![])), demo_core::demo_wc::parse_args(&c).map_err(|e| e.to_string()));
+}
@@ -0,0 +1,15 @@
+//! Reporting every invalid field:
+//! an applicative Validated type which collects errors, in place of FluentValidation.
+//!
+//! The `accumulate` demo on its own: `cargo run --example accumulate -- <args>` is
+//! `demo run accumulate <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_accumulate;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("accumulate", demo_accumulate::main)
+}
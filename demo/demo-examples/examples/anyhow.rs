@@ -0,0 +1,15 @@
+//! Application errors with anyhow:
+//! anyhow::Result and context in place of a hand-written error enum.
+//!
+//! The `anyhow` demo on its own: `cargo run --example anyhow -- <args>` is
+//! `demo run anyhow <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_anyhow;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("anyhow", demo_anyhow::main)
+}
@@ -0,0 +1,14 @@
+//! Asynchronous streams: Stream and while let ... .await.
+//!
+//! The `async-stream` demo on its own: `cargo run --example async-stream -- <args>` is
+//! `demo run async-stream <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_async_stream;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("async-stream", demo_async_stream::main)
+}
@@ -0,0 +1,14 @@
+//! Counting across threads: AtomicU64 in place of Interlocked.Increment.
+//!
+//! The `atomics` demo on its own: `cargo run --example atomics -- <args>` is
+//! `demo run atomics <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_atomics;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("atomics", demo_atomics::main)
+}
@@ -0,0 +1,14 @@
+//! Changing a collection while using it: & and &mut in place of InvalidOperationException.
+//!
+//! The `borrowing` demo on its own: `cargo run --example borrowing -- <args>` is
+//! `demo run borrowing <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_borrowing;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("borrowing", demo_borrowing::main)
+}
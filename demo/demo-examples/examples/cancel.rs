@@ -0,0 +1,14 @@
+//! Cancelling with Ctrl-C: a shared AtomicBool as a CancellationToken.
+//!
+//! The `cancel` demo on its own: `cargo run --example cancel -- <args>` is
+//! `demo run cancel <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_cancel;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("cancel", demo_cancel::main)
+}
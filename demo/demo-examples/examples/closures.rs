@@ -0,0 +1,14 @@
+//! Closures and delegates: Fn, FnMut and FnOnce in place of Func and Action.
+//!
+//! The `closures` demo on its own: `cargo run --example closures -- <args>` is
+//! `demo run closures <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_closures;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("closures", demo_closures::main)
+}
@@ -0,0 +1,14 @@
+//! A tour of the collections: std::collections in place of System.Collections.Generic.
+//!
+//! The `collections` demo on its own: `cargo run --example collections -- <args>` is
+//! `demo run collections <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_collections;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("collections", demo_collections::main)
+}
@@ -0,0 +1,14 @@
+//! Converting between types: From, Into and TryFrom in place of conversion operators.
+//!
+//! The `conversions` demo on its own: `cargo run --example conversions -- <args>` is
+//! `demo run conversions <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_conversions;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("conversions", demo_conversions::main)
+}
@@ -0,0 +1,14 @@
+//! Reading typed records from CSV: the csv crate and #[derive(Deserialize)] in place of CsvHelper.
+//!
+//! The `csv` demo on its own: `cargo run --example csv -- <args>` is
+//! `demo run csv <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_csv;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("csv", demo_csv::main)
+}
@@ -0,0 +1,14 @@
+//! Dropping repeated lines: HashSet<&str> and Vec::dedup_by in place of Distinct().
+//!
+//! The `dedup` demo on its own: `cargo run --example dedup -- <args>` is
+//! `demo run dedup <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_dedup;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("dedup", demo_dedup::main)
+}
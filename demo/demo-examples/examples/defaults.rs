@@ -0,0 +1,14 @@
+//! Settings with defaults: Default and struct update syntax in place of optional parameters.
+//!
+//! The `defaults` demo on its own: `cargo run --example defaults -- <args>` is
+//! `demo run defaults <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_defaults;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("defaults", demo_defaults::main)
+}
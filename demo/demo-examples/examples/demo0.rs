@@ -0,0 +1,17 @@
+//! Introducing unwrap: unwrap, panicking on failure.
+//!
+//! The `demo0` demo on its own: `cargo run --example demo0 -- <args>` is
+//! `demo run demo0 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo0;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo0", |args| {
+        demo0::main(args);
+        Ok(())
+    })
+}
@@ -0,0 +1,14 @@
+//! Match your way to success: match on Option and Result.
+//!
+//! The `demo1` demo on its own: `cargo run --example demo1 -- <args>` is
+//! `demo run demo1 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo1;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo1", demo1::main)
+}
@@ -0,0 +1,17 @@
+//! A more complicated example: unwrap around file I/O.
+//!
+//! The `demo2` demo on its own: `cargo run --example demo2 -- <args>` is
+//! `demo run demo2 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo2;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo2", |args| {
+        demo2::main(args);
+        Ok(())
+    })
+}
@@ -0,0 +1,14 @@
+//! Let's try matching again: match, returning Result<_, &str>.
+//!
+//! The `demo3` demo on its own: `cargo run --example demo3 -- <args>` is
+//! `demo run demo3 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo3;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo3", demo3::main)
+}
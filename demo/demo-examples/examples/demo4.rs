@@ -0,0 +1,14 @@
+//! Functional style: map, map_err, and_then, or_else and unwrap_or_else.
+//!
+//! The `demo4` demo on its own: `cargo run --example demo4 -- <args>` is
+//! `demo run demo4 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo4;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo4", demo4::main)
+}
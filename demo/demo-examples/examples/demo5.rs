@@ -0,0 +1,14 @@
+//! Getting closer: try! for early returns.
+//!
+//! The `demo5` demo on its own: `cargo run --example demo5 -- <args>` is
+//! `demo run demo5 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo5;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo5", demo5::main)
+}
@@ -0,0 +1,15 @@
+//! Introducing moar complexity:
+//! custom error enum with From conversions, generic over the parsed type.
+//!
+//! The `demo6` demo on its own: `cargo run --example demo6 -- <args>` is
+//! `demo run demo6 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo6;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo6", demo6::main)
+}
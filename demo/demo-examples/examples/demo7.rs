@@ -0,0 +1,14 @@
+//! The question mark operator: ? and returning Result from main.
+//!
+//! The `demo7` demo on its own: `cargo run --example demo7 -- <args>` is
+//! `demo run demo7 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo7;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo7", |args| Ok(demo7::main(args)?))
+}
@@ -0,0 +1,14 @@
+//! Async all the way down: async/await with tokio.
+//!
+//! The `demo8` demo on its own: `cargo run --example demo8 -- <args>` is
+//! `demo run demo8 <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo8;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("demo8", |args| Ok(demo8::main(args)?))
+}
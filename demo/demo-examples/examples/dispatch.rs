@@ -0,0 +1,14 @@
+//! Static and dynamic dispatch: generics and dyn in place of virtual calls.
+//!
+//! The `dispatch` demo on its own: `cargo run --example dispatch -- <args>` is
+//! `demo run dispatch <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_dispatch;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("dispatch", demo_dispatch::main)
+}
@@ -0,0 +1,14 @@
+//! Cleaning up at the end of a scope: Drop in place of IDisposable and using.
+//!
+//! The `drop` demo on its own: `cargo run --example drop -- <args>` is
+//! `demo run drop <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_drop;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("drop", demo_drop::main)
+}
@@ -0,0 +1,14 @@
+//! Why Rust strings are always UTF-8: validating bytes before they become a String.
+//!
+//! The `encoding` demo on its own: `cargo run --example encoding -- <args>` is
+//! `demo run encoding <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_encoding;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("encoding", demo_encoding::main)
+}
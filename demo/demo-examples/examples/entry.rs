@@ -0,0 +1,14 @@
+//! Counting with one lookup: the Entry API in place of TryGetValue and AddOrUpdate.
+//!
+//! The `entry` demo on its own: `cargo run --example entry -- <args>` is
+//! `demo run entry <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_entry;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("entry", demo_entry::main)
+}
@@ -0,0 +1,14 @@
+//! Enums with data: enums and exhaustive match in place of class hierarchies.
+//!
+//! The `enums` demo on its own: `cargo run --example enums -- <args>` is
+//! `demo run enums <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_enums;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("enums", demo_enums::main)
+}
@@ -0,0 +1,15 @@
+//! Settings from environment variables:
+//! env::var, FromStr and envy in place of IConfiguration's environment provider.
+//!
+//! The `env` demo on its own: `cargo run --example env -- <args>` is
+//! `demo run env <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_env;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("env", demo_env::main)
+}
@@ -0,0 +1,15 @@
+//! Comparing, hashing and sorting records:
+//! Eq, Hash and Ord in place of Equals, GetHashCode and IComparable.
+//!
+//! The `equality` demo on its own: `cargo run --example equality -- <args>` is
+//! `demo run equality <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_equality;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("equality", demo_equality::main)
+}
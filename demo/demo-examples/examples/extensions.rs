@@ -0,0 +1,14 @@
+//! New methods on a type we don't own: extension traits in place of extension methods.
+//!
+//! The `extensions` demo on its own: `cargo run --example extensions -- <args>` is
+//! `demo run extensions <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_extensions;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("extensions", demo_extensions::main)
+}
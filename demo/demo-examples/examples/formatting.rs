@@ -0,0 +1,14 @@
+//! Formatting output: Display, Debug and format specifiers in place of ToString and format strings.
+//!
+//! The `formatting` demo on its own: `cargo run --example formatting -- <args>` is
+//! `demo run formatting <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_formatting;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("formatting", demo_formatting::main)
+}
@@ -0,0 +1,14 @@
+//! Where's yield return?: state machines, channels and coroutines.
+//!
+//! The `generator` demo on its own: `cargo run --example generator -- <args>` is
+//! `demo run generator <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_generator;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("generator", demo_generator::main)
+}
@@ -0,0 +1,14 @@
+//! Generics with trait bounds: trait bounds and where clauses in place of generic constraints.
+//!
+//! The `generics` demo on its own: `cargo run --example generics -- <args>` is
+//! `demo run generics <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_generics;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("generics", demo_generics::main)
+}
@@ -0,0 +1,14 @@
+//! Filtering lines with a regex: the regex crate in place of System.Text.RegularExpressions.
+//!
+//! The `grep` demo on its own: `cargo run --example grep -- <args>` is
+//! `demo run grep <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_grep;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("grep", demo_grep::main)
+}
@@ -0,0 +1,14 @@
+//! Writing an iterator: impl Iterator in place of IEnumerator<T>.
+//!
+//! The `iterator` demo on its own: `cargo run --example iterator -- <args>` is
+//! `demo run iterator <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_iterator;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("iterator", demo_iterator::main)
+}
@@ -0,0 +1,14 @@
+//! Typed JSON in and out: serde_json and #[derive] in place of System.Text.Json attributes.
+//!
+//! The `json` demo on its own: `cargo run --example json -- <args>` is
+//! `demo run json <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_json;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("json", demo_json::main)
+}
@@ -0,0 +1,14 @@
+//! Lazy evaluation: Iterator adaptors in place of deferred execution.
+//!
+//! The `lazy` demo on its own: `cargo run --example lazy -- <args>` is
+//! `demo run lazy <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_lazy;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("lazy", demo_lazy::main)
+}
@@ -0,0 +1,14 @@
+//! Skipping bad lines: collecting errors instead of returning the first.
+//!
+//! The `lenient` demo on its own: `cargo run --example lenient -- <args>` is
+//! `demo run lenient <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_lenient;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("lenient", demo_lenient::main)
+}
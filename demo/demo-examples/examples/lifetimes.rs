@@ -0,0 +1,14 @@
+//! Slices of a buffer: &str with lifetimes in place of Substring.
+//!
+//! The `lifetimes` demo on its own: `cargo run --example lifetimes -- <args>` is
+//! `demo run lifetimes <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_lifetimes;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("lifetimes", demo_lifetimes::main)
+}
@@ -0,0 +1,14 @@
+//! LINQ with iterators: Iterator adaptors and itertools in place of LINQ.
+//!
+//! The `linq` demo on its own: `cargo run --example linq -- <args>` is
+//! `demo run linq <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_linq;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("linq", demo_linq::main)
+}
@@ -0,0 +1,14 @@
+//! Logging from a library: the log facade and env_logger in place of ILogger and Serilog.
+//!
+//! The `logging` demo on its own: `cargo run --example logging -- <args>` is
+//! `demo run logging <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_logging;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("logging", demo_logging::main)
+}
@@ -0,0 +1,14 @@
+//! Values which might be missing: Option<T> in place of null, ?. and ??.
+//!
+//! The `option` demo on its own: `cargo run --example option -- <args>` is
+//! `demo run option <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_option;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("option", demo_option::main)
+}
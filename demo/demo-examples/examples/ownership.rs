@@ -0,0 +1,14 @@
+//! Ownership and moves: moves, clones and borrows in place of shared references.
+//!
+//! The `ownership` demo on its own: `cargo run --example ownership -- <args>` is
+//! `demo run ownership <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_ownership;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("ownership", demo_ownership::main)
+}
@@ -0,0 +1,14 @@
+//! Panics and where they stop: panic, catch_unwind and a panic hook in place of exceptions.
+//!
+//! The `panics` demo on its own: `cargo run --example panics -- <args>` is
+//! `demo run panics <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_panics;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("panics", demo_panics::main)
+}
@@ -0,0 +1,14 @@
+//! Data parallelism with rayon: par_iter, collecting into a Result.
+//!
+//! The `parallel` demo on its own: `cargo run --example parallel -- <args>` is
+//! `demo run parallel <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_parallel;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("parallel", demo_parallel::main)
+}
@@ -0,0 +1,14 @@
+//! Pattern matching on settings: destructuring, @ bindings, or-patterns and guards.
+//!
+//! The `patterns` demo on its own: `cargo run --example patterns -- <args>` is
+//! `demo run patterns <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_patterns;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("patterns", demo_patterns::main)
+}
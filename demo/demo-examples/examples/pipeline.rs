@@ -0,0 +1,14 @@
+//! A producer/consumer pipeline: mpsc channels between threads.
+//!
+//! The `pipeline` demo on its own: `cargo run --example pipeline -- <args>` is
+//! `demo run pipeline <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_pipeline;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("pipeline", demo_pipeline::main)
+}
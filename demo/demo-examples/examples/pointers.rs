@@ -0,0 +1,14 @@
+//! Box, Cow and Deref: smart pointers in place of references to objects.
+//!
+//! The `pointers` demo on its own: `cargo run --example pointers -- <args>` is
+//! `demo run pointers <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_pointers;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("pointers", demo_pointers::main)
+}
@@ -0,0 +1,14 @@
+//! Pipelines built from closures: impl Fn arguments and return types in place of Func.
+//!
+//! The `process` demo on its own: `cargo run --example process -- <args>` is
+//! `demo run process <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_process;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("process", demo_process::main)
+}
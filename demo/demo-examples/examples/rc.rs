@@ -0,0 +1,14 @@
+//! Sharing by reference counting: Rc, Arc and Weak in place of the garbage collector.
+//!
+//! The `rc` demo on its own: `cargo run --example rc -- <args>` is
+//! `demo run rc <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_rc;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("rc", demo_rc::main)
+}
@@ -0,0 +1,14 @@
+//! A shared, changeable cache: RefCell and Cell for mutation through shared references.
+//!
+//! The `refcell` demo on its own: `cargo run --example refcell -- <args>` is
+//! `demo run refcell <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_refcell;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("refcell", demo_refcell::main)
+}
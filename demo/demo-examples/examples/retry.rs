@@ -0,0 +1,14 @@
+//! Retrying a flaky read: a retry function with exponential backoff in place of Polly.
+//!
+//! The `retry` demo on its own: `cargo run --example retry -- <args>` is
+//! `demo run retry <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_retry;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("retry", demo_retry::main)
+}
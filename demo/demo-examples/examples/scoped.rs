@@ -0,0 +1,14 @@
+//! Threads which borrow: thread::scope in place of Arc.
+//!
+//! The `scoped` demo on its own: `cargo run --example scoped -- <args>` is
+//! `demo run scoped <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_scoped;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("scoped", demo_scoped::main)
+}
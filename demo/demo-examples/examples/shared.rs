@@ -0,0 +1,14 @@
+//! Shared state between threads: Arc<Mutex<T>> in place of lock(obj).
+//!
+//! The `shared` demo on its own: `cargo run --example shared -- <args>` is
+//! `demo run shared <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_shared;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("shared", demo_shared::main)
+}
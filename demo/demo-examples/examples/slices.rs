@@ -0,0 +1,14 @@
+//! Views into the numbers: slices in place of Span<T> and ArraySegment<T>.
+//!
+//! The `slices` demo on its own: `cargo run --example slices -- <args>` is
+//! `demo run slices <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_slices;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("slices", demo_slices::main)
+}
@@ -0,0 +1,15 @@
+//! Sorting and searching:
+//! stable and unstable sorts, and binary_search in place of Array.BinarySearch.
+//!
+//! The `sorting` demo on its own: `cargo run --example sorting -- <args>` is
+//! `demo run sorting <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_sorting;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("sorting", demo_sorting::main)
+}
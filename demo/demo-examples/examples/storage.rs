@@ -0,0 +1,14 @@
+//! Storage with its own error type: associated types in place of a second generic parameter.
+//!
+//! The `storage` demo on its own: `cargo run --example storage -- <args>` is
+//! `demo run storage <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_storage;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("storage", demo_storage::main)
+}
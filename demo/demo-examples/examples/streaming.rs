@@ -0,0 +1,14 @@
+//! Lazy iteration over a file: an Iterator of Results, one per line.
+//!
+//! The `streaming` demo on its own: `cargo run --example streaming -- <args>` is
+//! `demo run streaming <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_streaming;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("streaming", demo_streaming::main)
+}
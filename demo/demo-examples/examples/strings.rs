@@ -0,0 +1,14 @@
+//! Taking strings apart: UTF-8 String and &str in place of UTF-16 string.
+//!
+//! The `strings` demo on its own: `cargo run --example strings -- <args>` is
+//! `demo run strings <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_strings;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("strings", demo_strings::main)
+}
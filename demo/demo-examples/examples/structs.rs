@@ -0,0 +1,14 @@
+//! Structs and impl blocks: structs with associated functions in place of classes.
+//!
+//! The `structs` demo on its own: `cargo run --example structs -- <args>` is
+//! `demo run structs <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_structs;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("structs", demo_structs::main)
+}
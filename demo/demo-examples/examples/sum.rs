@@ -0,0 +1,28 @@
+//! Adds up a file of numbers, skipping blank lines and comments:
+//! `cargo run --example sum numbers.txt`
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::{ read_lines_with, ReadError, ReadOptions };
+use demo_core::exit_codes::Exit;
+use demo_core::numbers::parse_line;
+use demo_core::registry::DemoError;
+
+// Into a u128, which no number of u64s can overflow
+fn total(file_name: &str) -> Result<u128, ReadError> {
+    let options = ReadOptions::new().skip_blank(true).skip_comments(true);
+    let mut total = 0;
+    for (number, line) in read_lines_with(file_name, &options)? {
+        total += u128::from(parse_line::<u64>(number, &line)?);
+    }
+    Ok(total)
+}
+
+fn main() -> Exit {
+    demo_examples::run(|args| {
+        let file_name = args.first().ok_or_else(|| DemoError::Usage("Expected a file of numbers".to_string()))?;
+        println!("{}", total(file_name).map_err(|e| e.in_file(file_name))?);
+        Ok(())
+    })
+}
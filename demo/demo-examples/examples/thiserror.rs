@@ -0,0 +1,15 @@
+//! Library errors with thiserror:
+//! derive(Error) with #[from] and #[error] in place of an exception hierarchy.
+//!
+//! The `thiserror` demo on its own: `cargo run --example thiserror -- <args>` is
+//! `demo run thiserror <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_thiserror;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("thiserror", demo_thiserror::main)
+}
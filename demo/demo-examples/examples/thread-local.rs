@@ -0,0 +1,14 @@
+//! Per-thread scratch space: thread_local! in place of ThreadLocal<T>.
+//!
+//! The `thread-local` demo on its own: `cargo run --example thread-local -- <args>` is
+//! `demo run thread-local <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_thread_local;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("thread-local", demo_thread_local::main)
+}
@@ -0,0 +1,14 @@
+//! Reading files in parallel: thread::spawn, move closures and JoinHandle.
+//!
+//! The `threads` demo on its own: `cargo run --example threads -- <args>` is
+//! `demo run threads <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_threads;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("threads", demo_threads::main)
+}
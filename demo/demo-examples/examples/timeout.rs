@@ -0,0 +1,14 @@
+//! Giving up on a slow read: recv_timeout in place of Task.WaitAsync.
+//!
+//! The `timeout` demo on its own: `cargo run --example timeout -- <args>` is
+//! `demo run timeout <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_timeout;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("timeout", demo_timeout::main)
+}
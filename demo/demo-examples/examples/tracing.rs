@@ -0,0 +1,14 @@
+//! Spans and structured logging: tracing spans and fields in place of Activity and OpenTelemetry.
+//!
+//! The `tracing` demo on its own: `cargo run --example tracing -- <args>` is
+//! `demo run tracing <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_tracing;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("tracing", demo_tracing::main)
+}
@@ -0,0 +1,14 @@
+//! Traits and interfaces: traits, generics and dyn in place of interfaces.
+//!
+//! The `traits` demo on its own: `cargo run --example traits -- <args>` is
+//! `demo run traits <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_traits;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("traits", demo_traits::main)
+}
@@ -0,0 +1,15 @@
+//! Text as people read it:
+//! grapheme clusters and normalization in place of StringInfo and Normalize.
+//!
+//! The `unicode` demo on its own: `cargo run --example unicode -- <args>` is
+//! `demo run unicode <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_unicode;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("unicode", demo_unicode::main)
+}
@@ -0,0 +1,14 @@
+//! Units which can't be mixed up: newtypes with operator traits in place of operator overloads.
+//!
+//! The `units` demo on its own: `cargo run --example units -- <args>` is
+//! `demo run units <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_units;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("units", demo_units::main)
+}
@@ -0,0 +1,14 @@
+//! Types which can't hold bad values: newtypes with TryFrom in place of validation checks.
+//!
+//! The `validate` demo on its own: `cargo run --example validate -- <args>` is
+//! `demo run validate <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_validate;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("validate", demo_validate::main)
+}
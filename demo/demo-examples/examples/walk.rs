@@ -0,0 +1,14 @@
+//! Walking a directory tree: reporting and skipping per-entry errors.
+//!
+//! The `walk` demo on its own: `cargo run --example walk -- <args>` is
+//! `demo run walk <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_walk;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("walk", demo_walk::main)
+}
@@ -0,0 +1,20 @@
+//! Counts the lines, words and bytes in each file, through the wc demo's prelude:
+//! `cargo run --example wc-prelude text.txt words.txt`
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::{ open_input, ReadError };
+use demo_core::demo_wc::prelude::*;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run(|args| {
+        for file_name in args {
+            let read = open_input(Some(file_name)).map_err(|e| ReadError::from(e).in_file(file_name))?;
+            let counts: Counts = count(read).map_err(|e| e.in_file(file_name))?;
+            println!("{:>7} {:>7} {:>7} {}", counts.lines, counts.words, counts.bytes, file_name);
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,15 @@
+//! Counting lines, words and bytes:
+//! a small tool, counting raw lines from read_until in place of a LINQ one-liner.
+//!
+//! The `wc` demo on its own: `cargo run --example wc -- <args>` is
+//! `demo run wc <args>` without the runner.
+
+extern crate demo_core;
+extern crate demo_examples;
+
+use demo_core::demo_wc;
+use demo_core::exit_codes::Exit;
+
+fn main() -> Exit {
+    demo_examples::run_demo("wc", demo_wc::main)
+}
@@ -0,0 +1,82 @@
+//! Small programs built on demo-core, in `examples/`, as another project in a
+//! solution would reference a class library. Each is its own `main`, run with
+//! `cargo run --example <name>`, and sees only what demo-core makes public.
+//!
+//! Every demo the runner knows is an example too, named as it is in `demo list`:
+//! `cargo run --example demo4 -- numbers.txt` is `demo run demo4 numbers.txt`
+//! without the runner in between. Each calls its demo's `main` itself, so an
+//! example is also where to see how one demo is used on its own.
+//!
+//! This library is the little they have in common.
+
+extern crate demo_core;
+
+use std::env;
+use std::path::Path;
+
+use demo_core::args;
+use demo_core::diagnostics;
+use demo_core::exit_codes::Exit;
+use demo_core::registry::DemoError;
+
+/// The workspace folder, which has the sample files the demos read - numbers.txt,
+/// words.txt and the rest
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Runs `main` with the program's arguments, after its name, reporting an error
+/// the way the runner does and exiting with the code for it
+pub fn run<F: FnOnce(&[String]) -> Result<(), DemoError>>(main: F) -> Exit {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = main(&args);
+    if let Err(ref e) = result {
+        diagnostics::report_error(e);
+    }
+    Exit(result)
+}
+
+/// The demos' options which are followed by a value - a type name, a character or
+/// a number of seconds - rather than a file
+const VALUE_OPTIONS: &[&str] = &["--as", "--split-on", "--after"];
+
+/// Runs `main`, the demo called `name`, with the program's arguments, as `demo run`
+/// would, but without demo.toml: what's on the command line is all it gets
+pub fn run_demo<F: FnOnce(&[String]) -> Result<(), DemoError>>(name: &str, main: F) -> Exit {
+    // The runner's own, so the logging and tracing demos print the same either way
+    diagnostics::init_logging();
+    run(|args| {
+        let mut demo_args = vec![name.to_string()];
+        demo_args.append(&mut args::expand_globs(&shared_files(args))?);
+        main(&demo_args)
+    })
+}
+
+/// `args`, with each file among them found by `shared_data`. Options, and the
+/// values which follow those that take one, are left as they are
+pub fn shared_files(args: &[String]) -> Vec<String> {
+    let mut shared = Vec::with_capacity(args.len());
+    let mut value_next = false;
+    for arg in args {
+        if value_next || arg.starts_with('-') {
+            shared.push(arg.clone());
+        } else {
+            shared.push(shared_data(arg));
+        }
+        value_next = VALUE_OPTIONS.contains(&arg.as_str());
+    }
+    shared
+}
+
+/// `arg`, or the sample file of that name in the workspace folder if there's
+/// nothing by that name here. `cargo run` doesn't change directory, so this is
+/// what lets `cargo run --example demo4 -- numbers.txt` find numbers.txt from
+/// demo-examples as well as the workspace folder. Cargo only finds the example
+/// itself from those two, unless it's given `-p demo-examples`
+pub fn shared_data(arg: &str) -> String {
+    let path = Path::new(arg);
+    let shared = Path::new(DATA_DIR).join(path);
+    if path.is_relative() && !path.exists() && shared.exists() {
+        shared.to_string_lossy().into_owned()
+    } else {
+        arg.to_string()
+    }
+}
@@ -0,0 +1,49 @@
+extern crate demo_core;
+extern crate demo_examples;
+
+use std::fs;
+use std::path::Path;
+
+use demo_core::registry;
+use demo_examples::{ shared_data, shared_files, DATA_DIR };
+
+#[test]
+fn every_demo_has_an_example() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    for demo in registry::all() {
+        let example = examples.join(format!("{}.rs", demo.name()));
+        assert!(example.exists(), "no example for {}", demo.name());
+        // demo4's module is demo4, and thread-local's is demo_thread_local
+        let module = if demo.name().starts_with("demo") {
+            demo.name().to_string()
+        } else {
+            format!("demo_{}", demo.name().replace('-', "_"))
+        };
+        let source = fs::read_to_string(&example).unwrap();
+        assert!(source.contains(&format!("run_demo(\"{}\", ", demo.name())), "{} is run under another name", demo.name());
+        assert!(source.contains(&format!("{}::main", module)), "{} doesn't call {}::main", demo.name(), module);
+    }
+}
+
+#[test]
+fn a_sample_file_is_found_in_the_workspace() {
+    // Tests run in demo-examples, which has no numbers.txt of its own
+    let found = shared_data("numbers.txt");
+    assert_eq!(Path::new(&found), Path::new(DATA_DIR).join("numbers.txt"));
+    assert!(Path::new(&found).exists());
+}
+
+#[test]
+fn other_arguments_are_left_alone() {
+    assert_eq!(shared_data("missing.txt"), "missing.txt");
+    assert_eq!(shared_data("42"), "42");
+    assert_eq!(shared_data("Cargo.toml"), "Cargo.toml");
+}
+
+#[test]
+fn only_files_are_looked_for() {
+    let args: Vec<String> = ["--as", "numbers.txt", "-c", "numbers.txt"].iter().map(|a| a.to_string()).collect();
+    let shared = shared_files(&args);
+    assert_eq!(shared[..3], args[..3]);
+    assert_eq!(Path::new(&shared[3]), Path::new(DATA_DIR).join("numbers.txt"));
+}
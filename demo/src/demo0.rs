@@ -1,8 +0,0 @@
-use std::env;
-
-pub fn main() {
-    let mut argv = env::args();
-    let arg: String = argv.nth(1).unwrap();
-    let n: i32 = arg.parse().unwrap();
-    println!("{}", n);
-}
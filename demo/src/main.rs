@@ -1,25 +0,0 @@
-
-mod demo0;
-use demo0::main as demo_main;
-
-//mod demo1;
-//use demo1::main as demo_main;
-
-//mod demo2;
-//use demo2::main as demo_main;
-
-//mod demo3;
-//use demo3::main as demo_main;
-
-//mod demo4;
-//use demo4::main as demo_main;
-
-//mod demo5;
-//use demo5::main as demo_main;
-
-//mod demo6;
-//use demo6::main as demo_main;
-
-fn main() {
-    demo_main();
-}
\ No newline at end of file